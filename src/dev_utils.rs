@@ -1,17 +1,31 @@
 use clap_stdin::MaybeStdin;
 
 pub mod base64;
+pub mod calendar;
+pub mod case;
+pub mod cipher;
 pub mod colour;
 pub mod command_matchers;
 pub mod convert;
+pub mod cron;
 pub mod date;
 pub mod datetime;
+pub mod escape;
 pub mod generate;
 pub mod hash;
+pub mod humanize;
+pub mod id;
 pub mod list;
+pub mod number;
 pub mod percentage;
 pub mod regex;
+pub mod roman;
+pub mod rrule;
+pub mod spelling;
+pub mod unicode;
+pub mod units;
 pub mod url;
+pub mod words;
 
 pub enum CliError {
     NoDataProvided,
@@ -26,6 +40,11 @@ pub enum CliError {
     GenerateError(generate::GenerateError),
     PercentageError(String),
     RegexError(regex::RegexError),
+    EscapeError(escape::EscapeError),
+    CipherError(cipher::CipherError),
+    IdError(id::IdError),
+    CronError(cron::CronError),
+    RruleError(rrule::RruleError),
 }
 
 pub fn enum_variants<T: strum::VariantNames>() -> String {
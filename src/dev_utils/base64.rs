@@ -23,17 +23,24 @@ impl Display for B64Error {
 }
 
 pub fn encode(data: &str) -> String {
+    encode_bytes(data.as_bytes())
+}
+
+pub fn encode_bytes(data: &[u8]) -> String {
     general_purpose::STANDARD_NO_PAD.encode(data)
 }
 
 pub fn decode(data: &str) -> Result<String, B64Error> {
+    let decoded = decode_bytes(data)?;
+    let result = String::from_utf8(decoded)
+        .map_err(|non_utf8| String::from_utf8_lossy(non_utf8.as_bytes()).into_owned())
+        .unwrap();
+    Ok(result)
+}
+
+pub fn decode_bytes(data: &str) -> Result<Vec<u8>, B64Error> {
     match general_purpose::STANDARD_NO_PAD.decode(data) {
-        Ok(decoded) => {
-            let result = String::from_utf8(decoded)
-                .map_err(|non_utf8| String::from_utf8_lossy(non_utf8.as_bytes()).into_owned())
-                .unwrap();
-            Ok(result)
-        }
+        Ok(decoded) => Ok(decoded),
         Err(e) => Err(B64Error {
             error: e.clone(),
             message: e.to_string(),
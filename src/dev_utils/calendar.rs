@@ -0,0 +1,238 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Month, NaiveDate, Weekday};
+use regex::Regex;
+
+use super::date::{self, DateError};
+
+const WEEKDAYS: &str = "Wk Mo Tu We Th Fr Sa Su";
+// The width of one month in the grid: a week number and seven days
+const MONTH_WIDTH: usize = 23;
+const REVERSE_VIDEO: &str = "\x1b[7m";
+const RESET: &str = "\x1b[0m";
+
+fn days_in_month(year: i32, month: u32) -> Result<u32, DateError> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1).ok_or(DateError::OutOfRange)?;
+    let next = match month {
+        12 => NaiveDate::from_ymd_opt(year + 1, 1, 1),
+        _ => NaiveDate::from_ymd_opt(year, month + 1, 1),
+    }
+    .ok_or(DateError::OutOfRange)?;
+    Ok((next - first).num_days() as u32)
+}
+
+fn is_leap_year(year: i32) -> bool {
+    NaiveDate::from_ymd_opt(year, 2, 29).is_some()
+}
+
+fn month_name(month: u32) -> &'static str {
+    Month::try_from(month as u8).map_or("", |m| m.name())
+}
+
+pub fn iso_week_date(date: NaiveDate) -> String {
+    let week = date.iso_week();
+    format!(
+        "{}-W{:02}-{}",
+        week.year(),
+        week.week(),
+        date.weekday().number_from_monday()
+    )
+}
+
+pub fn info(date: DateTime<FixedOffset>) -> Result<String, DateError> {
+    let day = date.date_naive();
+    let fields = [
+        ("date", day.format("%Y-%m-%d").to_string()),
+        ("weekday", day.format("%A").to_string()),
+        ("iso week", iso_week_date(day)),
+        ("day of year", day.ordinal().to_string()),
+        ("quarter", format!("Q{}", day.month0() / 3 + 1)),
+        (
+            "leap year",
+            if is_leap_year(day.year()) {
+                "yes"
+            } else {
+                "no"
+            }
+            .to_string(),
+        ),
+        (
+            "days in month",
+            days_in_month(day.year(), day.month())?.to_string(),
+        ),
+        (
+            "days in year",
+            if is_leap_year(day.year()) {
+                "366"
+            } else {
+                "365"
+            }
+            .to_string(),
+        ),
+        ("epoch", date.timestamp().to_string()),
+    ];
+    Ok(fields
+        .iter()
+        .map(|(name, value)| format!("{}: {}", name, value))
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+// Converts an ISO week date such as `2026-W42-1` to a calendar date, or any other date to its ISO
+// week date. A week without a day stands for its Monday.
+pub fn week(content: &str, now: DateTime<FixedOffset>) -> Result<String, DateError> {
+    let pattern = match Regex::new("^(?i)(?<year>\\d{4})-?W(?<week>\\d{2})(?:-?(?<day>[1-7]))?$") {
+        Ok(p) => p,
+        Err(e) => return Err(DateError::Regex(e)),
+    };
+    let content = content.trim();
+    let captures = match pattern.captures(content) {
+        Some(c) => c,
+        None => return Ok(iso_week_date(date::parse(content, now)?.date_naive())),
+    };
+
+    let year = captures["year"]
+        .parse::<i32>()
+        .map_err(|_| DateError::Parse)?;
+    let week = captures["week"]
+        .parse::<u32>()
+        .map_err(|_| DateError::Parse)?;
+    let day = match captures.name("day") {
+        Some(day) => day.as_str().parse::<u8>().map_err(|_| DateError::Parse)?,
+        None => 1,
+    };
+    let weekday = Weekday::try_from(day - 1).map_err(|_| DateError::Parse)?;
+    match NaiveDate::from_isoywd_opt(year, week, weekday) {
+        Some(date) => Ok(date.format("%Y-%m-%d").to_string()),
+        None => Err(DateError::Unrecognised(format!(
+            "{} has no week {}",
+            year, week
+        ))),
+    }
+}
+
+// One month as rows of ISO week numbers and days starting on Monday, always six weeks tall so
+// months line up side by side
+fn month_grid(
+    year: i32,
+    month: u32,
+    title: &str,
+    highlights: &[NaiveDate],
+) -> Result<Vec<String>, DateError> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1).ok_or(DateError::OutOfRange)?;
+    let days = days_in_month(year, month)?;
+    let mut lines = vec![
+        format!("{:^width$}", title, width = MONTH_WIDTH),
+        WEEKDAYS.to_string(),
+    ];
+
+    let mut monday = first - Duration::days(first.weekday().num_days_from_monday() as i64);
+    for _ in 0..6 {
+        let mut line = String::new();
+        let mut visible = 0;
+        if monday <= first + Duration::days(days as i64 - 1) {
+            line.push_str(&format!("{:>2}", monday.iso_week().week()));
+            visible = 2;
+            for offset in 0..7 {
+                let day = monday + Duration::days(offset);
+                if day.month() != month || day < first {
+                    line.push_str("   ");
+                } else if highlights.contains(&day) {
+                    line.push_str(&format!(" {}{:>2}{}", REVERSE_VIDEO, day.day(), RESET));
+                } else {
+                    line.push_str(&format!(" {:>2}", day.day()));
+                }
+                visible += 3;
+            }
+        }
+        // Pad with the width the escape codes do not take up on screen
+        line.push_str(&" ".repeat(MONTH_WIDTH - visible));
+        lines.push(line);
+        monday += Duration::days(7);
+    }
+    Ok(lines)
+}
+
+fn join_columns(months: &[Vec<String>]) -> Vec<String> {
+    (0..months[0].len())
+        .map(|row| {
+            months
+                .iter()
+                .map(|month| month[row].as_str())
+                .collect::<Vec<&str>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+// Twelve months in four rows of three
+fn year_grid(year: i32, highlights: &[NaiveDate]) -> Result<String, DateError> {
+    let width = MONTH_WIDTH * 3 + 4;
+    let mut lines = vec![format!("{:^width$}", year).trim_end().to_string()];
+    for quarter in 0..4 {
+        let months = (1..=3)
+            .map(|m| {
+                let month = quarter * 3 + m;
+                month_grid(year, month, month_name(month), highlights)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        lines.push(String::new());
+        lines.extend(join_columns(&months));
+    }
+    Ok(lines.join("\n"))
+}
+
+// Prints a month (`2026-10`), a whole year (`2026`) or the month of any date, which is then
+// highlighted along with `highlights`. Without `highlight` no escape codes are written at all.
+pub fn cal(
+    content: &str,
+    now: DateTime<FixedOffset>,
+    highlights: &[NaiveDate],
+    highlight: bool,
+) -> Result<String, DateError> {
+    let content = content.trim();
+    let mut highlights = match highlight {
+        true => highlights.to_vec(),
+        false => vec![],
+    };
+
+    if content.len() == 4 {
+        if let Ok(year) = content.parse::<i32>() {
+            return year_grid(year, &highlights);
+        }
+    }
+
+    let month_pattern =
+        Regex::new("^(?<year>\\d{4})-(?<month>\\d{1,2})$").map_err(DateError::Regex)?;
+    let (year, month) = match month_pattern.captures(content) {
+        Some(captures) => (
+            captures["year"]
+                .parse::<i32>()
+                .map_err(|_| DateError::Parse)?,
+            captures["month"]
+                .parse::<u32>()
+                .map_err(|_| DateError::Parse)?,
+        ),
+        None => {
+            let day = date::parse(content, now)?.date_naive();
+            if highlight {
+                highlights.push(day);
+            }
+            (day.year(), day.month())
+        }
+    };
+    let title = format!("{} {}", month_name(month), year);
+    let lines = month_grid(year, month, &title, &highlights)?;
+    // The blank rows only matter when months sit side by side
+    Ok(lines
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<&str>>()
+        .join("\n")
+        .trim_end()
+        .to_string())
+}
+
+#[cfg(test)]
+#[path = "./calendar_test.rs"]
+mod calendar_test;
@@ -0,0 +1,108 @@
+use crate::dev_utils::calendar::*;
+use chrono::{DateTime, FixedOffset, NaiveDate};
+
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2026-10-19T09:00:00Z").unwrap()
+    }
+
+    #[test]
+    fn test_info() {
+        let date = DateTime::parse_from_rfc3339("2024-12-30T12:00:00Z").unwrap();
+        match info(date) {
+            Ok(s) => assert_eq!(
+                s,
+                "date: 2024-12-30\n\
+                 weekday: Monday\n\
+                 iso week: 2025-W01-1\n\
+                 day of year: 365\n\
+                 quarter: Q4\n\
+                 leap year: yes\n\
+                 days in month: 31\n\
+                 days in year: 366\n\
+                 epoch: 1735560000"
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_week() {
+        let cases = [
+            ("2026-W42-1", "2026-10-12"),
+            ("2026W427", "2026-10-18"),
+            ("2026-w42", "2026-10-12"),
+            ("2020-W53-5", "2021-01-01"),
+            ("2026-10-19", "2026-W43-1"),
+            ("2027-01-01", "2026-W53-5"),
+            ("2008-12-29", "2009-W01-1"),
+            ("next friday", "2026-W43-5"),
+        ];
+        for (content, expected) in cases {
+            match week(content, now()) {
+                Ok(s) => assert_eq!(s, expected, "{}", content),
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+        assert!(week("2025-W53-1", now()).is_err());
+    }
+
+    #[test]
+    fn test_month_calendar() {
+        let highlights = [NaiveDate::from_ymd_opt(2026, 2, 14).unwrap()];
+        match cal("2026-02", now(), &highlights, true) {
+            Ok(s) => assert_eq!(
+                s,
+                "     February 2026\n\
+                 Wk Mo Tu We Th Fr Sa Su\n \
+                 5                    1\n \
+                 6  2  3  4  5  6  7  8\n \
+                 7  9 10 11 12 13 \x1b[7m14\x1b[0m 15\n \
+                 8 16 17 18 19 20 21 22\n \
+                 9 23 24 25 26 27 28"
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        // A date shows its month with the date highlighted
+        match cal("today", now(), &[], true) {
+            Ok(s) => assert!(s.contains("43 \x1b[7m19\x1b[0m 20 21")),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        // Output that is not going to a terminal has no escape codes
+        match cal("today", now(), &highlights, false) {
+            Ok(s) => {
+                assert!(s.contains("43 19 20 21"));
+                assert!(!s.contains('\x1b'));
+            }
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_year_calendar() {
+        match cal("2026", now(), &[], true) {
+            Ok(s) => {
+                let lines = s.lines().collect::<Vec<&str>>();
+                assert_eq!(lines.len(), 1 + 4 * 9);
+                assert_eq!(lines[0].trim(), "2026");
+                assert_eq!(
+                    lines[2],
+                    "        January                 February                   March"
+                );
+                assert_eq!(
+                    lines[4],
+                    " 1           1  2  3  4   5                    1   9                    1"
+                );
+                assert_eq!(
+                    lines[9],
+                    "                                                  14 30 31"
+                );
+            }
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use capitalize::Capitalize;
+use strum_macros::{EnumIter, EnumString, EnumVariantNames};
+
+#[derive(Clone, Copy, EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum CaseAction {
+    Camel,
+    Pascal,
+    Snake,
+    #[strum(
+        serialize = "screaming-snake",
+        serialize = "screamingsnake",
+        serialize = "constant"
+    )]
+    ScreamingSnake,
+    Kebab,
+    Train,
+    Dot,
+    Title,
+}
+
+#[derive(Clone, Copy, EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum AcronymMode {
+    // `HTTPServer` is split into `HTTP` and `Server`, both treated as regular words
+    Split,
+    // Same split, but all-caps words keep their casing in camel/pascal/train/title output
+    Preserve,
+    // Every uppercase letter starts a new word: `HTTPServer` -> `h_t_t_p_server`
+    None,
+}
+
+fn is_acronym(word: &str) -> bool {
+    word.chars().count() > 1 && word.chars().all(|c| !c.is_lowercase())
+}
+
+pub fn split_words(content: &str, acronyms: AcronymMode) -> Vec<String> {
+    let mut words = vec![];
+    for chunk in content.split(|c: char| !c.is_alphanumeric()) {
+        let chars = chunk.chars().collect::<Vec<char>>();
+        let mut word = String::new();
+        for (i, c) in chars.iter().enumerate() {
+            let boundary = i > 0
+                && c.is_uppercase()
+                && match acronyms {
+                    AcronymMode::None => true,
+                    AcronymMode::Split | AcronymMode::Preserve => {
+                        let previous = chars[i - 1];
+                        let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+                        previous.is_lowercase()
+                            || previous.is_numeric()
+                            || (previous.is_uppercase() && next_is_lower)
+                    }
+                };
+            if boundary && !word.is_empty() {
+                words.push(word);
+                word = String::new();
+            }
+            word.push(*c);
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+    words
+}
+
+fn capitalised(word: &str, acronyms: AcronymMode) -> String {
+    match acronyms {
+        AcronymMode::Preserve if is_acronym(word) => word.to_string(),
+        _ => word.capitalize(),
+    }
+}
+
+fn joined_lowercase(words: &[String], separator: &str) -> String {
+    words
+        .iter()
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+fn joined_capitalised(words: &[String], separator: &str, acronyms: AcronymMode) -> String {
+    words
+        .iter()
+        .map(|w| capitalised(w, acronyms))
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+pub fn convert(content: &str, case: CaseAction, acronyms: AcronymMode) -> String {
+    let words = split_words(content, acronyms);
+
+    match case {
+        CaseAction::Camel => {
+            let mut result = String::new();
+            for (i, word) in words.iter().enumerate() {
+                if i == 0 {
+                    result.push_str(&word.to_lowercase());
+                } else {
+                    result.push_str(&capitalised(word, acronyms));
+                }
+            }
+            result
+        }
+        CaseAction::Pascal => joined_capitalised(&words, "", acronyms),
+        CaseAction::Snake => joined_lowercase(&words, "_"),
+        CaseAction::ScreamingSnake => words
+            .iter()
+            .map(|w| w.to_uppercase())
+            .collect::<Vec<String>>()
+            .join("_"),
+        CaseAction::Kebab => joined_lowercase(&words, "-"),
+        CaseAction::Train => joined_capitalised(&words, "-", acronyms),
+        CaseAction::Dot => joined_lowercase(&words, "."),
+        CaseAction::Title => joined_capitalised(&words, " ", acronyms),
+    }
+}
+
+#[cfg(test)]
+#[path = "./case_test.rs"]
+mod case_test;
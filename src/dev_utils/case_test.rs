@@ -0,0 +1,66 @@
+use crate::dev_utils::case::*;
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(
+            split_words("HTTPServer", AcronymMode::Split),
+            vec!["HTTP", "Server"]
+        );
+        assert_eq!(
+            split_words("HTTPServer", AcronymMode::None),
+            vec!["H", "T", "T", "P", "Server"]
+        );
+        assert_eq!(
+            split_words("parseJSON2Yaml", AcronymMode::Split),
+            vec!["parse", "JSON2", "Yaml"]
+        );
+        assert_eq!(
+            split_words("some-mixed_case.words here", AcronymMode::Split),
+            vec!["some", "mixed", "case", "words", "here"]
+        );
+    }
+
+    #[test]
+    fn test_convert() {
+        let input = "HTTPServer error";
+        let mode = AcronymMode::Split;
+        assert_eq!(convert(input, CaseAction::Camel, mode), "httpServerError");
+        assert_eq!(convert(input, CaseAction::Pascal, mode), "HttpServerError");
+        assert_eq!(convert(input, CaseAction::Snake, mode), "http_server_error");
+        assert_eq!(
+            convert(input, CaseAction::ScreamingSnake, mode),
+            "HTTP_SERVER_ERROR"
+        );
+        assert_eq!(convert(input, CaseAction::Kebab, mode), "http-server-error");
+        assert_eq!(convert(input, CaseAction::Train, mode), "Http-Server-Error");
+        assert_eq!(convert(input, CaseAction::Dot, mode), "http.server.error");
+        assert_eq!(convert(input, CaseAction::Title, mode), "Http Server Error");
+    }
+
+    #[test]
+    fn test_convert_acronyms() {
+        assert_eq!(
+            convert("http_server", CaseAction::Pascal, AcronymMode::Preserve),
+            "HttpServer"
+        );
+        assert_eq!(
+            convert("user ID", CaseAction::Camel, AcronymMode::Preserve),
+            "userID"
+        );
+        assert_eq!(
+            convert("getHTTPResponse", CaseAction::Pascal, AcronymMode::Preserve),
+            "GetHTTPResponse"
+        );
+        assert_eq!(
+            convert("getHTTPResponse", CaseAction::Snake, AcronymMode::None),
+            "get_h_t_t_p_response"
+        );
+        assert_eq!(
+            convert("user_id", CaseAction::ScreamingSnake, AcronymMode::Split),
+            "USER_ID"
+        );
+    }
+}
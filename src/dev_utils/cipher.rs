@@ -0,0 +1,180 @@
+use std::fmt::{Display, Formatter};
+
+use strum_macros::{EnumIter, EnumString, EnumVariantNames};
+
+use super::base64;
+use super::convert::{self, ConversionError};
+
+#[derive(Clone, Copy, EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum CipherAction {
+    Rot13,
+    Rot47,
+    Caesar,
+    // Lists the text shifted by every possible amount
+    #[strum(serialize = "caesar-bruteforce", serialize = "caesarbruteforce")]
+    CaesarBruteforce,
+    Atbash,
+    Vigenere,
+    Xor,
+}
+
+#[derive(Clone, Copy, PartialEq, EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum ByteFormat {
+    Text,
+    Hex,
+    Base64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CipherError {
+    pub message: String,
+}
+
+impl Display for CipherError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+fn error(message: String) -> CipherError {
+    CipherError { message }
+}
+
+// Shifts ASCII letters by `shift` positions, wrapping around the alphabet and keeping the case
+fn shift_letter(c: char, shift: i32) -> char {
+    let base = if c.is_ascii_lowercase() {
+        b'a'
+    } else if c.is_ascii_uppercase() {
+        b'A'
+    } else {
+        return c;
+    };
+    let offset = (c as u8 - base) as i32;
+    (base + ((offset + shift.rem_euclid(26)) % 26) as u8) as char
+}
+
+pub fn caesar(content: &str, shift: i32) -> String {
+    content.chars().map(|c| shift_letter(c, shift)).collect()
+}
+
+pub fn rot13(content: &str) -> String {
+    caesar(content, 13)
+}
+
+// Rotates every printable ASCII character from `!` to `~`, so digits and punctuation change too
+pub fn rot47(content: &str) -> String {
+    content
+        .chars()
+        .map(|c| match c {
+            '!'..='~' => (b'!' + (c as u8 - b'!' + 47) % 94) as char,
+            _ => c,
+        })
+        .collect()
+}
+
+pub fn caesar_bruteforce(content: &str) -> String {
+    (0..26)
+        .map(|shift| format!("{:>2}: {}", shift, caesar(content, shift)))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn atbash(content: &str) -> String {
+    content
+        .chars()
+        .map(|c| match c {
+            'a'..='z' => (b'z' - (c as u8 - b'a')) as char,
+            'A'..='Z' => (b'Z' - (c as u8 - b'A')) as char,
+            _ => c,
+        })
+        .collect()
+}
+
+// Only letters are enciphered and only they consume key letters, so spacing and punctuation are
+// kept in place
+pub fn vigenere(content: &str, key: &str, decode: bool) -> Result<String, CipherError> {
+    let shifts = key
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| (c.to_ascii_lowercase() as u8 - b'a') as i32)
+        .collect::<Vec<i32>>();
+    if shifts.is_empty() {
+        return Err(error(
+            "The Vigenère key must contain at least one letter".to_string(),
+        ));
+    }
+
+    let mut index = 0;
+    let mut result = String::new();
+    for c in content.chars() {
+        if c.is_ascii_alphabetic() {
+            let shift = shifts[index % shifts.len()];
+            result.push(shift_letter(c, if decode { -shift } else { shift }));
+            index += 1;
+        } else {
+            result.push(c);
+        }
+    }
+    Ok(result)
+}
+
+fn read_bytes(content: &str, format: ByteFormat) -> Result<Vec<u8>, CipherError> {
+    match format {
+        ByteFormat::Text => Ok(content.as_bytes().to_vec()),
+        ByteFormat::Hex => {
+            let hex = content
+                .trim()
+                .trim_start_matches("0x")
+                .replace(char::is_whitespace, "");
+            match convert::hex2bytes(&hex) {
+                Ok(bytes) => Ok(bytes),
+                Err(ConversionError::Hex2String(message)) => Err(error(message)),
+                Err(e) => Err(error(e.to_string())),
+            }
+        }
+        // Padding is optional, matching what the base64 command produces
+        ByteFormat::Base64 => match base64::decode_bytes(content.trim().trim_end_matches('=')) {
+            Ok(bytes) => Ok(bytes),
+            Err(e) => Err(error(e.message)),
+        },
+    }
+}
+
+fn write_bytes(bytes: &[u8], format: ByteFormat) -> String {
+    match format {
+        ByteFormat::Text => String::from_utf8_lossy(bytes).into_owned(),
+        ByteFormat::Hex => convert::bytes2hex(bytes),
+        ByteFormat::Base64 => base64::encode_bytes(bytes),
+    }
+}
+
+// XORs the input with a repeating key. The key is read as text unless it is written as
+// `0x`-prefixed hex.
+pub fn xor(
+    content: &str,
+    key: &str,
+    input: ByteFormat,
+    output: ByteFormat,
+) -> Result<String, CipherError> {
+    let key = match key.strip_prefix("0x") {
+        Some(hex) => read_bytes(hex, ByteFormat::Hex)?,
+        None => key.as_bytes().to_vec(),
+    };
+    if key.is_empty() {
+        return Err(error("The XOR key cannot be empty".to_string()));
+    }
+
+    let bytes = read_bytes(content, input)?
+        .iter()
+        .zip(key.iter().cycle())
+        .map(|(b, k)| b ^ k)
+        .collect::<Vec<u8>>();
+
+    Ok(write_bytes(&bytes, output))
+}
+
+#[cfg(test)]
+#[path = "./cipher_test.rs"]
+mod cipher_test;
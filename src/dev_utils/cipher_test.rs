@@ -0,0 +1,118 @@
+use crate::dev_utils::cipher::*;
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rot13() {
+        assert_eq!(rot13("Hello, World!"), "Uryyb, Jbeyq!");
+        assert_eq!(rot13(&rot13("Round trip")), "Round trip");
+    }
+
+    #[test]
+    fn test_rot47() {
+        assert_eq!(rot47("Hello, World! 123"), "w6==@[ (@C=5P `ab");
+        assert_eq!(rot47(&rot47("p@ssw0rd~")), "p@ssw0rd~");
+    }
+
+    #[test]
+    fn test_caesar() {
+        assert_eq!(caesar("abc xyz", 3), "def abc");
+        assert_eq!(caesar("def abc", -3), "abc xyz");
+        assert_eq!(caesar("Zebra", 27), "Afcsb");
+        assert_eq!(caesar("ñ-42", 5), "ñ-42");
+        assert_eq!(caesar("abc", i32::MAX), "xyz");
+        assert_eq!(caesar("abc", i32::MIN), "cde");
+    }
+
+    #[test]
+    fn test_caesar_bruteforce() {
+        let result = caesar_bruteforce("Khoor");
+        let lines = result.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 26);
+        assert_eq!(lines[0], " 0: Khoor");
+        assert_eq!(lines[23], "23: Hello");
+    }
+
+    #[test]
+    fn test_atbash() {
+        assert_eq!(atbash("Hello, World!"), "Svool, Dliow!");
+        assert_eq!(atbash(&atbash("Atbash")), "Atbash");
+    }
+
+    #[test]
+    fn test_vigenere() {
+        let result = vigenere("ATTACK AT DAWN", "lemon", false);
+        match result {
+            Ok(s) => assert_eq!(s, "LXFOPV EF RNHR"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = vigenere("LXFOPV EF RNHR", "LEMON", true);
+        match result {
+            Ok(s) => assert_eq!(s, "ATTACK AT DAWN"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = vigenere("text", "123", false);
+        match result {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => assert_eq!(
+                e.message,
+                "The Vigenère key must contain at least one letter"
+            ),
+        }
+    }
+
+    #[test]
+    fn test_xor() {
+        let result = xor("hello world", "key", ByteFormat::Text, ByteFormat::Hex);
+        match result {
+            Ok(s) => assert_eq!(s, "030015070a591c0a0b0701"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = xor(
+            "030015070a591c0a0b0701",
+            "key",
+            ByteFormat::Hex,
+            ByteFormat::Text,
+        );
+        match result {
+            Ok(s) => assert_eq!(s, "hello world"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = xor(
+            "AwAVBwpZHAoLBwE=",
+            "0x6b6579",
+            ByteFormat::Base64,
+            ByteFormat::Text,
+        );
+        match result {
+            Ok(s) => assert_eq!(s, "hello world"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = xor("0x03 00 15", "key", ByteFormat::Hex, ByteFormat::Base64);
+        match result {
+            Ok(s) => assert_eq!(s, "aGVs"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = xor("abc", "key", ByteFormat::Hex, ByteFormat::Text);
+        match result {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => assert_eq!(
+                e.message,
+                "Hex string must have an even number of characters"
+            ),
+        }
+
+        let result = xor("abcd", "", ByteFormat::Hex, ByteFormat::Text);
+        match result {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => assert_eq!(e.message, "The XOR key cannot be empty"),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt::{Display, Formatter};
+
 use colors_transform::{Color, Hsl, Rgb};
 use strum_macros::EnumString;
 use strum_macros::{EnumIter, EnumVariantNames};
@@ -19,6 +21,17 @@ pub enum ColourConversionError {
     Hsl2Hex(String),
 }
 
+impl Display for ColourConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColourConversionError::Hex2Rgb(message)
+            | ColourConversionError::Hex2Hsl(message)
+            | ColourConversionError::Rgb2Hex(message)
+            | ColourConversionError::Hsl2Hex(message) => write!(f, "{}", message),
+        }
+    }
+}
+
 pub fn hex2rgb(data: &str) -> Result<String, ColourConversionError> {
     let rgb = match Rgb::from_hex_str(data) {
         Ok(c) => c,
@@ -1,16 +1,36 @@
-use crate::dev_utils::date::DateAction;
+use crate::dev_utils::date::{DateAction, DiffOutput};
 use crate::{
-    dev_utils, B64Args, Cli, ColourArgs, ConversionArgs, DateArgs, DateTimeArgs, GenerateArgs,
-    HashArgs, ListArgs, PercentageArgs, RegexArgs, URLArgs,
+    dev_utils, B64Args, CaseArgs, CipherArgs, Cli, ColourArgs, ConversionArgs, CronArgs, DateArgs,
+    DateTimeArgs, EscapeArgs, GenerateArgs, HashArgs, IdArgs, ListArgs, PercentageArgs, RegexArgs,
+    RruleArgs, URLArgs, UnicodeArgs,
 };
+use clap_stdin::MaybeStdin;
+use std::io::IsTerminal;
 use std::str::FromStr;
 
+use super::case::{AcronymMode, CaseAction};
+use super::cipher::{ByteFormat, CipherAction};
 use super::colour::Colour;
+use super::cron::CronAction;
+use super::datetime::{DateTimeFormat, Zone, ZoneOptions};
+use super::escape::EscapeFormat;
 use super::generate::{GenerateParams, GenerateSubcommands};
-use super::list::ListAction;
+use super::id::IdKind;
+use super::list::{GroupKey, ListAction, Separator, SortMode, SortOptions};
+use super::number::{Notation, NumberFormat, NumberLocale};
 use super::percentage::PercentageAction;
 use super::regex::RegexAction;
-use super::{base64::B64Action, convert::Conversion, hash::HashType, url::UrlAction, CliError};
+use super::rrule::RruleAction;
+use super::spelling::Alphabet;
+use super::unicode::UnicodeAction;
+use super::words::{Currency, Language};
+use super::{
+    base64::B64Action,
+    convert::{Conversion, ConversionError, MorseOptions, TextEncoding},
+    hash::HashType,
+    url::UrlAction,
+    CliError,
+};
 
 pub fn hash(hash_args: HashArgs, cli_args: Cli) -> Result<String, CliError> {
     let hash_type = match <HashType as FromStr>::from_str(&hash_args.hash_type) {
@@ -99,8 +119,69 @@ pub fn conversion(convert_args: ConversionArgs, cli_args: Cli) -> Result<String,
             )));
         }
     };
+    let encoding = match TextEncoding::from_str(&convert_args.encoding) {
+        Ok(e) => e,
+        Err(_) => {
+            return Err(CliError::InvalidArgs(format!(
+                "Invalid encoding. Valid encodings are: {}",
+                dev_utils::enum_variants::<TextEncoding>()
+            )));
+        }
+    };
+    let alphabet = match Alphabet::from_str(&convert_args.alphabet) {
+        Ok(a) => a,
+        Err(_) => {
+            return Err(CliError::InvalidArgs(format!(
+                "Invalid alphabet. Valid alphabets are: {}",
+                dev_utils::enum_variants::<Alphabet>()
+            )));
+        }
+    };
+    let language = match Language::from_str(&convert_args.lang) {
+        Ok(l) => l,
+        Err(_) => {
+            return Err(CliError::InvalidArgs(format!(
+                "Invalid language. Valid languages are: {}",
+                dev_utils::enum_variants::<Language>()
+            )));
+        }
+    };
+    let currency = match Currency::from_str(&convert_args.currency) {
+        Ok(c) => c,
+        Err(_) => {
+            return Err(CliError::InvalidArgs(format!(
+                "Invalid currency. Valid currencies are: {}",
+                dev_utils::enum_variants::<Currency>()
+            )));
+        }
+    };
+    let morse_options = MorseOptions {
+        letter_separator: &convert_args.letter_separator,
+        word_separator: &convert_args.word_separator,
+        timing: convert_args.timing,
+    };
+    let number_format = NumberFormat {
+        locale: number_locale(&convert_args.locale)?,
+        notation: match Notation::from_str(&convert_args.notation) {
+            Ok(n) => n,
+            Err(_) => {
+                return Err(CliError::InvalidArgs(format!(
+                    "Invalid notation. Valid notations are: {}",
+                    dev_utils::enum_variants::<Notation>()
+                )));
+            }
+        },
+        decimals: convert_args.decimals,
+        significant: convert_args.significant,
+    };
     let content = dev_utils::get_content(convert_args.content, cli_args.editor)?;
     let content_str = content.as_str();
+    // Numeric conversions read localized input, anything unparseable is left for the
+    // conversion itself to report
+    let number_input = match number_format.parse(content_str) {
+        Ok(v) => v.to_string(),
+        Err(_) => content.clone(),
+    };
 
     match action {
         Conversion::Json2Csv => match dev_utils::convert::json2csv(content_str) {
@@ -117,80 +198,102 @@ pub fn conversion(convert_args: ConversionArgs, cli_args: Cli) -> Result<String,
             Ok(data) => Ok(data),
             Err(e) => Err(CliError::ConversionError(e)),
         },
-        Conversion::Text2Nato => Ok(dev_utils::convert::text2nato(content_str)),
+        Conversion::Text2Nato => Ok(dev_utils::convert::text2nato(content_str, alphabet)),
+        Conversion::Nato2Text => match dev_utils::convert::nato2text(content_str, alphabet) {
+            Ok(data) => Ok(data),
+            Err(e) => Err(CliError::ConversionError(e)),
+        },
+        Conversion::Text2Morse => {
+            match dev_utils::convert::text2morse(content_str, &morse_options) {
+                Ok(data) => Ok(data),
+                Err(e) => Err(CliError::ConversionError(e)),
+            }
+        }
+        Conversion::Morse2Text => {
+            match dev_utils::convert::morse2text(content_str, &morse_options) {
+                Ok(data) => Ok(data),
+                Err(e) => Err(CliError::ConversionError(e)),
+            }
+        }
         Conversion::Slugify => Ok(dev_utils::convert::slugify(content_str)),
         Conversion::Celsius2Fahrenheit | Conversion::C2F => {
-            match dev_utils::convert::celsius2fahrenheit(content_str) {
-                Ok(data) => Ok(data.to_string()),
+            match dev_utils::convert::celsius2fahrenheit(&number_input) {
+                Ok(data) => Ok(number_format.format(data)),
                 Err(e) => Err(CliError::ConversionError(e)),
             }
         }
         Conversion::Fahrenheit2Celsius | Conversion::F2C => {
-            match dev_utils::convert::fahrenheit2celsius(content_str) {
-                Ok(data) => Ok(data.to_string()),
+            match dev_utils::convert::fahrenheit2celsius(&number_input) {
+                Ok(data) => Ok(number_format.format(data)),
                 Err(e) => Err(CliError::ConversionError(e)),
             }
         }
         Conversion::Celsius2Kelvin | Conversion::C2K => {
-            match dev_utils::convert::celsius2kelvin(content_str) {
-                Ok(data) => Ok(data.to_string()),
+            match dev_utils::convert::celsius2kelvin(&number_input) {
+                Ok(data) => Ok(number_format.format(data)),
                 Err(e) => Err(CliError::ConversionError(e)),
             }
         }
         Conversion::Kelvin2Celsius | Conversion::K2C => {
-            match dev_utils::convert::kelvin2celsius(content_str) {
-                Ok(data) => Ok(data.to_string()),
+            match dev_utils::convert::kelvin2celsius(&number_input) {
+                Ok(data) => Ok(number_format.format(data)),
                 Err(e) => Err(CliError::ConversionError(e)),
             }
         }
         Conversion::Fahrenheit2Kelvin | Conversion::F2K => {
-            match dev_utils::convert::fahrenheit2kelvin(content_str) {
-                Ok(data) => Ok(data.to_string()),
+            match dev_utils::convert::fahrenheit2kelvin(&number_input) {
+                Ok(data) => Ok(number_format.format(data)),
                 Err(e) => Err(CliError::ConversionError(e)),
             }
         }
         Conversion::Kelvin2Fahrenheit | Conversion::K2F => {
-            match dev_utils::convert::kelvin2fahrenheit(content_str) {
-                Ok(data) => Ok(data.to_string()),
+            match dev_utils::convert::kelvin2fahrenheit(&number_input) {
+                Ok(data) => Ok(number_format.format(data)),
                 Err(e) => Err(CliError::ConversionError(e)),
             }
         }
-        Conversion::Text2ASCIIBinary => match dev_utils::convert::text2asciibinary(content_str) {
-            Ok(data) => Ok(data),
-            Err(e) => Err(CliError::ConversionError(e)),
-        },
-        Conversion::AsciiBinary2Text => match dev_utils::convert::asciibinary2text(content_str) {
-            Ok(data) => Ok(data),
-            Err(e) => Err(CliError::ConversionError(e)),
-        },
-        Conversion::Kilometers2Miles | Conversion::Km2Mi => {
-            match dev_utils::convert::kilometers2miles(content_str) {
+        Conversion::Text2ASCIIBinary => {
+            match dev_utils::convert::text2asciibinary(content_str, &encoding) {
                 Ok(data) => Ok(data),
                 Err(e) => Err(CliError::ConversionError(e)),
             }
         }
-        Conversion::Miles2Kilometers | Conversion::Mi2Km => {
-            match dev_utils::convert::miles2kilometers(content_str) {
+        Conversion::AsciiBinary2Text => {
+            match dev_utils::convert::asciibinary2text(content_str, &encoding) {
                 Ok(data) => Ok(data),
                 Err(e) => Err(CliError::ConversionError(e)),
             }
         }
+        Conversion::Kilometers2Miles | Conversion::Km2Mi => {
+            match dev_utils::convert::kilometers2miles(&number_input) {
+                Ok(data) => Ok(number_format.format(data)),
+                Err(e) => Err(CliError::ConversionError(e)),
+            }
+        }
+        Conversion::Miles2Kilometers | Conversion::Mi2Km => {
+            match dev_utils::convert::miles2kilometers(&number_input) {
+                Ok(data) => Ok(number_format.format(data)),
+                Err(e) => Err(CliError::ConversionError(e)),
+            }
+        }
         Conversion::Pounds2Kilos | Conversion::Lbs2Kgs => {
-            match dev_utils::convert::pounds2kilos(content_str) {
-                Ok(data) => Ok(data),
+            match dev_utils::convert::pounds2kilos(&number_input) {
+                Ok(data) => Ok(number_format.format(data)),
                 Err(e) => Err(CliError::ConversionError(e)),
             }
         }
         Conversion::Kilos2Pounds | Conversion::Kgs2Lbs => {
-            match dev_utils::convert::kilos2pounds(content_str) {
+            match dev_utils::convert::kilos2pounds(&number_input) {
+                Ok(data) => Ok(number_format.format(data)),
+                Err(e) => Err(CliError::ConversionError(e)),
+            }
+        }
+        Conversion::Arabic2Roman => {
+            match dev_utils::convert::arabic2roman(content_str, convert_args.vinculum) {
                 Ok(data) => Ok(data),
                 Err(e) => Err(CliError::ConversionError(e)),
             }
         }
-        Conversion::Arabic2Roman => match dev_utils::convert::arabic2roman(content_str) {
-            Ok(data) => Ok(data),
-            Err(e) => Err(CliError::ConversionError(e)),
-        },
         Conversion::Roman2Arabic => match dev_utils::convert::roman2arabic(content_str) {
             Ok(data) => Ok(data),
             Err(e) => Err(CliError::ConversionError(e)),
@@ -198,13 +301,96 @@ pub fn conversion(convert_args: ConversionArgs, cli_args: Cli) -> Result<String,
         Conversion::ToOrdinal | Conversion::To_Ordinal => {
             Ok(dev_utils::convert::to_ordinal(content_str))
         }
+        Conversion::Units => match dev_utils::units::convert(content_str, &number_format) {
+            Ok(data) => Ok(data),
+            Err(e) => Err(CliError::ConversionError(e)),
+        },
+        Conversion::Bytes2Human => match dev_utils::humanize::bytes2human(
+            content_str,
+            convert_args.si,
+            convert_args.decimals.unwrap_or(1),
+        ) {
+            Ok(data) => Ok(data),
+            Err(e) => Err(CliError::ConversionError(e)),
+        },
+        Conversion::Human2Bytes => match dev_utils::humanize::human2bytes(content_str) {
+            Ok(data) => Ok(data),
+            Err(e) => Err(CliError::ConversionError(e)),
+        },
+        Conversion::Seconds2Human => match dev_utils::humanize::seconds2human(content_str) {
+            Ok(data) => Ok(data),
+            Err(e) => Err(CliError::ConversionError(e)),
+        },
+        Conversion::Seconds2Iso8601 => match dev_utils::humanize::seconds2iso8601(content_str) {
+            Ok(data) => Ok(data),
+            Err(e) => Err(CliError::ConversionError(e)),
+        },
+        Conversion::Human2Seconds => match dev_utils::humanize::human2seconds(content_str) {
+            Ok(data) => Ok(data),
+            Err(e) => Err(CliError::ConversionError(e)),
+        },
+        Conversion::Number2Words => match dev_utils::words::number2words(content_str, language) {
+            Ok(data) => Ok(data),
+            Err(e) => Err(CliError::ConversionError(e)),
+        },
+        Conversion::Words2Number => match dev_utils::words::words2number(content_str, language) {
+            Ok(data) => Ok(data),
+            Err(e) => Err(CliError::ConversionError(e)),
+        },
+        Conversion::Ordinal2Words => match dev_utils::words::ordinal2words(content_str, language) {
+            Ok(data) => Ok(data),
+            Err(e) => Err(CliError::ConversionError(e)),
+        },
+        Conversion::FormatNumber => {
+            match dev_utils::number::parse(content_str, NumberLocale::Plain) {
+                Ok(value) => Ok(number_format.format(value)),
+                Err(e) => Err(CliError::ConversionError(
+                    ConversionError::NumberConversion(e.message),
+                )),
+            }
+        }
+        Conversion::ParseNumber => match number_format.parse(content_str) {
+            Ok(value) => Ok(value.to_string()),
+            Err(e) => Err(CliError::ConversionError(
+                ConversionError::NumberConversion(e.message),
+            )),
+        },
+        Conversion::Currency2Words => {
+            match dev_utils::words::currency2words(content_str, language, currency) {
+                Ok(data) => Ok(data),
+                Err(e) => Err(CliError::ConversionError(e)),
+            }
+        }
     }
 }
 
 pub fn date_time(date_time_args: DateTimeArgs, args: Cli) -> Result<String, CliError> {
     let content = dev_utils::get_content(date_time_args.content, args.editor)?;
     let content_str = content.as_str();
-    match dev_utils::datetime::convert(&date_time_args.from, &date_time_args.to, content_str) {
+    let parse_zone = |zone: &str| Zone::from_str(zone).map_err(CliError::DateTimeError);
+    let mut zones = ZoneOptions {
+        from: parse_zone(&date_time_args.from_tz)?,
+        ..ZoneOptions::default()
+    };
+    if !date_time_args.tz.is_empty() {
+        zones.to = date_time_args
+            .tz
+            .iter()
+            .map(|zone| parse_zone(zone))
+            .collect::<Result<Vec<_>, _>>()?;
+    }
+    // Say which format `auto` picked on stderr, so the converted value can still be piped
+    if DateTimeFormat::from_str(&date_time_args.from) == Ok(DateTimeFormat::Auto) {
+        let (format, _) =
+            dev_utils::datetime::detect(content_str, &zones).map_err(CliError::DateTimeError)?;
+        eprintln!("Detected format: {}", format);
+    }
+    match dev_utils::datetime::convert(
+        &date_time_args.from,
+        &date_time_args.to,
+        content_str,
+        &zones,
+    ) {
         Ok(result) => Ok(result),
         Err(e) => Err(CliError::DateTimeError(e)),
     }
@@ -220,17 +406,111 @@ pub fn date(date_args: DateArgs, cli_args: Cli) -> Result<String, CliError> {
             )));
         }
     };
-    let content = dev_utils::get_content(date_args.content, cli_args.editor)?;
+    let read_date = |date: &str| match dev_utils::datetime::detect(date, &ZoneOptions::default()) {
+        Ok((_, date)) => Ok(date),
+        Err(e) => Err(CliError::DateTimeError(e)),
+    };
+    let now = chrono::Utc::now().fixed_offset();
+    let base = match &date_args.from {
+        Some(from) => read_date(from)?,
+        None => now,
+    };
+    let holidays = date_args
+        .holidays
+        .iter()
+        .map(|holiday| {
+            chrono::NaiveDate::parse_from_str(holiday.trim(), "%Y-%m-%d").map_err(|_| {
+                CliError::InvalidArgs(format!("Invalid holiday {}, expected YYYY-MM-DD", holiday))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let highlights = date_args
+        .highlight
+        .iter()
+        .map(|date| dev_utils::date::parse(date, base).map(|d| d.date_naive()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(CliError::DateError)?;
+    // Without a date, `cal` shows the current month
+    let content = match (&action, &date_args.content) {
+        (DateAction::Cal, None) if !cli_args.editor => "today".to_string(),
+        _ => dev_utils::get_content(date_args.content, cli_args.editor)?,
+    };
     let content_str = content.as_str();
 
     match action {
-        DateAction::Delta => match dev_utils::date::delta(content_str, -1) {
-            Ok(result) => Ok(result),
+        DateAction::Delta => {
+            match dev_utils::date::delta(content_str, base, date_args.format.as_deref(), &holidays)
+            {
+                Ok(result) => Ok(result),
+                Err(e) => Err(CliError::DateError(e)),
+            }
+        }
+        DateAction::Diff => {
+            let output = match DiffOutput::from_str(&date_args.output) {
+                Ok(o) => o,
+                Err(_) => {
+                    return Err(CliError::InvalidArgs(format!(
+                        "Invalid output. Valid outputs are: {}",
+                        dev_utils::enum_variants::<DiffOutput>()
+                    )));
+                }
+            };
+            let start = read_date(content_str)?;
+            let end = match &date_args.end {
+                Some(end) => read_date(end)?,
+                None => now,
+            };
+            match dev_utils::date::diff(start, end, &holidays) {
+                Ok(result) => Ok(result.render(output)),
+                Err(e) => Err(CliError::DateError(e)),
+            }
+        }
+        DateAction::Parse => {
+            let format = date_args.format.as_deref().unwrap_or("rfc3339");
+            match dev_utils::date::parse(content_str, base) {
+                Ok(date) => {
+                    dev_utils::datetime::format(format, &date).map_err(CliError::DateTimeError)
+                }
+                Err(e) => Err(CliError::DateError(e)),
+            }
+        }
+        DateAction::Info => match dev_utils::date::parse(content_str, base) {
+            Ok(date) => dev_utils::calendar::info(date).map_err(CliError::DateError),
             Err(e) => Err(CliError::DateError(e)),
         },
+        DateAction::Week => {
+            dev_utils::calendar::week(content_str, base).map_err(CliError::DateError)
+        }
+        DateAction::Cal => {
+            // Escape codes only belong on a terminal, not in a pipe or a file
+            let highlight = std::io::stdout().is_terminal();
+            dev_utils::calendar::cal(content_str, base, &highlights, highlight)
+                .map_err(CliError::DateError)
+        }
+        DateAction::Humanize => {
+            let target = dev_utils::date::parse(content_str, base).map_err(CliError::DateError)?;
+            match dev_utils::date::humanize(target, base) {
+                Ok(result) => Ok(result),
+                Err(e) => Err(CliError::DateError(e)),
+            }
+        }
     }
 }
 
+fn list_separator(
+    separator: &str,
+    pattern: Option<&str>,
+    output: Option<&str>,
+) -> Result<Separator, CliError> {
+    Separator::parse(separator, pattern, output).map_err(|e| {
+        CliError::InvalidArgs(format!(
+            "Invalid separator regex {}: {}",
+            pattern.unwrap_or(separator),
+            e
+        ))
+    })
+}
+
 pub fn list(list_args: ListArgs, cli_args: Cli) -> Result<String, CliError> {
     let action = match ListAction::from_str(&list_args.action) {
         Ok(a) => a,
@@ -243,29 +523,144 @@ pub fn list(list_args: ListArgs, cli_args: Cli) -> Result<String, CliError> {
     };
     let content = dev_utils::get_content(list_args.content, cli_args.editor)?;
     let content_str = content.as_str();
-    let separator = list_args.separator.as_str();
+    let separator = Separator {
+        trim: list_args.trim,
+        drop_empty: list_args.drop_empty,
+        quoted: list_args.quoted,
+        ..list_separator(
+            &list_args.separator,
+            list_args.separator_regex.as_deref(),
+            list_args.output_separator.as_deref(),
+        )?
+    };
     let index = list_args.index;
     let length = list_args.length;
+    let key = match &list_args.key {
+        Some(key) => Some(
+            regex::Regex::new(key)
+                .map_err(|e| CliError::InvalidArgs(format!("Invalid sort key {}: {}", key, e)))?,
+        ),
+        None => None,
+    };
+    let mode = match (
+        list_args.numeric,
+        list_args.natural,
+        list_args.by_length,
+        list_args.version_sort,
+    ) {
+        (true, _, _, _) => SortMode::Numeric,
+        (_, true, _, _) => SortMode::Natural,
+        (_, _, true, _) => SortMode::Length,
+        (_, _, _, true) => SortMode::Version,
+        _ => SortMode::Lexical,
+    };
+    // The second list of the set actions, split with the same separator
+    let other = match (&list_args.other, &list_args.other_file) {
+        (Some(other), _) => Some(other.clone()),
+        (_, Some(path)) => match std::fs::read_to_string(path) {
+            Ok(other) => Some(
+                other
+                    .strip_suffix('\n')
+                    .map(|o| o.strip_suffix('\r').unwrap_or(o))
+                    .unwrap_or(&other)
+                    .to_string(),
+            ),
+            Err(e) => {
+                return Err(CliError::InvalidArgs(format!(
+                    "Could not read {}: {}",
+                    path, e
+                )))
+            }
+        },
+        (None, None) => None,
+    };
+    let other_list = || match &other {
+        Some(other) => Ok(other.as_str()),
+        None => Err(CliError::InvalidArgs(format!(
+            "{} needs a second list, pass it with --other or --other-file",
+            list_args.action
+        ))),
+    };
+    let group_key = match (&key, list_args.prefix) {
+        (_, Some(length)) => Some(GroupKey::Prefix(length)),
+        (Some(pattern), None) => Some(GroupKey::Pattern(pattern.clone())),
+        (None, None) => None,
+    };
+    let sort_options = SortOptions {
+        mode,
+        ignore_case: list_args.ignore_case,
+        reverse: list_args.reverse,
+        stable: list_args.stable,
+        key,
+    };
 
     match action {
-        ListAction::Sort => Ok(dev_utils::list::sort(content_str, separator)),
-        ListAction::Lowercase => Ok(dev_utils::list::lowercase(content_str, separator)),
-        ListAction::Uppercase => Ok(dev_utils::list::uppercase(content_str, separator)),
+        ListAction::Sort => Ok(dev_utils::list::sort(
+            content_str,
+            &separator,
+            &sort_options,
+        )),
+        ListAction::Lowercase => Ok(dev_utils::list::lowercase(content_str, &separator)),
+        ListAction::Uppercase => Ok(dev_utils::list::uppercase(content_str, &separator)),
         ListAction::Capitalise | ListAction::Capitalize => {
-            Ok(dev_utils::list::capitalise(content_str, separator))
+            Ok(dev_utils::list::capitalise(content_str, &separator))
         }
-        ListAction::Reverse => Ok(dev_utils::list::reverse(content_str, separator)),
+        ListAction::Reverse => Ok(dev_utils::list::reverse(content_str, &separator)),
         ListAction::Deduplicate | ListAction::Unique | ListAction::Dedup => {
-            Ok(dev_utils::list::deduplicate(content_str, separator))
+            Ok(dev_utils::list::deduplicate(content_str, &separator))
         }
-        ListAction::Shuffle => Ok(dev_utils::list::shuffle(content_str, separator)),
+        ListAction::Shuffle => Ok(dev_utils::list::shuffle(content_str, &separator)),
         ListAction::Slice => Ok(dev_utils::list::slice(
             content_str,
-            separator,
+            &separator,
             index,
             length,
         )),
-        ListAction::Count => Ok(dev_utils::list::count(content_str, separator).to_string()),
+        ListAction::Count => Ok(dev_utils::list::count(content_str, &separator).to_string()),
+        ListAction::Union => Ok(dev_utils::list::union(
+            content_str,
+            other_list()?,
+            &separator,
+        )),
+        ListAction::Intersect => Ok(dev_utils::list::intersect(
+            content_str,
+            other_list()?,
+            &separator,
+        )),
+        ListAction::Difference => Ok(dev_utils::list::difference(
+            content_str,
+            other_list()?,
+            &separator,
+        )),
+        ListAction::SymmetricDifference => Ok(dev_utils::list::symmetric_difference(
+            content_str,
+            other_list()?,
+            &separator,
+        )),
+        ListAction::IsSubset => {
+            Ok(dev_utils::list::is_subset(content_str, other_list()?, &separator).to_string())
+        }
+        ListAction::Frequency => Ok(dev_utils::list::frequency(
+            content_str,
+            &separator,
+            list_args.top,
+        )),
+        ListAction::Histogram => Ok(dev_utils::list::histogram(
+            content_str,
+            &separator,
+            list_args.top,
+        )),
+        ListAction::GroupBy => match group_key {
+            Some(group_key) => Ok(dev_utils::list::group_by(
+                content_str,
+                &separator,
+                &group_key,
+                list_args.top,
+            )),
+            None => Err(CliError::InvalidArgs(
+                "group-by needs a --key regex or a --prefix length".to_string(),
+            )),
+        },
     }
 }
 
@@ -286,28 +681,28 @@ pub fn colour(colour_args: ColourArgs, cli_args: Cli) -> Result<String, CliError
         Colour::Hex2Rgb => match dev_utils::colour::hex2rgb(content_str) {
             Ok(rgb) => Ok(rgb),
             Err(e) => Err(CliError::ColourError(format!(
-                "Error while converting hex to rgb: {:#?}",
+                "Error while converting hex to rgb: {}",
                 e,
             ))),
         },
         Colour::Hex2Hsl => match dev_utils::colour::hex2hsl(content_str) {
             Ok(hsl) => Ok(hsl),
             Err(e) => Err(CliError::ColourError(format!(
-                "Error while converting hex to hsl: {:#?}",
+                "Error while converting hex to hsl: {}",
                 e
             ))),
         },
         Colour::Rgb2Hex => match dev_utils::colour::rgb2hex(content_str) {
             Ok(rgb) => Ok(rgb),
             Err(e) => Err(CliError::ColourError(format!(
-                "Error while converting rgb to hex: {:#?}\nFormat should be `rgb(r,g,b)`",
+                "Error while converting rgb to hex: {}\nFormat should be `rgb(r,g,b)`",
                 e
             ))),
         },
         Colour::Hsl2Hex => match dev_utils::colour::hsl2hex(content_str) {
             Ok(hsl) => Ok(hsl),
             Err(e) => Err(CliError::ColourError(format!(
-                "Error while converting hsl to hex: {:#?}\nFormat should be `hsl(h,s,l)`",
+                "Error while converting hsl to hex: {}\nFormat should be `hsl(h,s,l)`",
                 e
             ))),
         },
@@ -367,6 +762,29 @@ pub fn generate(generate_args: GenerateArgs) -> Result<String, CliError> {
     }
 }
 
+fn number_locale(locale: &str) -> Result<NumberLocale, CliError> {
+    match NumberLocale::from_str(locale) {
+        Ok(l) => Ok(l),
+        Err(_) => Err(CliError::InvalidArgs(format!(
+            "Invalid locale. Valid locales are: {}",
+            dev_utils::enum_variants::<NumberLocale>()
+        ))),
+    }
+}
+
+fn percentage_number(
+    number: Option<MaybeStdin<String>>,
+    locale: NumberLocale,
+) -> Result<f64, CliError> {
+    match number {
+        Some(n) => match dev_utils::number::parse(&n, locale) {
+            Ok(v) => Ok(v),
+            Err(e) => Err(CliError::PercentageError(e.message)),
+        },
+        None => Err(CliError::NoDataProvided),
+    }
+}
+
 pub fn percentage(percentage_args: PercentageArgs) -> Result<String, CliError> {
     let action = match PercentageAction::from_str(&percentage_args.action) {
         Ok(a) => a,
@@ -377,83 +795,36 @@ pub fn percentage(percentage_args: PercentageArgs) -> Result<String, CliError> {
             )));
         }
     };
+    let locale = number_locale(&percentage_args.locale)?;
+    let format = NumberFormat {
+        locale,
+        ..NumberFormat::fixed(percentage_args.precision.map_or(0, |p| *p))
+    };
 
     match action {
         PercentageAction::To => {
-            let from;
-            let to;
-            let precision;
-            if let Some(f) = percentage_args.from_number {
-                from = *f;
-            } else {
-                return Err(CliError::NoDataProvided);
-            }
-
-            if let Some(t) = percentage_args.to_number {
-                to = *t;
-            } else {
-                return Err(CliError::NoDataProvided);
-            }
-
-            if let Some(p) = percentage_args.precision {
-                precision = *p;
-            } else {
-                precision = 0;
-            }
+            let from = percentage_number(percentage_args.from_number, locale)?;
+            let to = percentage_number(percentage_args.to_number, locale)?;
 
-            match dev_utils::percentage::to(from, to, precision) {
+            match dev_utils::percentage::to(from, to, &format) {
                 Ok(result) => Ok(result),
                 Err(e) => Err(CliError::PercentageError(e)),
             }
         }
         PercentageAction::Of => {
-            let of;
-            let percentage;
-            let precision;
-
-            if let Some(o) = percentage_args.of_number {
-                of = *o;
-            } else {
-                return Err(CliError::NoDataProvided);
-            }
-            if let Some(p) = percentage_args.percentage {
-                percentage = *p;
-            } else {
-                return Err(CliError::NoDataProvided);
-            }
-            if let Some(p) = percentage_args.precision {
-                precision = *p;
-            } else {
-                precision = 0;
-            }
+            let of = percentage_number(percentage_args.of_number, locale)?;
+            let percentage = percentage_number(percentage_args.percentage, locale)?;
 
-            match dev_utils::percentage::of(percentage, of, precision) {
+            match dev_utils::percentage::of(percentage as f32, of, &format) {
                 Ok(result) => Ok(result),
                 Err(e) => Err(CliError::PercentageError(e)),
             }
         }
         PercentageAction::Change => {
-            let from;
-            let to;
-            let precision;
+            let from = percentage_number(percentage_args.from_number, locale)?;
+            let to = percentage_number(percentage_args.to_number, locale)?;
 
-            if let Some(f) = percentage_args.from_number {
-                from = *f;
-            } else {
-                return Err(CliError::NoDataProvided);
-            };
-            if let Some(t) = percentage_args.to_number {
-                to = *t;
-            } else {
-                return Err(CliError::NoDataProvided);
-            };
-            if let Some(p) = percentage_args.precision {
-                precision = *p;
-            } else {
-                precision = 0;
-            };
-
-            match dev_utils::percentage::change(from, to, precision) {
+            match dev_utils::percentage::change(from, to, &format) {
                 Ok(result) => Ok(result),
                 Err(e) => Err(CliError::PercentageError(e)),
             }
@@ -492,3 +863,246 @@ pub fn regex(regex_args: RegexArgs) -> Result<String, CliError> {
         }
     }
 }
+
+pub fn case(case_args: CaseArgs, cli_args: Cli) -> Result<String, CliError> {
+    let case = match CaseAction::from_str(&case_args.case) {
+        Ok(c) => c,
+        Err(_) => {
+            return Err(CliError::InvalidArgs(format!(
+                "Invalid case. Valid cases are: {}",
+                dev_utils::enum_variants::<CaseAction>()
+            )));
+        }
+    };
+    let acronyms = match AcronymMode::from_str(&case_args.acronyms) {
+        Ok(a) => a,
+        Err(_) => {
+            return Err(CliError::InvalidArgs(format!(
+                "Invalid acronym mode. Valid modes are: {}",
+                dev_utils::enum_variants::<AcronymMode>()
+            )));
+        }
+    };
+    let content = dev_utils::get_content(case_args.content, cli_args.editor)?;
+    let content_str = content.as_str();
+
+    match case_args.separator {
+        Some(separator) => Ok(dev_utils::list::convert_case(
+            content_str,
+            &list_separator(&separator, None, None)?,
+            case,
+            acronyms,
+        )),
+        None => Ok(dev_utils::case::convert(content_str, case, acronyms)),
+    }
+}
+
+fn escape_format(format: &str) -> Result<EscapeFormat, CliError> {
+    match EscapeFormat::from_str(format) {
+        Ok(f) => Ok(f),
+        Err(_) => Err(CliError::InvalidArgs(format!(
+            "Invalid format. Valid formats are: {}",
+            dev_utils::enum_variants::<EscapeFormat>()
+        ))),
+    }
+}
+
+pub fn escape(escape_args: EscapeArgs, cli_args: Cli) -> Result<String, CliError> {
+    let format = escape_format(&escape_args.format)?;
+    let content = dev_utils::get_content(escape_args.content, cli_args.editor)?;
+
+    Ok(dev_utils::escape::escape(
+        content.as_str(),
+        format,
+        escape_args.ascii,
+    ))
+}
+
+pub fn unescape(escape_args: EscapeArgs, cli_args: Cli) -> Result<String, CliError> {
+    let format = escape_format(&escape_args.format)?;
+    let content = dev_utils::get_content(escape_args.content, cli_args.editor)?;
+
+    match dev_utils::escape::unescape(content.as_str(), format) {
+        Ok(result) => Ok(result),
+        Err(e) => Err(CliError::EscapeError(e)),
+    }
+}
+
+pub fn unicode(unicode_args: UnicodeArgs, cli_args: Cli) -> Result<String, CliError> {
+    let action = match UnicodeAction::from_str(&unicode_args.action) {
+        Ok(a) => a,
+        Err(_) => {
+            return Err(CliError::InvalidArgs(format!(
+                "Invalid action. Valid actions are: {}",
+                dev_utils::enum_variants::<UnicodeAction>()
+            )));
+        }
+    };
+    let content = dev_utils::get_content(unicode_args.content, cli_args.editor)?;
+    let content_str = content.as_str();
+
+    match action {
+        UnicodeAction::Inspect => Ok(dev_utils::unicode::inspect(content_str)),
+        UnicodeAction::Nfc => Ok(dev_utils::unicode::nfc(content_str)),
+        UnicodeAction::Nfd => Ok(dev_utils::unicode::nfd(content_str)),
+        UnicodeAction::Nfkc => Ok(dev_utils::unicode::nfkc(content_str)),
+        UnicodeAction::Nfkd => Ok(dev_utils::unicode::nfkd(content_str)),
+        UnicodeAction::StripInvisibles => Ok(dev_utils::unicode::strip_invisibles(content_str)),
+    }
+}
+
+fn byte_format(format: &str) -> Result<ByteFormat, CliError> {
+    match ByteFormat::from_str(format) {
+        Ok(f) => Ok(f),
+        Err(_) => Err(CliError::InvalidArgs(format!(
+            "Invalid format. Valid formats are: {}",
+            dev_utils::enum_variants::<ByteFormat>()
+        ))),
+    }
+}
+
+fn cipher_key(key: Option<String>) -> Result<String, CliError> {
+    match key {
+        Some(k) => Ok(k),
+        None => Err(CliError::InvalidArgs(
+            "This cipher needs a key, pass it with --key".to_string(),
+        )),
+    }
+}
+
+pub fn cipher(cipher_args: CipherArgs, cli_args: Cli) -> Result<String, CliError> {
+    let action = match CipherAction::from_str(&cipher_args.cipher) {
+        Ok(a) => a,
+        Err(_) => {
+            return Err(CliError::InvalidArgs(format!(
+                "Invalid cipher. Valid ciphers are: {}",
+                dev_utils::enum_variants::<CipherAction>()
+            )));
+        }
+    };
+    let input = byte_format(&cipher_args.input)?;
+    let output = byte_format(&cipher_args.output)?;
+    let content = dev_utils::get_content(cipher_args.content, cli_args.editor)?;
+    let content_str = content.as_str();
+
+    let result = match action {
+        CipherAction::Rot13 => Ok(dev_utils::cipher::rot13(content_str)),
+        CipherAction::Rot47 => Ok(dev_utils::cipher::rot47(content_str)),
+        CipherAction::Caesar => {
+            let shift = if cipher_args.decode {
+                -cipher_args.shift
+            } else {
+                cipher_args.shift
+            };
+            Ok(dev_utils::cipher::caesar(content_str, shift))
+        }
+        CipherAction::CaesarBruteforce => Ok(dev_utils::cipher::caesar_bruteforce(content_str)),
+        CipherAction::Atbash => Ok(dev_utils::cipher::atbash(content_str)),
+        CipherAction::Vigenere => dev_utils::cipher::vigenere(
+            content_str,
+            &cipher_key(cipher_args.key)?,
+            cipher_args.decode,
+        ),
+        CipherAction::Xor => {
+            dev_utils::cipher::xor(content_str, &cipher_key(cipher_args.key)?, input, output)
+        }
+    };
+
+    match result {
+        Ok(s) => Ok(s),
+        Err(e) => Err(CliError::CipherError(e)),
+    }
+}
+
+pub fn id(id_args: IdArgs, cli_args: Cli) -> Result<String, CliError> {
+    let kind = match IdKind::from_str(&id_args.kind) {
+        Ok(k) => k,
+        Err(_) => {
+            return Err(CliError::InvalidArgs(format!(
+                "Invalid kind. Valid kinds are: {}",
+                dev_utils::enum_variants::<IdKind>()
+            )));
+        }
+    };
+    let epoch = dev_utils::id::snowflake_epoch(&id_args.epoch).map_err(CliError::IdError)?;
+    let content = dev_utils::get_content(id_args.content, cli_args.editor)?;
+
+    match dev_utils::id::inspect(content.as_str(), kind, epoch) {
+        Ok(result) => Ok(result),
+        Err(e) => Err(CliError::IdError(e)),
+    }
+}
+
+pub fn cron(cron_args: CronArgs, cli_args: Cli) -> Result<String, CliError> {
+    let action = match CronAction::from_str(&cron_args.action) {
+        Ok(a) => a,
+        Err(_) => {
+            return Err(CliError::InvalidArgs(format!(
+                "Invalid action. Valid actions are: {}",
+                dev_utils::enum_variants::<CronAction>()
+            )));
+        }
+    };
+    let zone = Zone::from_str(&cron_args.tz).map_err(CliError::DateTimeError)?;
+    let now = chrono::Utc::now().fixed_offset();
+    let from = match &cron_args.from {
+        Some(from) => dev_utils::date::parse(from, now).map_err(CliError::DateError)?,
+        None => now,
+    };
+    let content = dev_utils::get_content(cron_args.content, cli_args.editor)?;
+    let content_str = content.as_str();
+
+    let result = match action {
+        CronAction::Validate => dev_utils::cron::parse(content_str).map(|s| s.validate()),
+        CronAction::Explain => dev_utils::cron::parse(content_str).map(|s| s.explain()),
+        CronAction::Next => {
+            dev_utils::cron::fire_times(content_str, from, &zone, cron_args.count, false)
+        }
+        CronAction::Previous => {
+            dev_utils::cron::fire_times(content_str, from, &zone, cron_args.count, true)
+        }
+    };
+
+    match result {
+        Ok(s) => Ok(s),
+        Err(e) => Err(CliError::CronError(e)),
+    }
+}
+
+pub fn rrule(rrule_args: RruleArgs, cli_args: Cli) -> Result<String, CliError> {
+    let action = match RruleAction::from_str(&rrule_args.action) {
+        Ok(a) => a,
+        Err(_) => {
+            return Err(CliError::InvalidArgs(format!(
+                "Invalid action. Valid actions are: {}",
+                dev_utils::enum_variants::<RruleAction>()
+            )));
+        }
+    };
+    let zone = Zone::from_str(&rrule_args.tz).map_err(CliError::DateTimeError)?;
+    let now = chrono::Utc::now().fixed_offset();
+    let read_date = |date: &Option<String>| match date {
+        Some(date) => dev_utils::date::parse(date, now)
+            .map(Some)
+            .map_err(CliError::DateError),
+        None => Ok(None),
+    };
+    let start = read_date(&rrule_args.start)?.unwrap_or(now);
+    let from = read_date(&rrule_args.from)?;
+    let until = read_date(&rrule_args.until)?;
+    let content = dev_utils::get_content(rrule_args.content, cli_args.editor)?;
+
+    let set =
+        dev_utils::rrule::parse(content.as_str(), start, zone).map_err(CliError::RruleError)?;
+    let result = match action {
+        RruleAction::Explain => Ok(set.explain()),
+        RruleAction::Expand => {
+            dev_utils::rrule::expand(&set, from, until, rrule_args.count, &rrule_args.format)
+        }
+    };
+
+    match result {
+        Ok(s) => Ok(s),
+        Err(e) => Err(CliError::RruleError(e)),
+    }
+}
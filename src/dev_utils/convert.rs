@@ -2,6 +2,7 @@ use csv;
 use flatten_json_object::ArrayFormatting;
 use flatten_json_object::Flattener;
 use json_objects_to_csv::{Error, Json2Csv};
+use regex::Regex;
 use slug;
 use std::fmt::Display;
 use std::fmt::Formatter;
@@ -10,6 +11,10 @@ use std::str::Utf8Error;
 use strum_macros::EnumString;
 use strum_macros::{EnumIter, EnumVariantNames};
 
+use super::roman::{self, RomanError};
+use super::spelling::{self, Alphabet};
+use super::units;
+
 #[derive(EnumIter, EnumString, EnumVariantNames)]
 #[strum(serialize_all = "lowercase")]
 pub enum Conversion {
@@ -19,6 +24,9 @@ pub enum Conversion {
     String2Hex,
     Hex2String,
     Text2Nato,
+    Nato2Text,
+    Text2Morse,
+    Morse2Text,
     Slugify,
     // TODO find a way to list all patterns when printing out valid actions, e.g.
     // #[strum(serialize = "celsius2fahrenheit", serialize = "c2f")]
@@ -49,6 +57,30 @@ pub enum Conversion {
     ToOrdinal,
     #[allow(non_camel_case_types)]
     To_Ordinal,
+    Units,
+    Bytes2Human,
+    Human2Bytes,
+    Seconds2Human,
+    Seconds2Iso8601,
+    Human2Seconds,
+    Number2Words,
+    Words2Number,
+    Ordinal2Words,
+    Currency2Words,
+    FormatNumber,
+    ParseNumber,
+}
+
+#[derive(EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum TextEncoding {
+    #[strum(serialize = "utf8", serialize = "utf-8")]
+    Utf8,
+    #[strum(serialize = "utf16", serialize = "utf-16")]
+    Utf16,
+    #[strum(serialize = "latin1", serialize = "latin-1")]
+    Latin1,
+    Ascii,
 }
 
 #[derive(Debug)]
@@ -64,15 +96,50 @@ pub enum ConversionError {
     Utf8Error(Utf8Error),
     Hex2String(String),
     TemperatureConversion(String),
+    Text2AsciiBinary(String),
     AsciiBinary2Text(String),
     DistanceConversion(String),
     WeightConversion(String),
     NumberConversion(String),
+    UnitConversion(String),
+    ByteSizeConversion(String),
+    DurationConversion(String),
+    SpellingConversion(String),
+    MorseConversion(String),
+    RomanNumeral(RomanError),
+    WordsConversion(String),
+}
+
+impl Display for JsonYamlErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonYamlErrors::JsonError(e) => write!(f, "{}", e),
+            JsonYamlErrors::YamlError(e) => write!(f, "{}", e),
+        }
+    }
 }
 
 impl Display for ConversionError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            ConversionError::Json2Csv(e) => write!(f, "{}", e),
+            ConversionError::Json2Yaml(e) => write!(f, "{}", e),
+            ConversionError::Utf8Error(e) => write!(f, "{}", e),
+            ConversionError::RomanNumeral(e) => write!(f, "{}", e),
+            ConversionError::Hex2String(message)
+            | ConversionError::TemperatureConversion(message)
+            | ConversionError::Text2AsciiBinary(message)
+            | ConversionError::AsciiBinary2Text(message)
+            | ConversionError::DistanceConversion(message)
+            | ConversionError::WeightConversion(message)
+            | ConversionError::NumberConversion(message)
+            | ConversionError::UnitConversion(message)
+            | ConversionError::ByteSizeConversion(message)
+            | ConversionError::DurationConversion(message)
+            | ConversionError::SpellingConversion(message)
+            | ConversionError::MorseConversion(message)
+            | ConversionError::WordsConversion(message) => write!(f, "{}", message),
+        }
     }
 }
 
@@ -132,21 +199,31 @@ pub fn csv2tsv(data: &str) -> String {
 }
 
 pub fn string2hex(data: &str) -> String {
+    bytes2hex(data.as_bytes())
+}
+
+pub fn bytes2hex(data: &[u8]) -> String {
     let mut result: String = String::new();
-    for byte in data.bytes() {
+    for byte in data {
         result += &format!("{:02x}", byte);
     }
-    result.trim().to_string()
+    result
 }
 
 pub fn hex2string(data: &str) -> Result<String, ConversionError> {
-    if data.len() % 2 != 0 {
+    let bytes = hex2bytes(data)?;
+
+    String::from_utf8(bytes)
+        .map_err(|_| ConversionError::Hex2String("Invalid hex string".to_string()))
+}
+
+pub fn hex2bytes(data: &str) -> Result<Vec<u8>, ConversionError> {
+    if !data.len().is_multiple_of(2) {
         return Err(ConversionError::Hex2String(
             "Hex string must have an even number of characters".to_string(),
         ));
     }
 
-    let mut result: String = String::new();
     let mut bytes = Vec::new();
     let mut hex_iter = data.chars();
 
@@ -172,51 +249,181 @@ pub fn hex2string(data: &str) -> Result<String, ConversionError> {
         bytes.push(byte);
     }
 
-    result.push_str(
-        &String::from_utf8(bytes)
-            .map_err(|_| ConversionError::Hex2String("Invalid hex string".to_string()))?,
-    );
-
-    Ok(result)
+    Ok(bytes)
 }
 
-pub fn text2nato(content: &str) -> String {
+pub fn text2nato(content: &str, alphabet: Alphabet) -> String {
+    let table = alphabet.table();
     let mut result = String::new();
     for ch in content.chars() {
-        let ch = match ch {
-            'a' | 'A' => "Alpha".to_string(),
-            'b' | 'B' => "Bravo".to_string(),
-            'c' | 'C' => "Charlie".to_string(),
-            'd' | 'D' => "Delta".to_string(),
-            'e' | 'E' => "Echo".to_string(),
-            'f' | 'F' => "Foxtrot".to_string(),
-            'g' | 'G' => "Golf".to_string(),
-            'h' | 'H' => "Hotel".to_string(),
-            'i' | 'I' => "India".to_string(),
-            'j' | 'J' => "Juliet".to_string(),
-            'k' | 'K' => "Kilo".to_string(),
-            'l' | 'L' => "Lima".to_string(),
-            'm' | 'M' => "Mike".to_string(),
-            'n' | 'N' => "November".to_string(),
-            'o' | 'O' => "Oscar".to_string(),
-            'p' | 'P' => "Papa".to_string(),
-            'q' | 'Q' => "Quebec".to_string(),
-            'r' | 'R' => "Romeo".to_string(),
-            's' | 'S' => "Sierra".to_string(),
-            't' | 'T' => "Tango".to_string(),
-            'u' | 'U' => "Uniform".to_string(),
-            'v' | 'V' => "Victor".to_string(),
-            'w' | 'W' => "Whiskey".to_string(),
-            'x' | 'X' => "X-ray".to_string(),
-            'y' | 'Y' => "Yankee".to_string(),
-            'z' | 'Z' => "Zulu".to_string(),
-            _ => ch.to_string(),
+        let ch = match table.word(ch) {
+            Some(word) => word.to_string(),
+            None => ch.to_string(),
         };
         result.push_str(&format!("{} ", &ch));
     }
     result.trim().to_string()
 }
 
+// Spelled words are separated by a single space, while a space in the original text shows
+// up as a run of several spaces (see `text2nato`).
+pub fn nato2text(content: &str, alphabet: Alphabet) -> Result<String, ConversionError> {
+    let table = alphabet.table();
+    let word_break = match Regex::new(r"\s{2,}") {
+        Ok(r) => r,
+        Err(e) => return Err(ConversionError::SpellingConversion(e.to_string())),
+    };
+
+    let mut words = vec![];
+    for spelled_word in word_break.split(content.trim()) {
+        let mut word = String::new();
+        for token in spelled_word.split_whitespace() {
+            match table.letter(token) {
+                Some(c) => word.push(c),
+                None if token.chars().count() == 1 => word.push_str(token),
+                None => {
+                    return Err(ConversionError::SpellingConversion(format!(
+                        "Unknown spelling word {}",
+                        token
+                    )))
+                }
+            }
+        }
+        words.push(word);
+    }
+
+    Ok(words.join(" "))
+}
+
+pub struct MorseOptions<'a> {
+    pub letter_separator: &'a str,
+    pub word_separator: &'a str,
+    // Write the signal as timing units instead of dots and dashes: `=` is a dit, `===` a dah
+    // and each `.` one unit of silence
+    pub timing: bool,
+}
+
+const TIMING_ELEMENT_GAP: &str = ".";
+const TIMING_LETTER_GAP: &str = "...";
+const TIMING_WORD_GAP: &str = ".......";
+
+pub fn text2morse(content: &str, options: &MorseOptions) -> Result<String, ConversionError> {
+    let mut words = vec![];
+    for word in content.split_whitespace() {
+        let mut letters = vec![];
+        for c in word.chars() {
+            let code = match spelling::morse_code(c) {
+                Some(code) => code,
+                None => {
+                    return Err(ConversionError::MorseConversion(format!(
+                        "Cannot encode {} in Morse code",
+                        c
+                    )))
+                }
+            };
+            if options.timing {
+                letters.push(
+                    code.chars()
+                        .map(|e| if e == '.' { "=" } else { "===" })
+                        .collect::<Vec<&str>>()
+                        .join(TIMING_ELEMENT_GAP),
+                );
+            } else {
+                letters.push(code.to_string());
+            }
+        }
+        if options.timing {
+            words.push(letters.join(TIMING_LETTER_GAP));
+        } else {
+            words.push(letters.join(options.letter_separator));
+        }
+    }
+
+    if options.timing {
+        Ok(words.join(TIMING_WORD_GAP))
+    } else {
+        Ok(words.join(options.word_separator))
+    }
+}
+
+fn morse_letter(code: &str) -> Result<char, ConversionError> {
+    match spelling::morse_letter(code) {
+        Some(c) => Ok(c),
+        None => Err(ConversionError::MorseConversion(format!(
+            "Unknown Morse code {}",
+            code
+        ))),
+    }
+}
+
+// Separators are matched without their surrounding whitespace so that `/` and ` / ` both work.
+// A whitespace-only word separator is matched literally, a whitespace-only letter separator
+// matches any run of whitespace.
+fn split_on<'a>(content: &'a str, separator: &str, words: bool) -> Vec<&'a str> {
+    if separator.trim().is_empty() && words {
+        content
+            .split(separator)
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .collect()
+    } else if separator.trim().is_empty() {
+        content.split_whitespace().collect()
+    } else {
+        content
+            .split(separator.trim())
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+}
+
+pub fn morse2text(content: &str, options: &MorseOptions) -> Result<String, ConversionError> {
+    let content = content.trim();
+    let mut words = vec![];
+
+    // Plain Morse made only of dots, such as `...`, has no `=` and is not timing notation
+    if content.contains('=') && content.chars().all(|c| c == '=' || c == '.') {
+        for word in content.split(TIMING_WORD_GAP) {
+            let mut letters = String::new();
+            for letter in word.split(TIMING_LETTER_GAP) {
+                let code = letter
+                    .split(TIMING_ELEMENT_GAP)
+                    .map(|e| match e {
+                        "=" => Ok('.'),
+                        "===" => Ok('-'),
+                        _ => Err(ConversionError::MorseConversion(format!(
+                            "Invalid timing element {}",
+                            e
+                        ))),
+                    })
+                    .collect::<Result<String, ConversionError>>()?;
+                letters.push(morse_letter(&code)?);
+            }
+            words.push(letters);
+        }
+        return Ok(words.join(" "));
+    }
+
+    let normalised = content
+        .chars()
+        .map(|c| match c {
+            '·' | '•' => '.',
+            '−' | '–' | '—' | '_' => '-',
+            _ => c,
+        })
+        .collect::<String>();
+
+    for word in split_on(&normalised, options.word_separator, true) {
+        let mut letters = String::new();
+        for code in split_on(word, options.letter_separator, false) {
+            letters.push(morse_letter(code)?);
+        }
+        words.push(letters);
+    }
+
+    Ok(words.join(" "))
+}
+
 pub fn slugify(data: &str) -> String {
     slug::slugify(data)
 }
@@ -231,8 +438,7 @@ pub fn celsius2fahrenheit(data: &str) -> Result<f64, ConversionError> {
             )))
         }
     };
-    let result = (celsius_temp * 9.0 / 5.0) + 32.0;
-    Ok(result)
+    units::convert_value(celsius_temp, "C", "F")
 }
 
 pub fn fahrenheit2celsius(data: &str) -> Result<f64, ConversionError> {
@@ -245,8 +451,7 @@ pub fn fahrenheit2celsius(data: &str) -> Result<f64, ConversionError> {
             )))
         }
     };
-    let result = (fahrenheit_temp - 32.0) * 5.0 / 9.0;
-    Ok(result)
+    units::convert_value(fahrenheit_temp, "F", "C")
 }
 
 pub fn celsius2kelvin(data: &str) -> Result<f64, ConversionError> {
@@ -259,8 +464,7 @@ pub fn celsius2kelvin(data: &str) -> Result<f64, ConversionError> {
             )))
         }
     };
-    let result = celsius_temp + 273.15;
-    Ok(result)
+    units::convert_value(celsius_temp, "C", "K")
 }
 
 pub fn kelvin2celsius(data: &str) -> Result<f64, ConversionError> {
@@ -273,8 +477,7 @@ pub fn kelvin2celsius(data: &str) -> Result<f64, ConversionError> {
             )))
         }
     };
-    let result = kelvin_temp - 273.15;
-    Ok(result)
+    units::convert_value(kelvin_temp, "K", "C")
 }
 
 pub fn fahrenheit2kelvin(data: &str) -> Result<f64, ConversionError> {
@@ -287,8 +490,7 @@ pub fn fahrenheit2kelvin(data: &str) -> Result<f64, ConversionError> {
             )))
         }
     };
-    let result = (fahrenheit_temp - 32.0) * 5.0 / 9.0 + 273.15;
-    Ok(result)
+    units::convert_value(fahrenheit_temp, "F", "K")
 }
 
 pub fn kelvin2fahrenheit(data: &str) -> Result<f64, ConversionError> {
@@ -301,23 +503,21 @@ pub fn kelvin2fahrenheit(data: &str) -> Result<f64, ConversionError> {
             )))
         }
     };
-    let result = (kelvin_temp - 273.15) * 9.0 / 5.0 + 32.0;
-    Ok(result)
+    units::convert_value(kelvin_temp, "K", "F")
 }
 
-pub fn text2asciibinary(data: &str) -> Result<String, ConversionError> {
-    let mut result = String::new();
-
-    data.chars().for_each(|c| {
-        let byte = c as u8;
-        result.push_str(&format!("{:08b} ", byte));
-    });
+pub fn text2asciibinary(data: &str, encoding: &TextEncoding) -> Result<String, ConversionError> {
+    let bytes = encode_text(data, encoding)?;
 
-    Ok(result.trim_end().to_string())
+    Ok(bytes
+        .iter()
+        .map(|byte| format!("{:08b}", byte))
+        .collect::<Vec<String>>()
+        .join(" "))
 }
 
-pub fn asciibinary2text(data: &str) -> Result<String, ConversionError> {
-    let mut result = String::new();
+pub fn asciibinary2text(data: &str, encoding: &TextEncoding) -> Result<String, ConversionError> {
+    let mut bytes = Vec::new();
     for byte in data.split_whitespace() {
         let byte = match u8::from_str_radix(byte, 2) {
             Ok(b) => b,
@@ -328,13 +528,82 @@ pub fn asciibinary2text(data: &str) -> Result<String, ConversionError> {
                 )))
             }
         };
-        result.push(byte as char);
+        bytes.push(byte);
+    }
+
+    decode_text(&bytes, encoding)
+}
+
+fn encode_text(data: &str, encoding: &TextEncoding) -> Result<Vec<u8>, ConversionError> {
+    match encoding {
+        TextEncoding::Utf8 => Ok(data.as_bytes().to_vec()),
+        TextEncoding::Utf16 => Ok(data
+            .encode_utf16()
+            .flat_map(|unit| unit.to_be_bytes())
+            .collect()),
+        TextEncoding::Latin1 => data
+            .chars()
+            .map(|c| match u8::try_from(c) {
+                Ok(b) => Ok(b),
+                Err(_) => Err(ConversionError::Text2AsciiBinary(format!(
+                    "Character {} cannot be encoded as Latin-1",
+                    c
+                ))),
+            })
+            .collect(),
+        TextEncoding::Ascii => data
+            .chars()
+            .map(|c| {
+                if c.is_ascii() {
+                    Ok(c as u8)
+                } else {
+                    Err(ConversionError::Text2AsciiBinary(format!(
+                        "Non-ASCII character {} (U+{:04X})",
+                        c, c as u32
+                    )))
+                }
+            })
+            .collect(),
     }
+}
 
-    Ok(result)
+fn decode_text(bytes: &[u8], encoding: &TextEncoding) -> Result<String, ConversionError> {
+    match encoding {
+        TextEncoding::Utf8 => String::from_utf8(bytes.to_vec()).map_err(|e| {
+            ConversionError::AsciiBinary2Text(format!("Invalid UTF-8 sequence: {}", e))
+        }),
+        TextEncoding::Utf16 => {
+            if !bytes.len().is_multiple_of(2) {
+                return Err(ConversionError::AsciiBinary2Text(
+                    "UTF-16 input must have an even number of bytes".to_string(),
+                ));
+            }
+            let units = bytes
+                .chunks(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect::<Vec<u16>>();
+            String::from_utf16(&units).map_err(|e| {
+                ConversionError::AsciiBinary2Text(format!("Invalid UTF-16 sequence: {}", e))
+            })
+        }
+        TextEncoding::Latin1 => Ok(bytes.iter().map(|b| *b as char).collect()),
+        TextEncoding::Ascii => bytes
+            .iter()
+            .map(|b| {
+                if b.is_ascii() {
+                    Ok(*b as char)
+                } else {
+                    Err(ConversionError::AsciiBinary2Text(format!(
+                        "Non-ASCII byte {:08b}",
+                        b
+                    )))
+                }
+            })
+            .collect(),
+    }
 }
 
-pub fn kilometers2miles(data: &str) -> Result<String, ConversionError> {
+pub fn kilometers2miles(data: &str) -> Result<f64, ConversionError> {
     let kilometers = match data.parse::<f64>() {
         Ok(v) => v,
         Err(_) => {
@@ -344,11 +613,10 @@ pub fn kilometers2miles(data: &str) -> Result<String, ConversionError> {
             )))
         }
     };
-    let result = kilometers * 0.621371;
-    Ok(result.to_string())
+    units::convert_value(kilometers, "km", "mi")
 }
 
-pub fn miles2kilometers(data: &str) -> Result<String, ConversionError> {
+pub fn miles2kilometers(data: &str) -> Result<f64, ConversionError> {
     let miles = match data.parse::<f64>() {
         Ok(v) => v,
         Err(_) => {
@@ -358,11 +626,10 @@ pub fn miles2kilometers(data: &str) -> Result<String, ConversionError> {
             )))
         }
     };
-    let result = miles / 0.621371;
-    Ok(result.to_string())
+    units::convert_value(miles, "mi", "km")
 }
 
-pub fn pounds2kilos(data: &str) -> Result<String, ConversionError> {
+pub fn pounds2kilos(data: &str) -> Result<f64, ConversionError> {
     let pounds = match data.parse::<f64>() {
         Ok(v) => v,
         Err(_) => {
@@ -372,11 +639,10 @@ pub fn pounds2kilos(data: &str) -> Result<String, ConversionError> {
             )))
         }
     };
-    let result = pounds * 0.453592;
-    Ok(result.to_string())
+    units::convert_value(pounds, "lb", "kg")
 }
 
-pub fn kilos2pounds(data: &str) -> Result<String, ConversionError> {
+pub fn kilos2pounds(data: &str) -> Result<f64, ConversionError> {
     let kilos = match data.parse::<f64>() {
         Ok(v) => v,
         Err(_) => {
@@ -386,12 +652,11 @@ pub fn kilos2pounds(data: &str) -> Result<String, ConversionError> {
             )))
         }
     };
-    let result = kilos / 0.453592;
-    Ok(result.to_string())
+    units::convert_value(kilos, "kg", "lb")
 }
 
-pub fn arabic2roman(data: &str) -> Result<String, ConversionError> {
-    let mut value = match data.parse::<i32>() {
+pub fn arabic2roman(data: &str, vinculum: bool) -> Result<String, ConversionError> {
+    let value = match data.trim().parse::<i64>() {
         Ok(v) => v,
         Err(_) => {
             return Err(ConversionError::NumberConversion(format!(
@@ -400,55 +665,15 @@ pub fn arabic2roman(data: &str) -> Result<String, ConversionError> {
             )))
         }
     };
-    let letters = [
-        "M", "CM", "D", "CD", "C", "XC", "L", "XL", "X", "IX", "V", "IV", "I",
-    ];
-    let mut result: String = "".to_string();
-    let lookup_values = [1000, 900, 500, 400, 100, 90, 50, 40, 10, 9, 5, 4, 1];
-    let mut i = 0;
-    while value >= 0 && i < lookup_values.len() as i32 {
-        while value >= lookup_values[i as usize] {
-            value -= lookup_values[i as usize];
-            result += letters[i as usize];
-        }
-        i += 1;
-    }
-    Ok(result)
+
+    roman::to_roman(value, vinculum).map_err(ConversionError::RomanNumeral)
 }
 
 pub fn roman2arabic(data: &str) -> Result<String, ConversionError> {
-    let mut result = 0;
-    let mut roman_numeral = data.to_string();
-    let letters = [
-        ("M", 1000),
-        ("CM", 900),
-        ("D", 500),
-        ("CD", 400),
-        ("C", 100),
-        ("XC", 90),
-        ("L", 50),
-        ("XL", 40),
-        ("X", 10),
-        ("IX", 9),
-        ("V", 5),
-        ("IV", 4),
-        ("I", 1),
-    ];
-    for (letter, value) in letters.iter() {
-        while roman_numeral.starts_with(letter) {
-            result += value;
-            #[allow(clippy::absurd_extreme_comparisons)]
-            if roman_numeral.len() - letter.len() <= 0 {
-                return Ok(result.to_string());
-            }
-            roman_numeral = roman_numeral[letter.len()..].to_string();
-        }
+    match roman::from_roman(data) {
+        Ok(value) => Ok(value.to_string()),
+        Err(e) => Err(ConversionError::RomanNumeral(e)),
     }
-    println!("e:{}", data);
-    Err(ConversionError::NumberConversion(format!(
-        "Cannot convert {} to a number",
-        data
-    )))
 }
 
 pub fn to_ordinal(content: &str) -> String {
@@ -2,6 +2,8 @@ use crate::dev_utils::convert::*;
 
 mod tests {
     use super::*;
+    use crate::dev_utils::roman::RomanError;
+    use crate::dev_utils::spelling::Alphabet;
 
     #[test]
     fn test_json2csv() {
@@ -65,8 +67,173 @@ mod tests {
 
     #[test]
     fn test_text2nato() {
-        let result = text2nato("abc");
+        let result = text2nato("abc", Alphabet::Nato);
         assert_eq!(result, "Alpha Bravo Charlie");
+
+        let result = text2nato("a1 9.", Alphabet::Nato);
+        assert_eq!(result, "Alpha One   Niner Stop");
+
+        let result = text2nato("Kö#", Alphabet::Din);
+        assert_eq!(result, "Köln Umlaut-Offenbach #");
+
+        let result = text2nato("ßÄä", Alphabet::Din);
+        assert_eq!(result, "Eszett Umlaut-Aachen Umlaut-Aachen");
+
+        let result = text2nato("ß", Alphabet::German);
+        assert_eq!(result, "Eszett");
+
+        let result = text2nato("ab", Alphabet::Police);
+        assert_eq!(result, "Adam Boy");
+    }
+
+    #[test]
+    fn test_nato2text() {
+        let result = nato2text("Alpha Bravo Charlie", Alphabet::Nato);
+        match result {
+            Ok(s) => assert_eq!(s, "ABC"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = nato2text("alfa one   nine decimal Fife #", Alphabet::Nato);
+        match result {
+            Ok(s) => assert_eq!(s, "A1 9.5#"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = nato2text("Anton Zeppelin", Alphabet::German);
+        match result {
+            Ok(s) => assert_eq!(s, "AZ"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = nato2text("Alpha Bananas", Alphabet::Nato);
+        match result {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::SpellingConversion(s) => {
+                    assert_eq!(s, "Unknown spelling word Bananas");
+                }
+                _ => panic!("{:#?}", e),
+            },
+        }
+
+        let text = "HELLO WORLD 42";
+        let result = nato2text(&text2nato(text, Alphabet::Din), Alphabet::Din);
+        match result {
+            Ok(s) => assert_eq!(s, text),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_text2morse() {
+        let options = MorseOptions {
+            letter_separator: " ",
+            word_separator: " / ",
+            timing: false,
+        };
+        let result = text2morse("SOS help", &options);
+        match result {
+            Ok(s) => assert_eq!(s, "... --- ... / .... . .-.. .--."),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let options = MorseOptions {
+            letter_separator: "|",
+            word_separator: "  ",
+            timing: false,
+        };
+        let result = text2morse("ab c", &options);
+        match result {
+            Ok(s) => assert_eq!(s, ".-|-...  -.-."),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let options = MorseOptions {
+            letter_separator: " ",
+            word_separator: " / ",
+            timing: true,
+        };
+        let result = text2morse("et a", &options);
+        match result {
+            Ok(s) => assert_eq!(s, "=...===.......=.==="),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = text2morse("ü", &options);
+        match result {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::MorseConversion(s) => {
+                    assert_eq!(s, "Cannot encode ü in Morse code");
+                }
+                _ => panic!("{:#?}", e),
+            },
+        }
+    }
+
+    #[test]
+    fn test_morse2text() {
+        let options = MorseOptions {
+            letter_separator: " ",
+            word_separator: " / ",
+            timing: false,
+        };
+        let result = morse2text("... --- ... / .... . .-.. .--.", &options);
+        match result {
+            Ok(s) => assert_eq!(s, "SOS HELP"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        for (code, letter) in [(".", "E"), ("...", "S"), ("....", "H")] {
+            match morse2text(code, &options) {
+                Ok(s) => assert_eq!(s, letter),
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+
+        let result = morse2text("··· −−− ···/.----", &options);
+        match result {
+            Ok(s) => assert_eq!(s, "SOS 1"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = morse2text("=...===.......=.===", &options);
+        match result {
+            Ok(s) => assert_eq!(s, "ET A"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = morse2text("........", &options);
+        match result {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::MorseConversion(_) => (),
+                _ => panic!("{:#?}", e),
+            },
+        }
+
+        let options = MorseOptions {
+            letter_separator: "|",
+            word_separator: "  ",
+            timing: false,
+        };
+        let result = morse2text(".-|-...  -.-.", &options);
+        match result {
+            Ok(s) => assert_eq!(s, "AB C"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let options = MorseOptions {
+            letter_separator: " ",
+            word_separator: "   ",
+            timing: false,
+        };
+        let result = morse2text(".- -...   -.-.", &options);
+        match result {
+            Ok(s) => assert_eq!(s, "AB C"),
+            Err(e) => panic!("{:#?}", e),
+        }
     }
 
     #[test]
@@ -131,13 +298,13 @@ mod tests {
 
     #[test]
     fn test_text2asciibinary() {
-        let result = text2asciibinary("abc");
+        let result = text2asciibinary("abc", &TextEncoding::Utf8);
         match result {
             Ok(s) => assert_eq!(s, "01100001 01100010 01100011"),
             Err(e) => panic!("{:#?}", e),
         }
 
-        let result = text2asciibinary("á ê ç õ");
+        let result = text2asciibinary("á ê ç õ", &TextEncoding::Latin1);
         match result {
             Ok(s) => assert_eq!(
                 s,
@@ -145,35 +312,114 @@ mod tests {
             ),
             Err(e) => panic!("{:#?}", e),
         }
+
+        let result = text2asciibinary("á", &TextEncoding::Utf8);
+        match result {
+            Ok(s) => assert_eq!(s, "11000011 10100001"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = text2asciibinary("😀", &TextEncoding::Utf8);
+        match result {
+            Ok(s) => assert_eq!(s, "11110000 10011111 10011000 10000000"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = text2asciibinary("😀", &TextEncoding::Utf16);
+        match result {
+            Ok(s) => assert_eq!(s, "11011000 00111101 11011110 00000000"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = text2asciibinary("中", &TextEncoding::Latin1);
+        match result {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::Text2AsciiBinary(s) => {
+                    assert_eq!(s, "Character 中 cannot be encoded as Latin-1");
+                }
+                _ => panic!("{:#?}", e),
+            },
+        }
+
+        let result = text2asciibinary("aé", &TextEncoding::Ascii);
+        match result {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::Text2AsciiBinary(s) => {
+                    assert_eq!(s, "Non-ASCII character é (U+00E9)");
+                }
+                _ => panic!("{:#?}", e),
+            },
+        }
     }
 
     #[test]
     fn test_asciibinary2text() {
-        let result = asciibinary2text("01100001 01100010 01100011");
+        let result = asciibinary2text("01100001 01100010 01100011", &TextEncoding::Utf8);
         match result {
             Ok(s) => assert_eq!(s, "abc"),
             Err(e) => panic!("{:#?}", e),
         }
 
-        let result =
-            asciibinary2text("11100001 00100000 11101010 00100000 11100111 00100000 11110101");
+        let result = asciibinary2text(
+            "11100001 00100000 11101010 00100000 11100111 00100000 11110101",
+            &TextEncoding::Latin1,
+        );
         match result {
             Ok(s) => assert_eq!(s, "á ê ç õ"),
             Err(e) => panic!("{:#?}", e),
         }
+
+        let result = asciibinary2text("11100001", &TextEncoding::Utf8);
+        match result {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::AsciiBinary2Text(_) => (),
+                _ => panic!("{:#?}", e),
+            },
+        }
+
+        let result = asciibinary2text("11100001", &TextEncoding::Ascii);
+        match result {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::AsciiBinary2Text(s) => {
+                    assert_eq!(s, "Non-ASCII byte 11100001");
+                }
+                _ => panic!("{:#?}", e),
+            },
+        }
+    }
+
+    #[test]
+    fn test_asciibinary_round_trip() {
+        for text in ["á ê ç õ", "héllo 😀 wörld", "中文字符", "日本語 🎌 한국어"]
+        {
+            for encoding in [TextEncoding::Utf8, TextEncoding::Utf16] {
+                let binary = match text2asciibinary(text, &encoding) {
+                    Ok(s) => s,
+                    Err(e) => panic!("{:#?}", e),
+                };
+                match asciibinary2text(&binary, &encoding) {
+                    Ok(s) => assert_eq!(s, text),
+                    Err(e) => panic!("{:#?}", e),
+                }
+            }
+        }
     }
 
     #[test]
     fn test_kilometers2miles() {
         let result = kilometers2miles("0");
         match result {
-            Ok(s) => assert_eq!(s, "0"),
+            Ok(s) => assert_eq!(s, 0.0),
             Err(e) => panic!("{:#?}", e),
         }
 
         let result = kilometers2miles("1");
         match result {
-            Ok(s) => assert_eq!(s, "0.621371"),
+            Ok(s) => assert_eq!(s, 0.621371192237),
             Err(e) => panic!("{:#?}", e),
         }
     }
@@ -182,13 +428,13 @@ mod tests {
     fn test_miles2kilometers() {
         let result = miles2kilometers("0");
         match result {
-            Ok(s) => assert_eq!(s, "0"),
+            Ok(s) => assert_eq!(s, 0.0),
             Err(e) => panic!("{:#?}", e),
         }
 
         let result = miles2kilometers("1");
         match result {
-            Ok(s) => assert_eq!(s, "1.6093444978925633"),
+            Ok(s) => assert_eq!(s, 1.609344),
             Err(e) => panic!("{:#?}", e),
         }
     }
@@ -197,13 +443,13 @@ mod tests {
     fn test_pounds2kilos() {
         let result = pounds2kilos("0");
         match result {
-            Ok(s) => assert_eq!(s, "0"),
+            Ok(s) => assert_eq!(s, 0.0),
             Err(e) => panic!("{:#?}", e),
         }
 
         let result = pounds2kilos("1");
         match result {
-            Ok(s) => assert_eq!(s, "0.453592"),
+            Ok(s) => assert_eq!(s, 0.45359237),
             Err(e) => panic!("{:#?}", e),
         }
     }
@@ -212,105 +458,120 @@ mod tests {
     fn test_kilos2pounds() {
         let result = kilos2pounds("0");
         match result {
-            Ok(s) => assert_eq!(s, "0"),
+            Ok(s) => assert_eq!(s, 0.0),
             Err(e) => panic!("{:#?}", e),
         }
 
         let result = kilos2pounds("1");
         match result {
-            Ok(s) => assert_eq!(s, "2.2046244201837775"),
+            Ok(s) => assert_eq!(s, 2.20462262185),
             Err(e) => panic!("{:#?}", e),
         }
     }
 
     #[test]
     fn test_arabic2roman() {
-        let result = arabic2roman("1");
+        let result = arabic2roman("1", false);
         match result {
             Ok(s) => assert_eq!(s, "I"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("2");
+        let result = arabic2roman("2", false);
         match result {
             Ok(s) => assert_eq!(s, "II"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("3");
+        let result = arabic2roman("3", false);
         match result {
             Ok(s) => assert_eq!(s, "III"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("4");
+        let result = arabic2roman("4", false);
         match result {
             Ok(s) => assert_eq!(s, "IV"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("5");
+        let result = arabic2roman("5", false);
         match result {
             Ok(s) => assert_eq!(s, "V"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("6");
+        let result = arabic2roman("6", false);
         match result {
             Ok(s) => assert_eq!(s, "VI"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("10");
+        let result = arabic2roman("10", false);
         match result {
             Ok(s) => assert_eq!(s, "X"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("11");
+        let result = arabic2roman("11", false);
         match result {
             Ok(s) => assert_eq!(s, "XI"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("20");
+        let result = arabic2roman("20", false);
         match result {
             Ok(s) => assert_eq!(s, "XX"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("50");
+        let result = arabic2roman("50", false);
         match result {
             Ok(s) => assert_eq!(s, "L"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("100");
+        let result = arabic2roman("100", false);
         match result {
             Ok(s) => assert_eq!(s, "C"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("500");
+        let result = arabic2roman("500", false);
         match result {
             Ok(s) => assert_eq!(s, "D"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("1000");
+        let result = arabic2roman("1000", false);
         match result {
             Ok(s) => assert_eq!(s, "M"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("3030");
+        let result = arabic2roman("3030", false);
         match result {
             Ok(s) => assert_eq!(s, "MMMXXX"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("3038");
+        let result = arabic2roman("3038", false);
         match result {
             Ok(s) => assert_eq!(s, "MMMXXXVIII"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("0");
+        let result = arabic2roman("0", false);
         match result {
-            Ok(s) => assert_eq!(s, ""),
-            Err(e) => panic!("{:#?}", e),
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::RomanNumeral(RomanError::OutOfRange { value, max }) => {
+                    assert_eq!((value, max), (0, 3999));
+                }
+                _ => panic!("{:#?}", e),
+            },
         }
-        let result = arabic2roman("-1");
+        let result = arabic2roman("-1", false);
         match result {
-            Ok(s) => assert_eq!(s, ""),
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::RomanNumeral(RomanError::OutOfRange { value, max }) => {
+                    assert_eq!((value, max), (-1, 3999));
+                }
+                _ => panic!("{:#?}", e),
+            },
+        }
+        let result = arabic2roman("4000", true);
+        match result {
+            Ok(s) => assert_eq!(s, "I\u{305}V\u{305}"),
             Err(e) => panic!("{:#?}", e),
         }
-        let result = arabic2roman("foo");
+        let result = arabic2roman("foo", false);
         match result {
             Ok(s) => panic!("{:#?}", s),
             Err(e) => match e {
@@ -399,12 +660,17 @@ mod tests {
             Ok(s) => assert_eq!(s, "3038"),
             Err(e) => panic!("{:#?}", e),
         }
+        let result = roman2arabic("mmxxiv");
+        match result {
+            Ok(s) => assert_eq!(s, "2024"),
+            Err(e) => panic!("{:#?}", e),
+        }
         let result = roman2arabic("LMAOYOLOCOPTER");
         match result {
             Ok(s) => panic!("{:#?}", s),
             Err(e) => match e {
-                ConversionError::NumberConversion(s) => {
-                    assert_eq!(s, "Cannot convert LMAOYOLOCOPTER to a number");
+                ConversionError::RomanNumeral(e) => {
+                    assert_eq!(e.to_string(), "A is not a Roman numeral character");
                 }
                 _ => panic!("{:#?}", e),
             },
@@ -0,0 +1,549 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime,
+    Timelike, Utc,
+};
+use strum_macros::{EnumIter, EnumString, EnumVariantNames};
+
+use super::datetime::Zone;
+
+#[derive(EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum CronAction {
+    Validate,
+    Explain,
+    Next,
+    #[strum(serialize = "prev", serialize = "previous")]
+    Previous,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CronError {
+    pub message: String,
+}
+
+impl Display for CronError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+// Schedules are searched up to the end of the year range cron allows
+const LAST_YEAR: u32 = 2099;
+
+struct FieldKind {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    names: &'static [&'static str],
+}
+
+const SECOND: FieldKind = FieldKind {
+    name: "second",
+    min: 0,
+    max: 59,
+    names: &[],
+};
+const MINUTE: FieldKind = FieldKind {
+    name: "minute",
+    min: 0,
+    max: 59,
+    names: &[],
+};
+const HOUR: FieldKind = FieldKind {
+    name: "hour",
+    min: 0,
+    max: 23,
+    names: &[],
+};
+const DAY_OF_MONTH: FieldKind = FieldKind {
+    name: "day-of-month",
+    min: 1,
+    max: 31,
+    names: &[],
+};
+const MONTH: FieldKind = FieldKind {
+    name: "month",
+    min: 1,
+    max: 12,
+    names: &MONTHS,
+};
+// 7 is accepted as another Sunday
+const DAY_OF_WEEK: FieldKind = FieldKind {
+    name: "day-of-week",
+    min: 0,
+    max: 7,
+    names: &WEEKDAYS,
+};
+const YEAR: FieldKind = FieldKind {
+    name: "year",
+    min: 1970,
+    max: LAST_YEAR,
+    names: &[],
+};
+
+// One comma separated part of a field: `*`, `5`, `1-5`, `*/15`, `1-30/2` or `5/10`
+struct Item {
+    start: u32,
+    end: u32,
+    step: u32,
+    every: bool,
+}
+
+struct Field {
+    kind: &'static FieldKind,
+    items: Vec<Item>,
+    allowed: Vec<bool>,
+}
+
+pub struct Schedule {
+    seconds: Field,
+    minutes: Field,
+    hours: Field,
+    days_of_month: Field,
+    months: Field,
+    days_of_week: Field,
+    years: Field,
+    has_seconds: bool,
+}
+
+pub fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+pub fn join_english(parts: &[String]) -> String {
+    match parts {
+        [] => String::new(),
+        [one] => one.clone(),
+        [init @ .., last] => format!("{} and {}", init.join(", "), last),
+    }
+}
+
+impl FieldKind {
+    fn value(&self, text: &str) -> Result<u32, CronError> {
+        let upper = text.to_uppercase();
+        if let Some(index) = self
+            .names
+            .iter()
+            .position(|name| name[..3].to_uppercase() == upper)
+        {
+            // Month names count from 1, weekday names from 0
+            return Ok(index as u32 + self.min.min(1));
+        }
+        match text.parse::<u32>() {
+            Ok(v) if v >= self.min && v <= self.max => Ok(v),
+            _ => Err(CronError {
+                message: format!(
+                    "Invalid {} {}, expected {} to {}",
+                    self.name, text, self.min, self.max
+                ),
+            }),
+        }
+    }
+
+    fn label(&self, value: u32) -> String {
+        if self.names.is_empty() {
+            return value.to_string();
+        }
+        // Day of week 7 wraps around to Sunday
+        self.names[(value - self.min.min(1)) as usize % self.names.len()].to_string()
+    }
+}
+
+impl Field {
+    fn parse(text: &str, kind: &'static FieldKind) -> Result<Field, CronError> {
+        let invalid = |reason: &str| CronError {
+            message: format!("Invalid {} field {}: {}", kind.name, text, reason),
+        };
+        let mut items = vec![];
+        for part in text.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => match step.parse::<u32>() {
+                    Ok(step) if step > 0 => (range, Some(step)),
+                    _ => return Err(invalid("the step must be a positive number")),
+                },
+                None => (part, None),
+            };
+            let every = range == "*" || range == "?";
+            let (start, end) = if every {
+                (kind.min, kind.max)
+            } else if let Some((start, end)) = range.split_once('-') {
+                (kind.value(start)?, kind.value(end)?)
+            } else {
+                let start = kind.value(range)?;
+                // `5/10` runs from 5 to the end of the range
+                (start, if step.is_some() { kind.max } else { start })
+            };
+            if start > end {
+                return Err(invalid("ranges must go from low to high"));
+            }
+            items.push(Item {
+                start,
+                end,
+                step: step.unwrap_or(1),
+                every,
+            });
+        }
+
+        let mut allowed = vec![false; kind.max as usize + 1];
+        for item in &items {
+            for value in (item.start..=item.end).step_by(item.step as usize) {
+                allowed[value as usize] = true;
+            }
+        }
+        Ok(Field {
+            kind,
+            items,
+            allowed,
+        })
+    }
+
+    fn any(&self) -> bool {
+        self.items.iter().any(|item| item.every && item.step == 1)
+    }
+
+    fn matches(&self, value: u32) -> bool {
+        self.allowed.get(value as usize).copied().unwrap_or(false)
+    }
+
+    fn single(&self) -> Option<u32> {
+        match self.items.as_slice() {
+            [item] if item.start == item.end => Some(item.start),
+            _ => None,
+        }
+    }
+
+    fn values(&self) -> String {
+        if self.any() {
+            return "*".to_string();
+        }
+        (self.kind.min..=self.kind.max)
+            .filter(|v| self.matches(*v))
+            .map(|v| v.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    fn describe(&self) -> String {
+        let name = self.kind.name;
+        let parts = self
+            .items
+            .iter()
+            .map(|item| {
+                let every = match item.step {
+                    1 => format!("every {}", name),
+                    step => format!("every {} {}", ordinal(step), name),
+                };
+                match (item.every, item.start == item.end) {
+                    (true, _) => every,
+                    (false, true) if self.kind.names.is_empty() => {
+                        format!("{} {}", name, item.start)
+                    }
+                    (false, true) => self.kind.label(item.start),
+                    (false, false) => format!(
+                        "{} from {} through {}",
+                        every,
+                        self.kind.label(item.start),
+                        self.kind.label(item.end)
+                    ),
+                }
+            })
+            .collect::<Vec<String>>();
+        join_english(&parts)
+    }
+}
+
+fn expand_macro(expression: &str) -> Result<&str, CronError> {
+    match expression {
+        "@yearly" | "@annually" => Ok("0 0 1 1 *"),
+        "@monthly" => Ok("0 0 1 * *"),
+        "@weekly" => Ok("0 0 * * 0"),
+        "@daily" | "@midnight" => Ok("0 0 * * *"),
+        "@hourly" => Ok("0 * * * *"),
+        "@reboot" => Err(CronError {
+            message: "@reboot runs at startup and has no schedule".to_string(),
+        }),
+        _ => Err(CronError {
+            message: format!("Unknown macro {}", expression),
+        }),
+    }
+}
+
+// Reads 5 fields (minute to day of week), 6 with leading seconds, or 7 with trailing years
+pub fn parse(content: &str) -> Result<Schedule, CronError> {
+    let mut expression = content.trim().to_lowercase();
+    if expression.starts_with('@') {
+        expression = expand_macro(&expression)?.to_string();
+    }
+    let fields = expression.split_whitespace().collect::<Vec<&str>>();
+    let (seconds, rest, years) = match fields.len() {
+        5 => ("0", &fields[..], "*"),
+        6 => (fields[0], &fields[1..], "*"),
+        7 => (fields[0], &fields[1..6], fields[6]),
+        n => {
+            return Err(CronError {
+                message: format!("Expected 5, 6 or 7 fields but found {}", n),
+            })
+        }
+    };
+    Ok(Schedule {
+        seconds: Field::parse(seconds, &SECOND)?,
+        minutes: Field::parse(rest[0], &MINUTE)?,
+        hours: Field::parse(rest[1], &HOUR)?,
+        days_of_month: Field::parse(rest[2], &DAY_OF_MONTH)?,
+        months: Field::parse(rest[3], &MONTH)?,
+        days_of_week: Field::parse(rest[4], &DAY_OF_WEEK)?,
+        years: Field::parse(years, &YEAR)?,
+        has_seconds: fields.len() > 5,
+    })
+}
+
+impl Schedule {
+    // When both day fields are restricted a day matching either one fires, as in Vixie cron
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let weekday = date.weekday().num_days_from_sunday();
+        let dom = self.days_of_month.matches(date.day());
+        let dow =
+            self.days_of_week.matches(weekday) || (weekday == 0 && self.days_of_week.matches(7));
+        match (self.days_of_month.any(), self.days_of_week.any()) {
+            (true, true) => true,
+            (true, false) => dow,
+            (false, true) => dom,
+            (false, false) => dom || dow,
+        }
+    }
+
+    pub fn validate(&self) -> String {
+        [
+            ("second", &self.seconds),
+            ("minute", &self.minutes),
+            ("hour", &self.hours),
+            ("day of month", &self.days_of_month),
+            ("month", &self.months),
+            ("day of week", &self.days_of_week),
+            ("year", &self.years),
+        ]
+        .iter()
+        .map(|(name, field)| format!("{}: {}", name, field.values()))
+        .collect::<Vec<String>>()
+        .join("\n")
+    }
+
+    pub fn explain(&self) -> String {
+        let time = match (
+            self.seconds.single(),
+            self.minutes.single(),
+            self.hours.single(),
+        ) {
+            (Some(0), Some(minute), Some(hour)) => format!("At {:02}:{:02}", hour, minute),
+            (Some(second), Some(minute), Some(hour)) => {
+                format!("At {:02}:{:02}:{:02}", hour, minute, second)
+            }
+            _ => {
+                let mut parts = vec![];
+                let seconds = self.has_seconds && self.seconds.single() != Some(0);
+                if seconds {
+                    parts.push(self.seconds.describe());
+                }
+                if !seconds || !self.minutes.any() {
+                    parts.push(self.minutes.describe());
+                }
+                let mut time = format!("At {}", parts.join(", "));
+                if !self.hours.any() {
+                    time.push_str(&format!(" past {}", self.hours.describe()));
+                }
+                time
+            }
+        };
+
+        let mut days = vec![];
+        if !self.days_of_month.any() {
+            days.push(format!("on {}", self.days_of_month.describe()));
+        }
+        if !self.days_of_week.any() {
+            days.push(format!("on {}", self.days_of_week.describe()));
+        }
+        let mut sentence = time;
+        if !days.is_empty() {
+            sentence.push(' ');
+            sentence.push_str(&days.join(" or "));
+        }
+        if !self.months.any() {
+            sentence.push_str(&format!(" in {}", self.months.describe()));
+        }
+        if !self.years.any() {
+            sentence.push_str(&format!(" in {}", self.years.describe()));
+        }
+        sentence.push('.');
+        sentence
+    }
+
+    // The first wall clock time at or after `from` matching the schedule, stepping over whole
+    // years, months, days, hours and minutes that cannot match
+    fn next_local(&self, from: NaiveDateTime) -> Option<NaiveDateTime> {
+        let mut t = from;
+        loop {
+            if t.year() as u32 > LAST_YEAR {
+                return None;
+            }
+            if !self.years.matches(t.year() as u32) {
+                t = NaiveDate::from_ymd_opt(t.year() + 1, 1, 1)?.and_time(NaiveTime::MIN);
+            } else if !self.months.matches(t.month()) {
+                let (year, month) = match t.month() {
+                    12 => (t.year() + 1, 1),
+                    m => (t.year(), m + 1),
+                };
+                t = NaiveDate::from_ymd_opt(year, month, 1)?.and_time(NaiveTime::MIN);
+            } else if !self.matches_day(t.date()) {
+                t = t.date().succ_opt()?.and_time(NaiveTime::MIN);
+            } else if !self.hours.matches(t.hour()) {
+                t = t.date().and_hms_opt(t.hour(), 0, 0)? + Duration::hours(1);
+            } else if !self.minutes.matches(t.minute()) {
+                t = t.date().and_hms_opt(t.hour(), t.minute(), 0)? + Duration::minutes(1);
+            } else if !self.seconds.matches(t.second()) {
+                t += Duration::seconds(1);
+            } else {
+                return Some(t);
+            }
+        }
+    }
+
+    // The last wall clock time at or before `from` matching the schedule
+    fn previous_local(&self, from: NaiveDateTime) -> Option<NaiveDateTime> {
+        let mut t = from;
+        let end_of_day = NaiveTime::from_hms_opt(23, 59, 59)?;
+        loop {
+            if (t.year() as u32) < YEAR.min {
+                return None;
+            }
+            if !self.years.matches(t.year() as u32) {
+                t = NaiveDate::from_ymd_opt(t.year() - 1, 12, 31)?.and_time(end_of_day);
+            } else if !self.months.matches(t.month()) {
+                let first = NaiveDate::from_ymd_opt(t.year(), t.month(), 1)?;
+                t = first.pred_opt()?.and_time(end_of_day);
+            } else if !self.matches_day(t.date()) {
+                t = t.date().pred_opt()?.and_time(end_of_day);
+            } else if !self.hours.matches(t.hour()) {
+                t = t.date().and_hms_opt(t.hour(), 0, 0)? - Duration::seconds(1);
+            } else if !self.minutes.matches(t.minute()) {
+                t = t.date().and_hms_opt(t.hour(), t.minute(), 0)? - Duration::seconds(1);
+            } else if !self.seconds.matches(t.second()) {
+                t -= Duration::seconds(1);
+            } else {
+                return Some(t);
+            }
+        }
+    }
+
+    // Lists up to `count` fire times after (or before, when `backwards`) `from`, as wall clock
+    // times in `zone`. Times skipped by a DST transition do not fire and repeated ones fire once.
+    pub fn fire_times(
+        &self,
+        from: DateTime<FixedOffset>,
+        zone: &Zone,
+        count: usize,
+        backwards: bool,
+    ) -> Vec<DateTime<FixedOffset>> {
+        let start = zone
+            .at(&from.with_timezone(&Utc))
+            .naive_local()
+            .with_nanosecond(0)
+            .unwrap_or_default();
+        let mut times = vec![];
+        let mut cursor = if backwards {
+            start - Duration::seconds(1)
+        } else {
+            start + Duration::seconds(1)
+        };
+        while times.len() < count {
+            let local = if backwards {
+                self.previous_local(cursor)
+            } else {
+                self.next_local(cursor)
+            };
+            let local = match local {
+                Some(local) => local,
+                None => break,
+            };
+            let candidates = match zone.local(&local) {
+                LocalResult::Single(t) => vec![t],
+                LocalResult::Ambiguous(earliest, latest) => vec![earliest, latest],
+                LocalResult::None => vec![],
+            };
+            let instant = if backwards {
+                candidates.into_iter().rev().find(|t| *t < from)
+            } else {
+                candidates.into_iter().find(|t| *t > from)
+            };
+            if let Some(instant) = instant {
+                times.push(instant);
+            }
+            cursor = if backwards {
+                local - Duration::seconds(1)
+            } else {
+                local + Duration::seconds(1)
+            };
+        }
+        times
+    }
+}
+
+pub fn fire_times(
+    content: &str,
+    from: DateTime<FixedOffset>,
+    zone: &Zone,
+    count: usize,
+    backwards: bool,
+) -> Result<String, CronError> {
+    let times = parse(content)?.fire_times(from, zone, count, backwards);
+    if times.is_empty() {
+        return Err(CronError {
+            message: format!(
+                "{} never fires {} {}",
+                content.trim(),
+                if backwards { "before" } else { "after" },
+                from.to_rfc3339()
+            ),
+        });
+    }
+    Ok(times
+        .iter()
+        .map(|t| t.to_rfc3339())
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+#[cfg(test)]
+#[path = "./cron_test.rs"]
+mod cron_test;
@@ -0,0 +1,121 @@
+use crate::dev_utils::cron::*;
+
+mod tests {
+    use super::*;
+    use crate::dev_utils::datetime::Zone;
+    use chrono::DateTime;
+    use std::str::FromStr;
+
+    fn explain(content: &str) -> String {
+        match parse(content) {
+            Ok(schedule) => schedule.explain(),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let result = parse("*/15 9-17 * JAN-MAR mon-fri");
+        match result {
+            Ok(schedule) => assert_eq!(
+                schedule.validate(),
+                "second: 0\n\
+                 minute: 0,15,30,45\n\
+                 hour: 9,10,11,12,13,14,15,16,17\n\
+                 day of month: *\n\
+                 month: 1,2,3\n\
+                 day of week: 1,2,3,4,5\n\
+                 year: *"
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        assert!(parse("* * * *").is_err());
+        assert!(parse("60 * * * *").is_err());
+        assert!(parse("* * 0 * *").is_err());
+        assert!(parse("5-1 * * * *").is_err());
+        assert!(parse("*/0 * * * *").is_err());
+        assert!(parse("* * * * * * 1969").is_err());
+        assert!(parse("@reboot").is_err());
+    }
+
+    #[test]
+    fn test_explain() {
+        assert_eq!(explain("30 9 * * *"), "At 09:30.");
+        assert_eq!(explain("@weekly"), "At 00:00 on Sunday.");
+        assert_eq!(explain("* * * * *"), "At every minute.");
+        assert_eq!(
+            explain("*/15 9-17 * * 1-5"),
+            "At every 15th minute past every hour from 9 through 17 on every day-of-week from Monday through Friday."
+        );
+        assert_eq!(
+            explain("0 12 1,15 * 5"),
+            "At 12:00 on day-of-month 1 and day-of-month 15 or on Friday."
+        );
+        assert_eq!(
+            explain("30 0 0 1 jan ? 2030"),
+            "At 00:00:30 on day-of-month 1 in January in year 2030."
+        );
+        assert_eq!(explain("*/10 * * * * *"), "At every 10th second.");
+    }
+
+    #[test]
+    fn test_next_and_previous() {
+        let from = DateTime::parse_from_rfc3339("2026-10-19T10:20:00Z").unwrap();
+        let utc = Zone::from_str("UTC").unwrap();
+        let result = fire_times("*/15 9-17 * * mon-fri", from, &utc, 3, false);
+        match result {
+            Ok(s) => assert_eq!(
+                s,
+                "2026-10-19T10:30:00+00:00\n\
+                 2026-10-19T10:45:00+00:00\n\
+                 2026-10-19T11:00:00+00:00"
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = fire_times("0 9 * * mon-fri", from, &utc, 2, true);
+        match result {
+            Ok(s) => assert_eq!(s, "2026-10-19T09:00:00+00:00\n2026-10-16T09:00:00+00:00"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        // The 13th and every Friday
+        let result = fire_times("0 0 13 * 5", from, &utc, 3, false);
+        match result {
+            Ok(s) => assert_eq!(
+                s,
+                "2026-10-23T00:00:00+00:00\n\
+                 2026-10-30T00:00:00+00:00\n\
+                 2026-11-06T00:00:00+00:00"
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = fire_times("@yearly", from, &utc, 1, false);
+        match result {
+            Ok(s) => assert_eq!(s, "2027-01-01T00:00:00+00:00"),
+            Err(e) => panic!("{:#?}", e),
+        }
+        assert!(fire_times("0 0 30 2 *", from, &utc, 1, false).is_err());
+    }
+
+    #[test]
+    fn test_daylight_saving() {
+        let london = Zone::from_str("Europe/London").unwrap();
+        // 01:30 is skipped on 2026-03-29 and happens twice on 2026-10-25
+        let from = DateTime::parse_from_rfc3339("2026-03-28T12:00:00Z").unwrap();
+        let result = fire_times("30 1 * * *", from, &london, 2, false);
+        match result {
+            Ok(s) => assert_eq!(s, "2026-03-30T01:30:00+01:00\n2026-03-31T01:30:00+01:00"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let from = DateTime::parse_from_rfc3339("2026-10-24T12:00:00Z").unwrap();
+        let result = fire_times("30 1 * * *", from, &london, 2, false);
+        match result {
+            Ok(s) => assert_eq!(s, "2026-10-25T01:30:00+01:00\n2026-10-26T01:30:00+00:00"),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+}
@@ -1,14 +1,40 @@
-use chrono::LocalResult::Single;
-use chrono::{Duration, Months, TimeZone};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Month, Months, NaiveDate, NaiveTime, TimeZone,
+    Weekday,
+};
 use regex::{Error, Regex};
 use std::fmt::{Display, Formatter};
 use strum_macros::EnumString;
 use strum_macros::{EnumIter, EnumVariantNames};
 
+use super::datetime::{self, ZoneOptions};
+use super::words::{self, Language};
+
 #[derive(EnumIter, EnumString, EnumVariantNames)]
 #[strum(serialize_all = "lowercase")]
 pub enum DateAction {
     Delta,
+    Diff,
+    Parse,
+    Humanize,
+    Info,
+    Week,
+    Cal,
+}
+
+#[derive(Clone, Copy, EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum DiffOutput {
+    Report,
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    #[strum(serialize = "business-days", serialize = "businessdays")]
+    BusinessDays,
+    Calendar,
+    Iso8601,
 }
 
 #[derive(Debug)]
@@ -17,47 +43,675 @@ pub enum DateError {
     Regex(Error),
     Capture,
     Parse,
-    InvalidUnit,
+    InvalidUnit(String),
+    OutOfRange,
+    Format(String),
+    Unrecognised(String),
 }
 
 impl Display for DateError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            DateError::Input => write!(f, "No date given"),
+            DateError::Regex(e) => write!(f, "{}", e),
+            DateError::Capture => {
+                write!(f, "Invalid delta, expected an expression such as -1y2m3d4h")
+            }
+            DateError::Parse => write!(f, "Cannot read the number"),
+            DateError::InvalidUnit(unit) => write!(f, "Invalid unit {}", unit),
+            DateError::OutOfRange => write!(f, "Date out of range"),
+            DateError::Format(message) => write!(f, "{}", message),
+            DateError::Unrecognised(word) => write!(f, "Unrecognised {}", word),
+        }
+    }
+}
+
+// The parts of a delta expression, kept apart because months and business days depend on the
+// calendar and have to be applied before the fixed length units
+#[derive(Default)]
+struct Offset {
+    months: i64,
+    business_days: i64,
+    seconds: i64,
+    has_time: bool,
+}
+
+impl Offset {
+    fn add(&mut self, value: i64, unit: &str) -> Result<(), DateError> {
+        let (field, scale, time) = match unit {
+            "s" | "sec" | "secs" | "second" | "seconds" => (&mut self.seconds, 1, true),
+            "min" | "mins" | "minute" | "minutes" => (&mut self.seconds, 60, true),
+            "h" | "hr" | "hrs" | "hour" | "hours" => (&mut self.seconds, 3600, true),
+            "d" | "day" | "days" => (&mut self.seconds, 86_400, false),
+            "w" | "wk" | "week" | "weeks" => (&mut self.seconds, 7 * 86_400, false),
+            "bd" | "businessday" | "businessdays" => (&mut self.business_days, 1, false),
+            "m" | "mo" | "month" | "months" => (&mut self.months, 1, false),
+            "q" | "quarter" | "quarters" => (&mut self.months, 3, false),
+            "y" | "yr" | "year" | "years" => (&mut self.months, 12, false),
+            _ => return Err(DateError::InvalidUnit(unit.to_string())),
+        };
+        *field = value
+            .checked_mul(scale)
+            .and_then(|v| field.checked_add(v))
+            .ok_or(DateError::OutOfRange)?;
+        self.has_time |= time;
+        Ok(())
     }
 }
 
-pub fn delta(content: &str, current_time: i64) -> Result<String, DateError> {
-    let now = if current_time < 0 {
-        chrono::Utc::now()
-    } else {
-        match chrono::Utc.timestamp_opt(current_time, 0) {
-            Single(t) => t,
-            _ => return Err(DateError::Input),
+fn is_business_day(date: NaiveDate, holidays: &[NaiveDate]) -> bool {
+    !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !holidays.contains(&date)
+}
+
+// Steps one day at a time, counting only Monday to Friday outside `holidays`
+fn add_business_days(
+    date: DateTime<FixedOffset>,
+    business_days: i64,
+    holidays: &[NaiveDate],
+) -> Result<DateTime<FixedOffset>, DateError> {
+    // Far enough to reach the end of the range chrono supports
+    if business_days.abs() > 100_000_000 {
+        return Err(DateError::OutOfRange);
+    }
+    let step = Duration::days(business_days.signum());
+    let mut date = date;
+    let mut remaining = business_days.abs();
+    while remaining > 0 {
+        date = date.checked_add_signed(step).ok_or(DateError::OutOfRange)?;
+        if is_business_day(date.date_naive(), holidays) {
+            remaining -= 1;
         }
+    }
+    Ok(date)
+}
+
+// Reads an expression such as `1d`, `-1y2m3d4h` or `2w 3bd`. A leading sign negates the whole
+// expression.
+fn parse_offset(content: &str) -> Result<Offset, DateError> {
+    let expression = content
+        .split_whitespace()
+        .collect::<String>()
+        .to_lowercase();
+    let pattern = match Regex::new("^(?<sign>[+-]?)(?<parts>(?:[0-9]+[a-z]+)+)$") {
+        Ok(p) => p,
+        Err(e) => return Err(DateError::Regex(e)),
     };
-    let pattern = match Regex::new("^(?i)(?<sign>-?)(?<value>[0-9]+)(?<unit>[a-z]+)$") {
+    let part = match Regex::new("(?<value>[0-9]+)(?<unit>[a-z]+)") {
         Ok(p) => p,
         Err(e) => return Err(DateError::Regex(e)),
     };
 
-    let captures = match pattern.captures(content) {
+    let captures = match pattern.captures(&expression) {
         Some(c) => c,
         None => return Err(DateError::Capture),
     };
-    let sign = if captures["sign"].is_empty() { 1 } else { -1 };
-    let value = match captures["value"].parse::<i64>() {
-        Ok(v) => v,
-        Err(_) => return Err(DateError::Parse),
+    let sign = if &captures["sign"] == "-" { -1 } else { 1 };
+
+    let mut offset = Offset::default();
+    for captures in part.captures_iter(&captures["parts"]) {
+        let value = match captures["value"].parse::<i64>() {
+            Ok(v) => v * sign,
+            Err(_) => return Err(DateError::Parse),
+        };
+        offset.add(value, &captures["unit"])?;
+    }
+    Ok(offset)
+}
+
+impl Offset {
+    fn negated(&self) -> Offset {
+        Offset {
+            months: -self.months,
+            business_days: -self.business_days,
+            seconds: -self.seconds,
+            has_time: self.has_time,
+        }
+    }
+
+    // Years, quarters and months are applied first and clamp to the end of the month, so
+    // 2024-01-31 plus one month is 2024-02-29
+    fn apply(
+        &self,
+        base: DateTime<FixedOffset>,
+        holidays: &[NaiveDate],
+    ) -> Result<DateTime<FixedOffset>, DateError> {
+        let months =
+            Months::new(u32::try_from(self.months.abs()).map_err(|_| DateError::OutOfRange)?);
+        let mut result = if self.months < 0 {
+            base.checked_sub_months(months)
+        } else {
+            base.checked_add_months(months)
+        }
+        .ok_or(DateError::OutOfRange)?;
+        result = add_business_days(result, self.business_days, holidays)?;
+        // `Duration::seconds` panics beyond i64::MAX milliseconds
+        if self.seconds.abs() > i64::MAX / 1000 {
+            return Err(DateError::OutOfRange);
+        }
+        result
+            .checked_add_signed(Duration::seconds(self.seconds))
+            .ok_or(DateError::OutOfRange)
+    }
+}
+
+// Applies a delta expression to `base`. The result is written as a date unless the expression
+// has a time unit or a `format` is given.
+pub fn delta(
+    content: &str,
+    base: DateTime<FixedOffset>,
+    format: Option<&str>,
+    holidays: &[NaiveDate],
+) -> Result<String, DateError> {
+    let offset = parse_offset(content)?;
+    let result = offset.apply(base, holidays)?;
+
+    let format = match format {
+        Some(format) => format,
+        None if offset.has_time => "rfc3339",
+        None => "%Y-%m-%d",
+    };
+    datetime::format(format, &result).map_err(|e| DateError::Format(e.to_string()))
+}
+
+// Business days after `start` up to and including `end`, so that `start` plus that many business
+// days with `delta` lands on `end` when it is a business day
+fn business_days_between(start: NaiveDate, end: NaiveDate, holidays: &[NaiveDate]) -> i64 {
+    let days = (end - start).num_days();
+    let weeks = days / 7;
+    let mut count = weeks * 5;
+    let mut date = start + Duration::days(weeks * 7);
+    while date < end {
+        date += Duration::days(1);
+        if !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            count += 1;
+        }
+    }
+    let mut holidays = holidays.to_vec();
+    holidays.sort();
+    holidays.dedup();
+    let skipped = holidays
+        .iter()
+        .filter(|h| {
+            **h > start && **h <= end && !matches!(h.weekday(), Weekday::Sat | Weekday::Sun)
+        })
+        .count() as i64;
+    count - skipped
+}
+
+// The interval between two instants, both as a fixed number of seconds and as calendar months,
+// days and time of day counted from the earlier one
+#[derive(Debug, PartialEq)]
+pub struct DateDiff {
+    pub negative: bool,
+    pub seconds: i64,
+    pub months: i64,
+    pub days: i64,
+    pub time: i64,
+    pub business_days: i64,
+}
+
+pub fn diff(
+    start: DateTime<FixedOffset>,
+    end: DateTime<FixedOffset>,
+    holidays: &[NaiveDate],
+) -> Result<DateDiff, DateError> {
+    let negative = end < start;
+    let (start, end) = if negative { (end, start) } else { (start, end) };
+    // Calendar arithmetic happens on the earlier date's wall clock
+    let end = end.with_timezone(&start.timezone());
+
+    let mut months =
+        (end.year() - start.year()) as i64 * 12 + end.month() as i64 - start.month() as i64;
+    let add_months = |months: i64| {
+        start
+            .checked_add_months(Months::new(months as u32))
+            .ok_or(DateError::OutOfRange)
     };
+    let mut anchor = add_months(months)?;
+    if anchor > end {
+        months -= 1;
+        anchor = add_months(months)?;
+    }
+    let rest = (end - anchor).num_seconds();
+
+    Ok(DateDiff {
+        negative,
+        seconds: (end - start).num_seconds(),
+        months,
+        days: rest / 86_400,
+        time: rest % 86_400,
+        business_days: business_days_between(start.date_naive(), end.date_naive(), holidays),
+    })
+}
+
+impl DateDiff {
+    fn sign(&self) -> &str {
+        if self.negative {
+            "-"
+        } else {
+            ""
+        }
+    }
+
+    fn total(&self, size: i64) -> String {
+        let sign = self.sign();
+        if self.seconds % size == 0 {
+            format!("{}{}", sign, self.seconds / size)
+        } else {
+            format!("{}{:.2}", sign, self.seconds as f64 / size as f64)
+        }
+    }
+
+    // Written with the units `delta` reads, so `date delta` can replay it
+    pub fn calendar(&self) -> String {
+        let parts = [
+            (self.months / 12, "y"),
+            (self.months % 12, "m"),
+            (self.days, "d"),
+            (self.time / 3600, "h"),
+            (self.time % 3600 / 60, "min"),
+            (self.time % 60, "s"),
+        ];
+        let parts = parts
+            .iter()
+            .filter(|(value, _)| *value > 0)
+            .map(|(value, unit)| format!("{}{}", value, unit))
+            .collect::<Vec<String>>();
+        match parts.is_empty() {
+            true => "0s".to_string(),
+            false => format!("{}{}", self.sign(), parts.join(" ")),
+        }
+    }
 
-    let offset = match &captures["unit"] {
-        "d" => now + Duration::days(sign * value),
-        "m" => now + Months::new((sign * value) as u32),
-        "y" => now + Months::new((12 * sign * value) as u32),
-        _ => return Err(DateError::InvalidUnit),
+    pub fn iso8601(&self) -> String {
+        let mut date = String::new();
+        for (value, unit) in [
+            (self.months / 12, 'Y'),
+            (self.months % 12, 'M'),
+            (self.days, 'D'),
+        ] {
+            if value > 0 {
+                date.push_str(&format!("{}{}", value, unit));
+            }
+        }
+        let mut time = String::new();
+        for (value, unit) in [
+            (self.time / 3600, 'H'),
+            (self.time % 3600 / 60, 'M'),
+            (self.time % 60, 'S'),
+        ] {
+            if value > 0 {
+                time.push_str(&format!("{}{}", value, unit));
+            }
+        }
+        match (date.is_empty(), time.is_empty()) {
+            (true, true) => "PT0S".to_string(),
+            (_, true) => format!("{}P{}", self.sign(), date),
+            _ => format!("{}P{}T{}", self.sign(), date, time),
+        }
+    }
+
+    pub fn render(&self, output: DiffOutput) -> String {
+        match output {
+            DiffOutput::Seconds => self.total(1),
+            DiffOutput::Minutes => self.total(60),
+            DiffOutput::Hours => self.total(3600),
+            DiffOutput::Days => self.total(86_400),
+            DiffOutput::Weeks => self.total(7 * 86_400),
+            DiffOutput::BusinessDays => format!("{}{}", self.sign(), self.business_days),
+            DiffOutput::Calendar => self.calendar(),
+            DiffOutput::Iso8601 => self.iso8601(),
+            DiffOutput::Report => [
+                ("seconds", DiffOutput::Seconds),
+                ("minutes", DiffOutput::Minutes),
+                ("hours", DiffOutput::Hours),
+                ("days", DiffOutput::Days),
+                ("weeks", DiffOutput::Weeks),
+                ("business days", DiffOutput::BusinessDays),
+                ("calendar", DiffOutput::Calendar),
+                ("iso8601", DiffOutput::Iso8601),
+            ]
+            .iter()
+            .map(|(name, output)| format!("{}: {}", name, self.render(*output)))
+            .collect::<Vec<String>>()
+            .join("\n"),
+        }
+    }
+}
+
+// A small grammar for dates written in English, read word by word:
+//
+//   now | today | tomorrow | yesterday
+//   in <amount> | <amount> ago | <amount> from now | <amount> later
+//   [this | next | last] <weekday> | next | last <unit>
+//   first | last day of [this | next | last] month | year | <month name> [<year>]
+//   [at] <time>, such as 3pm, 3:30 pm, 15:00, noon or midnight
+//   <year>, such as 2024, for the first day of that year
+//
+// where an amount is one or more `<number> <unit>`, the number being digits, a word or `a`.
+// Naming a day starts it at midnight, while offsets keep the time of day.
+struct Phrase<'a> {
+    words: Vec<&'a str>,
+    position: usize,
+    now: DateTime<FixedOffset>,
+}
+
+fn at(
+    date: DateTime<FixedOffset>,
+    day: NaiveDate,
+    time: NaiveTime,
+) -> Result<DateTime<FixedOffset>, DateError> {
+    date.timezone()
+        .from_local_datetime(&day.and_time(time))
+        .single()
+        .ok_or(DateError::OutOfRange)
+}
+
+fn at_time(
+    date: DateTime<FixedOffset>,
+    time: NaiveTime,
+) -> Result<DateTime<FixedOffset>, DateError> {
+    at(date, date.date_naive(), time)
+}
+
+fn on_day(date: DateTime<FixedOffset>, day: NaiveDate) -> Result<DateTime<FixedOffset>, DateError> {
+    at(date, day, NaiveTime::MIN)
+}
+
+fn month_bounds(year: i32, month: u32, last: bool) -> Result<NaiveDate, DateError> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1).ok_or(DateError::OutOfRange)?;
+    if !last {
+        return Ok(first);
+    }
+    first
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .ok_or(DateError::OutOfRange)
+}
+
+impl<'a> Phrase<'a> {
+    fn peek(&self, ahead: usize) -> Option<&'a str> {
+        self.words.get(self.position + ahead).copied()
+    }
+
+    fn next(&mut self) -> Result<&'a str, DateError> {
+        let word = self
+            .peek(0)
+            .ok_or_else(|| DateError::Unrecognised("unexpected end of input".to_string()))?;
+        self.position += 1;
+        Ok(word)
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), DateError> {
+        match self.next()? {
+            word if word == expected => Ok(()),
+            word => Err(DateError::Unrecognised(format!(
+                "expected {} but found {}",
+                expected, word
+            ))),
+        }
+    }
+
+    fn number(word: &str) -> Option<i64> {
+        match word {
+            "a" | "an" => Some(1),
+            _ => word.parse::<i64>().ok().or_else(|| {
+                words::words2number(word, Language::En)
+                    .ok()
+                    .and_then(|n| n.parse::<i64>().ok())
+            }),
+        }
+    }
+
+    fn time(word: &str) -> Option<NaiveTime> {
+        match word {
+            "noon" | "midday" => return NaiveTime::from_hms_opt(12, 0, 0),
+            "midnight" => return Some(NaiveTime::MIN),
+            _ => {}
+        }
+        let pattern = Regex::new("^(\\d{1,2})(?::(\\d{2}))?(?::(\\d{2}))?(am|pm)?$").ok()?;
+        let captures = pattern.captures(word)?;
+        // A bare number is an amount, not a time
+        if captures.get(2).is_none() && captures.get(4).is_none() {
+            return None;
+        }
+        let mut hour = captures[1].parse::<u32>().ok()?;
+        let minute = captures
+            .get(2)
+            .map_or(Some(0), |m| m.as_str().parse().ok())?;
+        let second = captures
+            .get(3)
+            .map_or(Some(0), |m| m.as_str().parse().ok())?;
+        match captures.get(4).map(|m| m.as_str()) {
+            Some(_) if hour == 0 || hour > 12 => return None,
+            Some("am") if hour == 12 => hour = 0,
+            Some("pm") if hour < 12 => hour += 12,
+            _ => {}
+        }
+        NaiveTime::from_hms_opt(hour, minute, second)
+    }
+
+    // One or more `<number> <unit>`, as the offset they add up to
+    fn amount(&mut self) -> Result<Offset, DateError> {
+        let mut offset = Offset::default();
+        loop {
+            // `1 hour and 30 minutes`
+            if self.peek(0) == Some("and") && self.peek(1).and_then(Phrase::number).is_some() {
+                self.position += 1;
+            }
+            let value = match self.peek(0).and_then(Phrase::number) {
+                Some(value) => value,
+                None => break,
+            };
+            self.position += 1;
+            let mut unit = self.next()?.to_string();
+            if unit == "business" || unit == "working" {
+                unit = format!("business{}", self.next()?);
+            }
+            match unit.as_str() {
+                "fortnight" | "fortnights" => offset.add(2 * value, "weeks")?,
+                unit => offset.add(value, unit)?,
+            }
+        }
+        if offset.months == 0 && offset.business_days == 0 && offset.seconds == 0 {
+            return Err(DateError::Unrecognised(format!(
+                "expected an amount such as 2 days, found {}",
+                self.peek(0).unwrap_or("nothing")
+            )));
+        }
+        Ok(offset)
+    }
+
+    // `first day of` or `last day of`, followed by the month or year it refers to
+    fn day_of(&mut self, date: DateTime<FixedOffset>, last: bool) -> Result<NaiveDate, DateError> {
+        self.expect("day")?;
+        self.expect("of")?;
+        let shift: i32 = match self.peek(0) {
+            Some("next") => 1,
+            Some("last") | Some("previous") => -1,
+            Some("this") => 0,
+            _ => {
+                let word = self.next()?;
+                let month = word
+                    .parse::<Month>()
+                    .map_err(|_| DateError::Unrecognised(word.to_string()))?;
+                let year = match self.peek(0).and_then(|w| w.parse::<i32>().ok()) {
+                    Some(year) => {
+                        self.position += 1;
+                        year
+                    }
+                    None => date.year(),
+                };
+                return month_bounds(year, month.number_from_month(), last);
+            }
+        };
+        self.position += 1;
+        match self.next()? {
+            "month" => {
+                let months = Months::new(shift.unsigned_abs());
+                let day = if shift < 0 {
+                    date.date_naive().checked_sub_months(months)
+                } else {
+                    date.date_naive().checked_add_months(months)
+                }
+                .ok_or(DateError::OutOfRange)?;
+                month_bounds(day.year(), day.month(), last)
+            }
+            "year" => {
+                let year = date.year() + shift;
+                month_bounds(year, if last { 12 } else { 1 }, last)
+            }
+            word => Err(DateError::Unrecognised(word.to_string())),
+        }
+    }
+
+    fn weekday(
+        &self,
+        date: DateTime<FixedOffset>,
+        weekday: Weekday,
+        relation: &str,
+    ) -> Result<DateTime<FixedOffset>, DateError> {
+        let today = date.weekday().num_days_from_monday() as i64;
+        let target = weekday.num_days_from_monday() as i64;
+        let days = match relation {
+            // The coming one, today included
+            "this" => (target - today).rem_euclid(7),
+            "next" => (target - today - 1).rem_euclid(7) + 1,
+            _ => -((today - target - 1).rem_euclid(7) + 1),
+        };
+        on_day(date, date.date_naive() + Duration::days(days))
+    }
+
+    fn parse(&mut self) -> Result<DateTime<FixedOffset>, DateError> {
+        let mut date = self.now;
+        while let Some(word) = self.peek(0) {
+            if let Some(time) = Phrase::time(word) {
+                self.position += 1;
+                date = at_time(date, time)?;
+                continue;
+            }
+            if let Ok(weekday) = word.parse::<Weekday>() {
+                self.position += 1;
+                date = self.weekday(date, weekday, "this")?;
+                continue;
+            }
+            match word {
+                "now" | "at" | "and" | "on" => self.position += 1,
+                "today" => {
+                    self.position += 1;
+                    date = at_time(date, NaiveTime::MIN)?;
+                }
+                "tomorrow" | "yesterday" => {
+                    self.position += 1;
+                    let days = if word == "tomorrow" { 1 } else { -1 };
+                    date = on_day(date, date.date_naive() + Duration::days(days))?;
+                }
+                "in" => {
+                    self.position += 1;
+                    date = self.amount()?.apply(date, &[])?;
+                }
+                "first" | "last" if self.peek(1) == Some("day") => {
+                    self.position += 1;
+                    let day = self.day_of(date, word == "last")?;
+                    date = on_day(date, day)?;
+                }
+                "this" | "next" | "last" | "previous" => {
+                    self.position += 1;
+                    let relation = if word == "previous" { "last" } else { word };
+                    let target = self.next()?;
+                    if let Ok(weekday) = target.parse::<Weekday>() {
+                        date = self.weekday(date, weekday, relation)?;
+                    } else if relation != "this" {
+                        let sign = if relation == "next" { 1 } else { -1 };
+                        let mut offset = Offset::default();
+                        offset.add(sign, target)?;
+                        date = offset.apply(date, &[])?;
+                    } else {
+                        return Err(DateError::Unrecognised(format!("this {}", target)));
+                    }
+                }
+                _ if Phrase::number(word).is_some() => {
+                    // `2 days` must be followed by `ago`, `from now` or `later`
+                    let offset = self.amount()?;
+                    match self.next() {
+                        Ok("ago") => date = offset.negated().apply(date, &[])?,
+                        Ok("later") | Ok("hence") => date = offset.apply(date, &[])?,
+                        Ok("from") => {
+                            self.expect("now")?;
+                            date = offset.apply(date, &[])?;
+                        }
+                        _ => {
+                            return Err(DateError::Unrecognised(
+                                "an amount needs ago, later or from now".to_string(),
+                            ))
+                        }
+                    }
+                }
+                _ => return Err(DateError::Unrecognised(word.to_string())),
+            }
+        }
+        Ok(date)
+    }
+}
+
+// Reads a date written in English, a delta expression or any format `datetime auto` reads,
+// relative to `now`
+pub fn parse(
+    content: &str,
+    now: DateTime<FixedOffset>,
+) -> Result<DateTime<FixedOffset>, DateError> {
+    // Epoch detection would read a bare year as seconds, 2024 -> 1970-01-01T00:33:44
+    let trimmed = content.trim();
+    if !trimmed.is_empty() && trimmed.len() < 5 && trimmed.chars().all(|c| c.is_ascii_digit()) {
+        let year = trimmed.parse::<i32>().map_err(|_| DateError::OutOfRange)?;
+        return on_day(now, month_bounds(year, 1, false)?);
+    }
+    if let Ok((_, date)) = datetime::detect(content, &ZoneOptions::default()) {
+        return Ok(date);
+    }
+    if let Ok(offset) = parse_offset(content) {
+        return offset.apply(now, &[]);
+    }
+    let text = content.to_lowercase().replace([',', '.'], " ");
+    // `3 pm` reads the same as `3pm`
+    let text = Regex::new("(\\d)\\s+(am|pm)\\b")
+        .map_err(DateError::Regex)?
+        .replace_all(&text, "$1$2")
+        .to_string();
+    let mut phrase = Phrase {
+        words: text.split_whitespace().collect(),
+        position: 0,
+        now,
     };
+    if phrase.words.is_empty() {
+        return Err(DateError::Input);
+    }
+    phrase.parse()
+}
 
-    Ok(offset.format("%Y-%m-%d").to_string())
+// Describes `target` relative to `now` in the largest whole unit, such as `in 3 hours` or
+// `2 days ago`
+pub fn humanize(
+    target: DateTime<FixedOffset>,
+    now: DateTime<FixedOffset>,
+) -> Result<String, DateError> {
+    let interval = diff(now, target, &[])?;
+    let seconds = interval.seconds;
+    let (value, unit) = match seconds {
+        0 => return Ok("now".to_string()),
+        s if s < 60 => (s, "second"),
+        s if s < 3600 => (s / 60, "minute"),
+        s if s < 86_400 => (s / 3600, "hour"),
+        s if interval.months == 0 && s < 7 * 86_400 => (s / 86_400, "day"),
+        s if interval.months == 0 => (s / (7 * 86_400), "week"),
+        _ if interval.months < 12 => (interval.months, "month"),
+        _ => (interval.months / 12, "year"),
+    };
+    let plural = if value == 1 { "" } else { "s" };
+    Ok(match interval.negative {
+        true => format!("{} {}{} ago", value, unit, plural),
+        false => format!("in {} {}{}", value, unit, plural),
+    })
 }
 
 #[cfg(test)]
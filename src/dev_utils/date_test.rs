@@ -1,26 +1,326 @@
 use crate::dev_utils::date::*;
+use chrono::{DateTime, FixedOffset, NaiveDate};
 
 mod tests {
     use super::*;
 
+    fn at(datetime: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(datetime).unwrap()
+    }
+
+    fn epoch(seconds: i64) -> DateTime<FixedOffset> {
+        DateTime::from_timestamp(seconds, 0).unwrap().fixed_offset()
+    }
+
     #[test]
     fn test_delta() {
-        let result = delta("1d", 0);
+        let result = delta("1d", epoch(0), None, &[]);
         match result {
             Ok(s) => assert_eq!(s, "1970-01-02"),
             Err(e) => panic!("{:#?}", e),
         }
 
-        let result = delta("1m", 0);
+        let result = delta("1m", epoch(0), None, &[]);
         match result {
             Ok(s) => assert_eq!(s, "1970-02-01"),
             Err(e) => panic!("{:#?}", e),
         }
 
-        let result = delta("-1d", 88000);
+        let result = delta("-1d", epoch(88000), None, &[]);
         match result {
             Ok(s) => assert_eq!(s, "1970-01-01"),
             Err(e) => panic!("{:#?}", e),
         }
     }
+
+    #[test]
+    fn test_negative_months_and_clamping() {
+        let cases = [
+            ("-1m", "2024-03-15T00:00:00Z", "2024-02-15"),
+            ("-13m", "2024-03-15T00:00:00Z", "2023-02-15"),
+            ("1m", "2024-01-31T00:00:00Z", "2024-02-29"),
+            ("-1m", "2023-03-31T00:00:00Z", "2023-02-28"),
+            ("1y", "2024-02-29T00:00:00Z", "2025-02-28"),
+            ("-1q", "2024-05-31T00:00:00Z", "2024-02-29"),
+        ];
+        for (expression, base, expected) in cases {
+            match delta(expression, at(base), None, &[]) {
+                Ok(s) => assert_eq!(s, expected, "{} from {}", expression, base),
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_compound_expressions() {
+        let base = at("2024-06-15T12:00:00Z");
+        let cases = [
+            ("-1y2m3d4h", "2023-04-12T08:00:00+00:00"),
+            ("1w 2d", "2024-06-24"),
+            ("90min", "2024-06-15T13:30:00+00:00"),
+            ("1h30min15s", "2024-06-15T13:30:15+00:00"),
+            ("+2 weeks", "2024-06-29"),
+        ];
+        for (expression, expected) in cases {
+            match delta(expression, base, None, &[]) {
+                Ok(s) => assert_eq!(s, expected, "{}", expression),
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_business_days() {
+        // 2024-06-14 is a Friday
+        let friday = at("2024-06-14T09:00:00Z");
+        let cases = [
+            ("1bd", "2024-06-17"),
+            ("5bd", "2024-06-21"),
+            ("-1bd", "2024-06-13"),
+            ("-5bd", "2024-06-07"),
+        ];
+        for (expression, expected) in cases {
+            match delta(expression, friday, None, &[]) {
+                Ok(s) => assert_eq!(s, expected, "{}", expression),
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+
+        let result = delta("1bd", at("2024-06-15T09:00:00Z"), None, &[]);
+        match result {
+            Ok(s) => assert_eq!(s, "2024-06-17"),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_output_format() {
+        let base = at("2024-06-15T12:00:00+02:00");
+        let result = delta("1d", base, Some("iso8601"), &[]);
+        match result {
+            Ok(s) => assert_eq!(s, "2024-06-16T12:00:00+02:00"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = delta("1d", base, Some("%d/%m/%Y"), &[]);
+        match result {
+            Ok(s) => assert_eq!(s, "16/06/2024"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = delta("1d", base, Some("epoch"), &[]);
+        match result {
+            Ok(s) => assert_eq!(s, "1718532000"),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        assert!(matches!(
+            delta("1x", epoch(0), None, &[]),
+            Err(DateError::InvalidUnit(_))
+        ));
+        assert!(matches!(
+            delta("d1", epoch(0), None, &[]),
+            Err(DateError::Capture)
+        ));
+        assert!(matches!(
+            delta("9999999999999y", epoch(0), None, &[]),
+            Err(DateError::OutOfRange)
+        ));
+        assert!(matches!(
+            delta("1d", epoch(0), Some("%Q"), &[]),
+            Err(DateError::Format(_))
+        ));
+    }
+
+    #[test]
+    fn test_business_days_skip_holidays() {
+        let holidays = [NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()];
+        let result = delta("2bd", at("2024-12-24T09:00:00Z"), None, &holidays);
+        match result {
+            Ok(s) => assert_eq!(s, "2024-12-27"),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_diff_totals() {
+        let result = diff(at("2024-01-01T00:00:00Z"), at("2024-01-02T02:03:04Z"), &[]);
+        match result {
+            Ok(d) => assert_eq!(
+                d.render(DiffOutput::Report),
+                "seconds: 93784\n\
+                 minutes: 1563.07\n\
+                 hours: 26.05\n\
+                 days: 1.09\n\
+                 weeks: 0.16\n\
+                 business days: 1\n\
+                 calendar: 1d 2h 3min 4s\n\
+                 iso8601: P1DT2H3M4S"
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_diff_calendar() {
+        let cases = [
+            (
+                "2023-01-31T00:00:00Z",
+                "2024-03-01T00:00:00Z",
+                "1y 1m 1d",
+                "P1Y1M1D",
+            ),
+            ("2024-01-31T00:00:00Z", "2024-02-29T00:00:00Z", "1m", "P1M"),
+            (
+                "2024-03-31T10:00:00Z",
+                "2024-03-01T00:00:00Z",
+                "-30d 10h",
+                "-P30DT10H",
+            ),
+            ("2024-01-01T00:00:00Z", "2024-01-01T00:00:00Z", "0s", "PT0S"),
+            // Offsets are compared as instants, the calendar runs on the first date's clock
+            (
+                "2024-01-01T00:00:00+02:00",
+                "2024-01-01T00:00:00Z",
+                "2h",
+                "PT2H",
+            ),
+        ];
+        for (start, end, calendar, iso) in cases {
+            match diff(at(start), at(end), &[]) {
+                Ok(d) => {
+                    assert_eq!(
+                        d.render(DiffOutput::Calendar),
+                        calendar,
+                        "{} {}",
+                        start,
+                        end
+                    );
+                    assert_eq!(d.render(DiffOutput::Iso8601), iso, "{} {}", start, end);
+                }
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_diff_business_days() {
+        let holidays = [
+            NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 26).unwrap(),
+            // Falls on a Saturday, so it is not counted twice
+            NaiveDate::from_ymd_opt(2024, 12, 28).unwrap(),
+        ];
+        let cases = [
+            (
+                "2024-12-02T00:00:00Z",
+                "2024-12-31T00:00:00Z",
+                &holidays[..0],
+                "21",
+            ),
+            (
+                "2024-12-02T00:00:00Z",
+                "2024-12-31T00:00:00Z",
+                &holidays[..],
+                "19",
+            ),
+            (
+                "2024-12-31T00:00:00Z",
+                "2024-12-02T00:00:00Z",
+                &holidays[..],
+                "-19",
+            ),
+            (
+                "2024-12-06T00:00:00Z",
+                "2024-12-08T00:00:00Z",
+                &holidays[..0],
+                "0",
+            ),
+        ];
+        for (start, end, holidays, expected) in cases {
+            match diff(at(start), at(end), holidays) {
+                Ok(d) => assert_eq!(d.render(DiffOutput::BusinessDays), expected),
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_natural_language() {
+        // A Wednesday
+        let now = at("2024-06-12T10:30:00+02:00");
+        let cases = [
+            ("now", "2024-06-12T10:30:00+02:00"),
+            ("today", "2024-06-12T00:00:00+02:00"),
+            ("tomorrow at noon", "2024-06-13T12:00:00+02:00"),
+            ("yesterday 9:15", "2024-06-11T09:15:00+02:00"),
+            ("next friday 3pm", "2024-06-14T15:00:00+02:00"),
+            ("friday", "2024-06-14T00:00:00+02:00"),
+            ("wednesday", "2024-06-12T00:00:00+02:00"),
+            ("next wednesday", "2024-06-19T00:00:00+02:00"),
+            ("last monday", "2024-06-10T00:00:00+02:00"),
+            ("last wed at 11:45pm", "2024-06-05T23:45:00+02:00"),
+            ("in 2 weeks", "2024-06-26T10:30:00+02:00"),
+            ("in an hour and 30 minutes", "2024-06-12T12:00:00+02:00"),
+            ("3 days ago", "2024-06-09T10:30:00+02:00"),
+            ("two fortnights from now", "2024-07-10T10:30:00+02:00"),
+            ("a year later", "2025-06-12T10:30:00+02:00"),
+            ("5 business days ago", "2024-06-05T10:30:00+02:00"),
+            ("next month", "2024-07-12T10:30:00+02:00"),
+            ("last day of next month", "2024-07-31T00:00:00+02:00"),
+            ("first day of last month", "2024-05-01T00:00:00+02:00"),
+            ("last day of february", "2024-02-29T00:00:00+02:00"),
+            ("first day of next year 8 am", "2025-01-01T08:00:00+02:00"),
+            ("12am", "2024-06-12T00:00:00+02:00"),
+            ("-1y2m", "2023-04-12T10:30:00+02:00"),
+            ("2024-01-01T00:00:00Z", "2024-01-01T00:00:00+00:00"),
+            ("2024", "2024-01-01T00:00:00+02:00"),
+            ("1700000000", "2023-11-14T22:13:20+00:00"),
+        ];
+        for (phrase, expected) in cases {
+            match parse(phrase, now) {
+                Ok(date) => assert_eq!(date.to_rfc3339(), expected, "{}", phrase),
+                Err(e) => panic!("{}: {:#?}", phrase, e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let now = at("2024-06-12T10:30:00Z");
+        for phrase in [
+            "",
+            "someday",
+            "in 2",
+            "2 days soon",
+            "13pm",
+            "first day of june 2024 soon",
+        ] {
+            assert!(parse(phrase, now).is_err(), "{}", phrase);
+        }
+    }
+
+    #[test]
+    fn test_humanize() {
+        let now = at("2024-06-12T10:30:00Z");
+        let cases = [
+            ("2024-06-12T10:30:00Z", "now"),
+            ("2024-06-12T10:30:01Z", "in 1 second"),
+            ("2024-06-12T10:29:15Z", "45 seconds ago"),
+            ("2024-06-12T13:45:00Z", "in 3 hours"),
+            ("2024-06-10T10:30:00Z", "2 days ago"),
+            ("2024-06-26T11:00:00Z", "in 2 weeks"),
+            ("2024-09-13T00:00:00Z", "in 3 months"),
+            ("2021-01-01T00:00:00Z", "3 years ago"),
+        ];
+        for (target, expected) in cases {
+            match humanize(at(target), now) {
+                Ok(s) => assert_eq!(s, expected, "{}", target),
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+    }
 }
@@ -3,18 +3,75 @@ use std::{
     str::FromStr,
 };
 
-use chrono::{DateTime, LocalResult, TimeZone, Utc};
-use strum_macros::{EnumIter, EnumString, EnumVariantNames};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{
+    DateTime, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+};
+use chrono_tz::{Tz, TZ_VARIANTS};
+use regex::Regex;
+use strum_macros::{Display, EnumIter, EnumString, EnumVariantNames};
 
-#[derive(EnumIter, EnumString, EnumVariantNames)]
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumIter, EnumString, EnumVariantNames)]
 #[strum(serialize_all = "lowercase")]
 pub enum DateTimeFormat {
+    // Tries the other formats in turn, see `detect`
+    Auto,
     Iso8601,
     Iso9075,
     Rfc3339,
     Rfc2822,
     Epoch,
     Unix,
+    #[strum(to_string = "epoch-ms", serialize = "epochms")]
+    EpochMs,
+    #[strum(to_string = "epoch-us", serialize = "epochus")]
+    EpochUs,
+    #[strum(to_string = "epoch-ns", serialize = "epochns")]
+    EpochNs,
+    // Windows FILETIME, 100ns intervals since 1601-01-01
+    Filetime,
+    // .NET `DateTime.Ticks`, 100ns intervals since 0001-01-01
+    #[strum(to_string = "ticks", serialize = "dotnet")]
+    Ticks,
+    // Days since 1899-12-30, keeping Lotus 1-2-3's fictitious 1900-02-29
+    #[strum(to_string = "excel", serialize = "lotus")]
+    Excel,
+    // Apple Cocoa `NSDate`, seconds since 2001-01-01
+    Cocoa,
+    // Seconds since 1980-01-06, without the leap seconds UTC has inserted since
+    Gps,
+    // Seconds since 1900-01-01
+    Ntp,
+}
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SECOND;
+
+// Unix time of each leap second since the GPS epoch, GPS time is ahead of UTC by one second more
+// after each of them
+const LEAP_SECONDS: [i64; 18] = [
+    362793600, 394329600, 425865600, 489024000, 567993600, 631152000, 662688000, 709948800,
+    741484800, 773020800, 820454400, 867715200, 915148800, 1136073600, 1230768000, 1341100800,
+    1435708800, 1483228800,
+];
+const GPS_EPOCH: i64 = 315964800;
+
+impl DateTimeFormat {
+    // The length of one unit and the Unix time of zero, in seconds, for formats that count units
+    // from a fixed epoch
+    fn scale(&self) -> Option<(i128, i128)> {
+        match self {
+            DateTimeFormat::Epoch | DateTimeFormat::Unix => Some((NANOS_PER_SECOND, 0)),
+            DateTimeFormat::EpochMs => Some((1_000_000, 0)),
+            DateTimeFormat::EpochUs => Some((1_000, 0)),
+            DateTimeFormat::EpochNs => Some((1, 0)),
+            DateTimeFormat::Filetime => Some((100, -11_644_473_600)),
+            DateTimeFormat::Ticks => Some((100, -62_135_596_800)),
+            DateTimeFormat::Cocoa => Some((NANOS_PER_SECOND, 978_307_200)),
+            DateTimeFormat::Ntp => Some((NANOS_PER_SECOND, -2_208_988_800)),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -28,72 +85,434 @@ impl Display for DateTimeError {
     }
 }
 
-pub fn convert(from: &str, to: &str, content: &str) -> Result<String, DateTimeError> {
-    let from_dt = match DateTimeFormat::from_str(from) {
-        Ok(dtf) => match dtf {
-            DateTimeFormat::Epoch | DateTimeFormat::Unix => {
-                let from_i64 = match content.parse::<i64>() {
-                    Ok(from_i64) => from_i64,
-                    Err(_) => {
-                        return Err(DateTimeError {
-                            message: format!("Cannot parse {} to i64", from),
-                        })
-                    }
-                };
-                match Utc.timestamp_opt(from_i64, 0) {
-                    LocalResult::Single(from_dt) => from_dt.fixed_offset(),
-                    _ => {
-                        return Err(DateTimeError {
-                            message: format!("Cannot parse {} to DateTime", from),
-                        })
-                    }
-                }
-            }
-            DateTimeFormat::Iso8601 | DateTimeFormat::Rfc3339 => {
-                match content.parse::<chrono::DateTime<Utc>>() {
-                    Ok(from_dt) => from_dt.fixed_offset(),
-                    Err(_) => {
-                        return Err(DateTimeError {
-                            message: format!("Cannot parse {} to DateTime", from),
-                        })
-                    }
-                }
+// A time zone given either as an IANA name (`America/Sao_Paulo`) or as a fixed offset (`+05:30`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Zone {
+    Named(Tz),
+    Fixed(FixedOffset),
+}
+
+impl FromStr for Zone {
+    type Err = DateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        if let Some(tz) = TZ_VARIANTS
+            .iter()
+            .find(|tz| tz.name().eq_ignore_ascii_case(name))
+        {
+            return Ok(Zone::Named(*tz));
+        }
+        if name.eq_ignore_ascii_case("z") {
+            return Ok(Zone::Named(Tz::UTC));
+        }
+
+        let invalid = || DateTimeError {
+            message: format!("Unknown time zone: {}", name),
+        };
+        let pattern = match Regex::new(r"^(?i)(?:utc|gmt)?([+-])(\d{1,2})(?::?(\d{2}))?$") {
+            Ok(p) => p,
+            Err(_) => return Err(invalid()),
+        };
+        let captures = pattern.captures(name).ok_or_else(invalid)?;
+        let hours = captures[2].parse::<i32>().map_err(|_| invalid())?;
+        let minutes = match captures.get(3) {
+            Some(m) => m.as_str().parse::<i32>().map_err(|_| invalid())?,
+            None => 0,
+        };
+        if minutes >= 60 {
+            return Err(invalid());
+        }
+        let seconds = (hours * 3600 + minutes * 60) * if &captures[1] == "-" { -1 } else { 1 };
+        match FixedOffset::east_opt(seconds) {
+            Some(offset) => Ok(Zone::Fixed(offset)),
+            None => Err(invalid()),
+        }
+    }
+}
+
+impl Display for Zone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Zone::Named(tz) => write!(f, "{}", tz.name()),
+            Zone::Fixed(offset) => write!(f, "{}", offset),
+        }
+    }
+}
+
+impl Zone {
+    pub fn at(&self, instant: &DateTime<Utc>) -> DateTime<FixedOffset> {
+        match self {
+            Zone::Named(tz) => instant.with_timezone(tz).fixed_offset(),
+            Zone::Fixed(offset) => instant.with_timezone(offset),
+        }
+    }
+
+    pub fn local(&self, naive: &NaiveDateTime) -> LocalResult<DateTime<FixedOffset>> {
+        match self {
+            Zone::Named(tz) => tz.from_local_datetime(naive).map(|dt| dt.fixed_offset()),
+            Zone::Fixed(offset) => offset.from_local_datetime(naive),
+        }
+    }
+
+    // Resolves a wall clock time in this zone, reporting times that a DST transition skips
+    // or repeats instead of silently picking one
+    pub fn localise(&self, naive: &NaiveDateTime) -> Result<DateTime<FixedOffset>, DateTimeError> {
+        match self.local(naive) {
+            LocalResult::Single(dt) => Ok(dt),
+            LocalResult::Ambiguous(earliest, latest) => Err(DateTimeError {
+                message: format!(
+                    "{} is ambiguous in {}, it occurs twice: {} and {}",
+                    naive,
+                    self,
+                    earliest.to_rfc3339(),
+                    latest.to_rfc3339()
+                ),
+            }),
+            LocalResult::None => Err(DateTimeError {
+                message: format!(
+                    "{} does not exist in {}, it is skipped by a daylight saving transition",
+                    naive, self
+                ),
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ZoneOptions {
+    // Zone used for inputs that carry no offset of their own
+    pub from: Zone,
+    // Zones to show the result in; more than one lists the instant in each, like a world clock
+    pub to: Vec<Zone>,
+}
+
+impl Default for ZoneOptions {
+    fn default() -> Self {
+        ZoneOptions {
+            from: Zone::Named(Tz::UTC),
+            to: vec![Zone::Named(Tz::UTC)],
+        }
+    }
+}
+
+// Formats tried, in order, when the input format is `auto`
+const AUTO_FORMATS: [DateTimeFormat; 4] = [
+    DateTimeFormat::Epoch,
+    DateTimeFormat::Iso8601,
+    DateTimeFormat::Iso9075,
+    DateTimeFormat::Rfc2822,
+];
+
+const ISO9075: &str = "%Y-%m-%d %H:%M:%S";
+
+// Either one of the named formats or a strftime pattern such as `%d/%m/%Y %H:%M`
+enum Pattern {
+    Named(DateTimeFormat),
+    Strftime(String),
+}
+
+fn pattern(format: &str, direction: &str) -> Result<Pattern, DateTimeError> {
+    match DateTimeFormat::from_str(format) {
+        Ok(dtf) => Ok(Pattern::Named(dtf)),
+        Err(_) if format.contains('%') => {
+            if StrftimeItems::new(format).any(|item| item == Item::Error) {
+                return Err(DateTimeError {
+                    message: format!("Invalid strftime pattern: {}", format),
+                });
             }
-            DateTimeFormat::Rfc2822 => match DateTime::parse_from_rfc2822(content) {
-                Ok(from_dt) => from_dt,
-                Err(_) => {
+            Ok(Pattern::Strftime(format.to_string()))
+        }
+        Err(_) => Err(DateTimeError {
+            message: format!("Invalid '{}' format: {}", direction, format),
+        }),
+    }
+}
+
+fn parse_as(
+    format: &DateTimeFormat,
+    content: &str,
+    zones: &ZoneOptions,
+) -> Result<DateTime<FixedOffset>, DateTimeError> {
+    let content = content.trim();
+    let invalid = || DateTimeError {
+        message: format!("Cannot parse {} to DateTime", format),
+    };
+    match format {
+        DateTimeFormat::Excel => {
+            let serial = parse_units(content, NANOS_PER_DAY).ok_or_else(invalid)?;
+            // Lotus 1-2-3 treated 1900 as a leap year and Excel kept the bug for compatibility,
+            // so serials before 1900-03-01 count from one day later
+            let unix = match serial {
+                s if s < 60 * NANOS_PER_DAY => s - 25_568 * NANOS_PER_DAY,
+                s if s < 61 * NANOS_PER_DAY => {
                     return Err(DateTimeError {
-                        message: format!("Cannot parse {} to DateTime", from),
+                        message: "Excel serial 60 is 1900-02-29, which does not exist".to_string(),
                     })
                 }
-            },
-            _ => {
-                return Err(DateTimeError {
-                    message: "`from` not implemented".to_string(),
-                })
+                s => s - 25_569 * NANOS_PER_DAY,
+            };
+            from_nanos(unix).ok_or_else(invalid)
+        }
+        DateTimeFormat::Gps => {
+            let gps = parse_units(content, NANOS_PER_SECOND).ok_or_else(invalid)?;
+            let gps_seconds = gps.div_euclid(NANOS_PER_SECOND);
+            let leaps = LEAP_SECONDS
+                .iter()
+                .enumerate()
+                .filter(|(i, leap)| gps_seconds > (**leap - GPS_EPOCH) as i128 + *i as i128)
+                .count() as i128;
+            from_nanos(gps + (GPS_EPOCH as i128 - leaps) * NANOS_PER_SECOND).ok_or_else(invalid)
+        }
+        DateTimeFormat::Ntp if content.starts_with("0x") => {
+            // The 64-bit on-the-wire form: 32 bits of seconds and 32 bits of fraction
+            let raw = u64::from_str_radix(&content[2..], 16).map_err(|_| invalid())? as i128;
+            let nanos =
+                (raw >> 32) * NANOS_PER_SECOND + (((raw & 0xFFFF_FFFF) * NANOS_PER_SECOND) >> 32);
+            from_nanos(nanos - 2_208_988_800 * NANOS_PER_SECOND).ok_or_else(invalid)
+        }
+        DateTimeFormat::Iso8601
+        | DateTimeFormat::Rfc3339
+        | DateTimeFormat::Iso9075
+        | DateTimeFormat::Rfc2822
+        | DateTimeFormat::Auto => parse_text(format, content, zones),
+        _ => match format.scale() {
+            Some((unit, epoch)) => {
+                let units = parse_units(content, unit).ok_or_else(|| DateTimeError {
+                    message: format!("Cannot parse {} to a number", format),
+                })?;
+                from_nanos(units + epoch * NANOS_PER_SECOND).ok_or_else(invalid)
+            }
+            None => Err(invalid()),
+        },
+    }
+}
+
+fn parse_text(
+    format: &DateTimeFormat,
+    content: &str,
+    zones: &ZoneOptions,
+) -> Result<DateTime<FixedOffset>, DateTimeError> {
+    let invalid = || DateTimeError {
+        message: format!("Cannot parse {} to DateTime", format),
+    };
+    match format {
+        DateTimeFormat::Iso8601 | DateTimeFormat::Rfc3339 => {
+            match content.parse::<DateTime<Utc>>() {
+                Ok(from_dt) => Ok(from_dt.fixed_offset()),
+                // Without an offset the input is a wall clock time in the `from` zone
+                Err(_) => match content.parse::<NaiveDateTime>() {
+                    Ok(naive) => zones.from.localise(&naive),
+                    Err(_) => match content.parse::<NaiveDate>() {
+                        Ok(date) => zones.from.localise(&date.and_time(NaiveTime::MIN)),
+                        Err(_) => Err(invalid()),
+                    },
+                },
             }
+        }
+        // SQL timestamps never carry an offset, so they are always read in the `from` zone
+        DateTimeFormat::Iso9075 => {
+            match NaiveDateTime::parse_from_str(content, &format!("{}%.f", ISO9075)) {
+                Ok(naive) => zones.from.localise(&naive),
+                Err(_) => Err(invalid()),
+            }
+        }
+        DateTimeFormat::Rfc2822 => match DateTime::parse_from_rfc2822(content) {
+            Ok(from_dt) => Ok(from_dt),
+            Err(_) => Err(invalid()),
         },
-        Err(_) => {
-            return Err(DateTimeError {
-                message: format!("Invalid 'from' format: {}", from),
-            })
+        DateTimeFormat::Auto => Ok(detect(content, zones)?.1),
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_strftime(
+    pattern: &str,
+    content: &str,
+    zones: &ZoneOptions,
+) -> Result<DateTime<FixedOffset>, DateTimeError> {
+    let content = content.trim();
+    if let Ok(from_dt) = DateTime::parse_from_str(content, pattern) {
+        return Ok(from_dt);
+    }
+    if let Ok(naive) = NaiveDateTime::parse_from_str(content, pattern) {
+        return zones.from.localise(&naive);
+    }
+    match NaiveDate::parse_from_str(content, pattern) {
+        Ok(date) => zones.from.localise(&date.and_time(NaiveTime::MIN)),
+        Err(e) => Err(DateTimeError {
+            message: format!("Cannot parse {} with {}: {}", content, pattern, e),
+        }),
+    }
+}
+
+// Picks the unit of an epoch from its magnitude: anything below 1e11 is taken as seconds (up to
+// the year 5138), then each further factor of a thousand as milli-, micro- and nanoseconds
+fn epoch_unit(content: &str) -> DateTimeFormat {
+    let whole = content.trim().trim_start_matches(['-', '+']);
+    let digits = whole
+        .split('.')
+        .next()
+        .unwrap_or("")
+        .trim_start_matches('0')
+        .len();
+    match digits {
+        0..=11 => DateTimeFormat::Epoch,
+        12..=14 => DateTimeFormat::EpochMs,
+        15..=17 => DateTimeFormat::EpochUs,
+        _ => DateTimeFormat::EpochNs,
+    }
+}
+
+// Reads a decimal number of units as exact nanoseconds, so fractions such as Excel's time of day
+// do not pick up floating point error
+fn parse_units(content: &str, unit: i128) -> Option<i128> {
+    let (negative, digits) = match content.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, content.strip_prefix('+').unwrap_or(content)),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+        return None;
+    }
+
+    let mut nanos = match whole {
+        "" => 0,
+        whole => whole.parse::<i128>().ok()?.checked_mul(unit)?,
+    };
+    // Digits past the 18th are far below a nanosecond for every unit
+    let fraction = &fraction[..fraction.len().min(18)];
+    if !fraction.is_empty() {
+        let scale = 10i128.pow(fraction.len() as u32);
+        nanos += (fraction.parse::<i128>().ok()? * unit + scale / 2) / scale;
+    }
+    Some(if negative { -nanos } else { nanos })
+}
+
+fn format_units(nanos: i128, unit: i128) -> String {
+    let sign = if nanos < 0 { "-" } else { "" };
+    let whole = nanos.abs() / unit;
+    let mut remainder = nanos.abs() % unit;
+    let mut fraction = String::new();
+    while remainder != 0 && fraction.len() < 15 {
+        remainder *= 10;
+        fraction.push(char::from(b'0' + (remainder / unit) as u8));
+        remainder %= unit;
+    }
+    match fraction.trim_end_matches('0') {
+        "" => format!("{}{}", sign, whole),
+        fraction => format!("{}{}.{}", sign, whole, fraction),
+    }
+}
+
+fn from_nanos(nanos: i128) -> Option<DateTime<FixedOffset>> {
+    let seconds = i64::try_from(nanos.div_euclid(NANOS_PER_SECOND)).ok()?;
+    let subsec = nanos.rem_euclid(NANOS_PER_SECOND) as u32;
+    Utc.timestamp_opt(seconds, subsec)
+        .single()
+        .map(|from_dt| from_dt.fixed_offset())
+}
+
+fn to_nanos(from_dt: &DateTime<FixedOffset>) -> i128 {
+    from_dt.timestamp() as i128 * NANOS_PER_SECOND + from_dt.timestamp_subsec_nanos() as i128
+}
+
+// Tries each known format in turn and returns the first one that reads the input
+pub fn detect(
+    content: &str,
+    zones: &ZoneOptions,
+) -> Result<(DateTimeFormat, DateTime<FixedOffset>), DateTimeError> {
+    let mut gap = None;
+    for format in AUTO_FORMATS {
+        let format = match format {
+            DateTimeFormat::Epoch => epoch_unit(content),
+            format => format,
+        };
+        match parse_as(&format, content, zones) {
+            Ok(from_dt) => return Ok((format, from_dt)),
+            // The input matched but falls in a DST gap or overlap, which is worth reporting as is
+            Err(e) if !e.message.starts_with("Cannot parse") => gap = Some(e),
+            Err(_) => continue,
         }
+    }
+    Err(gap.unwrap_or_else(|| DateTimeError {
+        message: format!(
+            "Cannot detect the format of {}, tried: {}",
+            content.trim(),
+            AUTO_FORMATS.map(|f| f.to_string()).join(", ")
+        ),
+    }))
+}
+
+pub fn convert(
+    from: &str,
+    to: &str,
+    content: &str,
+    zones: &ZoneOptions,
+) -> Result<String, DateTimeError> {
+    let from_dt = match pattern(from, "from")? {
+        Pattern::Named(dtf) => parse_as(&dtf, content, zones)?,
+        Pattern::Strftime(p) => parse_strftime(&p, content, zones)?,
     };
+    let to = pattern(to, "to")?;
+
+    let instant = from_dt.with_timezone(&Utc);
+    match zones.to.as_slice() {
+        [zone] => format_as(&to, &zone.at(&instant)),
+        many => {
+            let width = many.iter().map(|z| z.to_string().len()).max().unwrap_or(0);
+            let mut lines = vec![];
+            for zone in many {
+                let formatted = format_as(&to, &zone.at(&instant))?;
+                lines.push(format!("{:<width$}  {}", zone.to_string(), formatted));
+            }
+            Ok(lines.join("\n"))
+        }
+    }
+}
+
+// Writes a date time in one of the named formats or a strftime pattern
+pub fn format(to: &str, from_dt: &DateTime<FixedOffset>) -> Result<String, DateTimeError> {
+    format_as(&pattern(to, "to")?, from_dt)
+}
 
-    match DateTimeFormat::from_str(to) {
-        Ok(dtf) => match dtf {
+fn format_as(to: &Pattern, from_dt: &DateTime<FixedOffset>) -> Result<String, DateTimeError> {
+    match to {
+        Pattern::Named(dtf) => match dtf {
             DateTimeFormat::Epoch | DateTimeFormat::Unix => Ok(from_dt.timestamp().to_string()),
+            DateTimeFormat::Excel => {
+                let mut serial = to_nanos(from_dt) + 25_569 * NANOS_PER_DAY;
+                if serial < 61 * NANOS_PER_DAY {
+                    serial -= NANOS_PER_DAY;
+                }
+                Ok(format_units(serial, NANOS_PER_DAY))
+            }
+            DateTimeFormat::Gps => {
+                let seconds = from_dt.timestamp();
+                let leaps = LEAP_SECONDS.iter().filter(|leap| **leap <= seconds).count() as i128;
+                let gps = to_nanos(from_dt) + (leaps - GPS_EPOCH as i128) * NANOS_PER_SECOND;
+                Ok(format_units(gps, NANOS_PER_SECOND))
+            }
             // ISO8601 and RFC3339 are not exactly the same, but it will do for now
             // https://ijmacd.github.io/rfc3339-iso8601/
             DateTimeFormat::Iso8601 | DateTimeFormat::Rfc3339 => Ok(from_dt.to_rfc3339()),
+            DateTimeFormat::Iso9075 => Ok(from_dt.format(ISO9075).to_string()),
             DateTimeFormat::Rfc2822 => Ok(from_dt.to_rfc2822()),
-            _ => Err(DateTimeError {
-                message: "`to` not implemented".to_string(),
+            DateTimeFormat::Auto => Err(DateTimeError {
+                message: "`auto` can only be used as the 'from' format".to_string(),
             }),
+            _ => match dtf.scale() {
+                Some((unit, epoch)) => Ok(format_units(
+                    to_nanos(from_dt) - epoch * NANOS_PER_SECOND,
+                    unit,
+                )),
+                None => Err(DateTimeError {
+                    message: format!("Cannot format DateTime as {}", dtf),
+                }),
+            },
         },
-        Err(_) => Err(DateTimeError {
-            message: format!("Invalid 'to' format: {}", to),
-        }),
+        Pattern::Strftime(p) => Ok(from_dt.format(p).to_string()),
     }
 }
 
@@ -1,4 +1,6 @@
 use crate::dev_utils::datetime::*;
+use chrono::FixedOffset;
+use std::str::FromStr;
 
 mod tests {
     use super::*;
@@ -6,56 +8,76 @@ mod tests {
     #[test]
     fn test_epoch_to_iso8601() {
         assert_eq!(
-            convert("epoch", "iso8601", "1"),
+            convert("epoch", "iso8601", "1", &ZoneOptions::default()),
             Ok("1970-01-01T00:00:01+00:00".to_string())
         );
     }
     #[test]
     fn test_unix_to_iso8601() {
         assert_eq!(
-            convert("unix", "iso8601", "1"),
+            convert("unix", "iso8601", "1", &ZoneOptions::default()),
             Ok("1970-01-01T00:00:01+00:00".to_string())
         );
     }
     #[test]
     fn test_epoch_to_rfc3339() {
         assert_eq!(
-            convert("epoch", "rfc3339", "1"),
+            convert("epoch", "rfc3339", "1", &ZoneOptions::default()),
             Ok("1970-01-01T00:00:01+00:00".to_string())
         );
     }
     #[test]
     fn test_unix_to_rfc3339() {
         assert_eq!(
-            convert("unix", "rfc3339", "1"),
+            convert("unix", "rfc3339", "1", &ZoneOptions::default()),
             Ok("1970-01-01T00:00:01+00:00".to_string())
         );
     }
     #[test]
     fn test_iso8601_to_epoch() {
         assert_eq!(
-            convert("iso8601", "epoch", "1970-01-01T00:00:01+00:00"),
+            convert(
+                "iso8601",
+                "epoch",
+                "1970-01-01T00:00:01+00:00",
+                &ZoneOptions::default()
+            ),
             Ok("1".to_string())
         );
     }
     #[test]
     fn test_iso8601_to_unix() {
         assert_eq!(
-            convert("iso8601", "unix", "1970-01-01T00:00:01+00:00"),
+            convert(
+                "iso8601",
+                "unix",
+                "1970-01-01T00:00:01+00:00",
+                &ZoneOptions::default()
+            ),
             Ok("1".to_string())
         );
     }
     #[test]
     fn test_rfc3999_to_epoch() {
         assert_eq!(
-            convert("rfc3339", "epoch", "1970-01-01T00:00:01+00:00"),
+            convert(
+                "rfc3339",
+                "epoch",
+                "1970-01-01T00:00:01+00:00",
+                &ZoneOptions::default()
+            ),
             Ok("1".to_string())
         );
     }
     #[test]
     fn test_rfc3339_to_unix() {
         assert_eq!(
-            convert("rfc3339", "unix", "1970-01-01T00:00:01+00:00"),
+            convert(
+                "rfc3339",
+                "unix",
+                "1970-01-01T00:00:01+00:00",
+                &ZoneOptions::default()
+            ),
             Ok("1".to_string())
         );
     }
@@ -63,7 +85,7 @@ mod tests {
     #[test]
     fn epoch_to_rfc2822() {
         assert_eq!(
-            convert("epoch", "rfc2822", "1"),
+            convert("epoch", "rfc2822", "1", &ZoneOptions::default()),
             Ok("Thu, 1 Jan 1970 00:00:01 +0000".to_string())
         );
     }
@@ -71,7 +93,7 @@ mod tests {
     #[test]
     fn unix_to_rfc2822() {
         assert_eq!(
-            convert("unix", "rfc2822", "1"),
+            convert("unix", "rfc2822", "1", &ZoneOptions::default()),
             Ok("Thu, 1 Jan 1970 00:00:01 +0000".to_string())
         );
     }
@@ -79,7 +101,12 @@ mod tests {
     #[test]
     fn test_rfc2822_to_epoch() {
         assert_eq!(
-            convert("rfc2822", "epoch", "Thu, 01 Jan 1970 00:00:01 GMT"),
+            convert(
+                "rfc2822",
+                "epoch",
+                "Thu, 01 Jan 1970 00:00:01 GMT",
+                &ZoneOptions::default()
+            ),
             Ok("1".to_string())
         );
     }
@@ -87,7 +114,12 @@ mod tests {
     #[test]
     fn test_rfc2822_to_unix() {
         assert_eq!(
-            convert("rfc2822", "unix", "Thu, 01 Jan 1970 00:00:01 GMT"),
+            convert(
+                "rfc2822",
+                "unix",
+                "Thu, 01 Jan 1970 00:00:01 GMT",
+                &ZoneOptions::default()
+            ),
             Ok("1".to_string())
         );
     }
@@ -95,7 +127,12 @@ mod tests {
     #[test]
     fn test_rfc2822_to_iso8601() {
         assert_eq!(
-            convert("rfc2822", "iso8601", "Thu, 01 Jan 1970 00:00:01 GMT"),
+            convert(
+                "rfc2822",
+                "iso8601",
+                "Thu, 01 Jan 1970 00:00:01 GMT",
+                &ZoneOptions::default()
+            ),
             Ok("1970-01-01T00:00:01+00:00".to_string())
         );
     }
@@ -103,7 +140,12 @@ mod tests {
     #[test]
     fn test_rfc2822_to_rfc3339() {
         assert_eq!(
-            convert("rfc2822", "rfc3339", "Thu, 01 Jan 1970 00:00:01 GMT"),
+            convert(
+                "rfc2822",
+                "rfc3339",
+                "Thu, 01 Jan 1970 00:00:01 GMT",
+                &ZoneOptions::default()
+            ),
             Ok("1970-01-01T00:00:01+00:00".to_string())
         );
     }
@@ -111,7 +153,12 @@ mod tests {
     #[test]
     fn test_iso8601_to_rfc2822() {
         assert_eq!(
-            convert("iso8601", "rfc2822", "1970-01-01T00:00:01+00:00"),
+            convert(
+                "iso8601",
+                "rfc2822",
+                "1970-01-01T00:00:01+00:00",
+                &ZoneOptions::default()
+            ),
             Ok("Thu, 1 Jan 1970 00:00:01 +0000".to_string())
         )
     }
@@ -119,8 +166,368 @@ mod tests {
     #[test]
     fn test_rfc3339_to_rfc2822() {
         assert_eq!(
-            convert("rfc3339", "rfc2822", "1970-01-01T00:00:01+00:00"),
+            convert(
+                "rfc3339",
+                "rfc2822",
+                "1970-01-01T00:00:01+00:00",
+                &ZoneOptions::default()
+            ),
             Ok("Thu, 1 Jan 1970 00:00:01 +0000".to_string())
         )
     }
+
+    fn zones(from: &str, to: &[&str]) -> ZoneOptions {
+        ZoneOptions {
+            from: Zone::from_str(from).unwrap(),
+            to: to.iter().map(|z| Zone::from_str(z).unwrap()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_zone() {
+        assert_eq!(
+            Zone::from_str("america/sao_paulo"),
+            Ok(Zone::Named(chrono_tz::America::Sao_Paulo))
+        );
+        assert_eq!(
+            Zone::from_str("+05:30"),
+            Ok(Zone::Fixed(FixedOffset::east_opt(19800).unwrap()))
+        );
+        assert_eq!(
+            Zone::from_str("UTC-0800"),
+            Ok(Zone::Fixed(FixedOffset::west_opt(28800).unwrap()))
+        );
+        assert!(Zone::from_str("Mars/Olympus_Mons").is_err());
+        assert!(Zone::from_str("+05:75").is_err());
+    }
+
+    #[test]
+    fn test_epoch_in_zone() {
+        let result = convert(
+            "epoch",
+            "iso8601",
+            "1700000000",
+            &zones("UTC", &["America/Sao_Paulo"]),
+        );
+        match result {
+            Ok(s) => assert_eq!(s, "2023-11-14T19:13:20-03:00"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = convert("epoch", "rfc2822", "0", &zones("UTC", &["+05:30"]));
+        match result {
+            Ok(s) => assert_eq!(s, "Thu, 1 Jan 1970 05:30:00 +0530"),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_local_time_between_zones() {
+        let result = convert(
+            "iso8601",
+            "iso8601",
+            "2024-07-01T09:00:00",
+            &zones("Europe/London", &["Asia/Tokyo"]),
+        );
+        match result {
+            Ok(s) => assert_eq!(s, "2024-07-01T17:00:00+09:00"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        // An explicit offset wins over `from`
+        let result = convert(
+            "iso8601",
+            "epoch",
+            "1970-01-01T01:00:00+01:00",
+            &zones("Asia/Tokyo", &["UTC"]),
+        );
+        assert_eq!(result, Ok("0".to_string()));
+    }
+
+    #[test]
+    fn test_dst_gap_and_overlap() {
+        let gap = convert(
+            "iso8601",
+            "iso8601",
+            "2024-03-10T02:30:00",
+            &zones("America/New_York", &["UTC"]),
+        );
+        assert_eq!(
+            gap,
+            Err(DateTimeError {
+                message: "2024-03-10 02:30:00 does not exist in America/New_York, it is skipped by a daylight saving transition".to_string()
+            })
+        );
+
+        let overlap = convert(
+            "iso8601",
+            "iso8601",
+            "2024-11-03T01:30:00",
+            &zones("America/New_York", &["UTC"]),
+        );
+        assert_eq!(
+            overlap,
+            Err(DateTimeError {
+                message: "2024-11-03 01:30:00 is ambiguous in America/New_York, it occurs twice: 2024-11-03T01:30:00-04:00 and 2024-11-03T01:30:00-05:00".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_world_clock() {
+        let result = convert(
+            "epoch",
+            "iso8601",
+            "1700000000",
+            &zones("UTC", &["UTC", "Asia/Kolkata", "America/Los_Angeles"]),
+        );
+        match result {
+            Ok(s) => assert_eq!(
+                s,
+                "UTC                  2023-11-14T22:13:20+00:00\n\
+                 Asia/Kolkata         2023-11-15T03:43:20+05:30\n\
+                 America/Los_Angeles  2023-11-14T14:13:20-08:00"
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_iso9075() {
+        let result = convert(
+            "iso9075",
+            "iso8601",
+            "2024-02-29 13:45:00",
+            &ZoneOptions::default(),
+        );
+        match result {
+            Ok(s) => assert_eq!(s, "2024-02-29T13:45:00+00:00"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = convert(
+            "epoch",
+            "iso9075",
+            "1700000000",
+            &zones("UTC", &["Europe/Paris"]),
+        );
+        match result {
+            Ok(s) => assert_eq!(s, "2023-11-14 23:13:20"),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_strftime_patterns() {
+        let result = convert(
+            "%d/%m/%Y %H:%M",
+            "epoch",
+            "02/01/1970 00:00",
+            &ZoneOptions::default(),
+        );
+        assert_eq!(result, Ok("86400".to_string()));
+
+        let result = convert(
+            "%d/%m/%Y",
+            "iso8601",
+            "25/12/2023",
+            &zones("Asia/Tokyo", &["Asia/Tokyo"]),
+        );
+        match result {
+            Ok(s) => assert_eq!(s, "2023-12-25T00:00:00+09:00"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = convert(
+            "epoch",
+            "%A %e %B %Y, %H:%M %Z",
+            "0",
+            &ZoneOptions::default(),
+        );
+        match result {
+            Ok(s) => assert_eq!(s, "Thursday  1 January 1970, 00:00 +00:00"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        assert!(convert("%Q", "epoch", "1", &ZoneOptions::default()).is_err());
+        assert!(convert("%d/%m/%Y", "epoch", "2023-12-25", &ZoneOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_auto_detection() {
+        let cases = [
+            ("1700000000", DateTimeFormat::Epoch),
+            ("2023-11-14T22:13:20Z", DateTimeFormat::Iso8601),
+            ("2023-11-14", DateTimeFormat::Iso8601),
+            ("2023-11-14 22:13:20", DateTimeFormat::Iso9075),
+            ("Tue, 14 Nov 2023 22:13:20 +0000", DateTimeFormat::Rfc2822),
+        ];
+        for (content, expected) in cases {
+            match detect(content, &ZoneOptions::default()) {
+                Ok((format, _)) => assert_eq!(format, expected),
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+
+        assert_eq!(
+            convert(
+                "auto",
+                "epoch",
+                "2023-11-14 22:13:20",
+                &ZoneOptions::default()
+            ),
+            Ok("1700000000".to_string())
+        );
+        assert!(detect("next tuesday", &ZoneOptions::default()).is_err());
+        assert!(convert("epoch", "auto", "1", &ZoneOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_sub_second_epochs() {
+        let cases = [
+            ("epoch-ms", "1700000000123", "2023-11-14T22:13:20.123+00:00"),
+            (
+                "epoch-us",
+                "1700000000123456",
+                "2023-11-14T22:13:20.123456+00:00",
+            ),
+            (
+                "epoch-ns",
+                "1700000000123456789",
+                "2023-11-14T22:13:20.123456789+00:00",
+            ),
+            ("epoch", "1700000000.5", "2023-11-14T22:13:20.500+00:00"),
+            ("epochms", "-1", "1969-12-31T23:59:59.999+00:00"),
+        ];
+        for (from, content, expected) in cases {
+            match convert(from, "iso8601", content, &ZoneOptions::default()) {
+                Ok(s) => assert_eq!(s, expected),
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+
+        assert_eq!(
+            convert(
+                "iso8601",
+                "epoch-ms",
+                "2023-11-14T22:13:20.123Z",
+                &ZoneOptions::default()
+            ),
+            Ok("1700000000123".to_string())
+        );
+        assert_eq!(
+            convert("epoch", "epoch-ns", "1", &ZoneOptions::default()),
+            Ok("1000000000".to_string())
+        );
+    }
+
+    #[test]
+    fn test_auto_detects_epoch_magnitude() {
+        let cases = [
+            ("1700000000", DateTimeFormat::Epoch),
+            ("1700000000123", DateTimeFormat::EpochMs),
+            ("1700000000123456", DateTimeFormat::EpochUs),
+            ("1700000000123456789", DateTimeFormat::EpochNs),
+        ];
+        for (content, expected) in cases {
+            match detect(content, &ZoneOptions::default()) {
+                Ok((format, from_dt)) => {
+                    assert_eq!(format, expected);
+                    assert_eq!(from_dt.timestamp(), 1700000000);
+                }
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_machine_timestamps() {
+        // (format, value at 1970-01-01T00:00:00Z, value at 2023-11-14T22:13:20Z)
+        let cases = [
+            ("filetime", "116444736000000000", "133444736000000000"),
+            ("ticks", "621355968000000000", "638355968000000000"),
+            ("excel", "25569", "45244.925925925925925"),
+            ("cocoa", "-978307200", "721692800"),
+            ("gps", "-315964800", "1384035218"),
+            ("ntp", "2208988800", "3908988800"),
+        ];
+        for (format, zero, later) in cases {
+            for (epoch, value) in [("0", zero), ("1700000000", later)] {
+                assert_eq!(
+                    convert("epoch", format, epoch, &ZoneOptions::default()),
+                    Ok(value.to_string()),
+                    "epoch to {}",
+                    format
+                );
+                assert_eq!(
+                    convert(format, "epoch", value, &ZoneOptions::default()),
+                    Ok(epoch.to_string()),
+                    "{} to epoch",
+                    format
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_excel_serials() {
+        let cases = [
+            ("45000", "2023-03-15 00:00:00"),
+            ("45000.75", "2023-03-15 18:00:00"),
+            ("61", "1900-03-01 00:00:00"),
+            ("59", "1900-02-28 00:00:00"),
+            ("1", "1900-01-01 00:00:00"),
+        ];
+        for (serial, expected) in cases {
+            match convert("excel", "iso9075", serial, &ZoneOptions::default()) {
+                Ok(s) => assert_eq!(s, expected),
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+        assert!(convert("lotus", "iso9075", "60", &ZoneOptions::default()).is_err());
+        assert_eq!(
+            convert(
+                "iso9075",
+                "excel",
+                "1900-02-28 12:00:00",
+                &ZoneOptions::default()
+            ),
+            Ok("59.5".to_string())
+        );
+    }
+
+    #[test]
+    fn test_gps_leap_seconds() {
+        // GPS time was equal to UTC at its epoch and 18 seconds ahead after 2017
+        assert_eq!(
+            convert(
+                "iso8601",
+                "gps",
+                "1980-01-06T00:00:00Z",
+                &ZoneOptions::default()
+            ),
+            Ok("0".to_string())
+        );
+        assert_eq!(
+            convert("gps", "iso8601", "1167264018", &ZoneOptions::default()),
+            Ok("2017-01-01T00:00:00+00:00".to_string())
+        );
+        assert_eq!(
+            convert("gps", "iso8601", "1167264016", &ZoneOptions::default()),
+            Ok("2016-12-31T23:59:59+00:00".to_string())
+        );
+    }
+
+    #[test]
+    fn test_ntp_wire_format() {
+        assert_eq!(
+            convert(
+                "ntp",
+                "iso8601",
+                "0x83AA7E8080000000",
+                &ZoneOptions::default()
+            ),
+            Ok("1970-01-01T00:00:00.500+00:00".to_string())
+        );
+    }
 }
@@ -0,0 +1,312 @@
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::str::Chars;
+
+use strum_macros::{EnumIter, EnumString, EnumVariantNames};
+
+#[derive(Clone, Copy, PartialEq, EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum EscapeFormat {
+    Json,
+    Rust,
+    C,
+    Java,
+    // POSIX shell, single-quoted
+    Shell,
+    Sql,
+    Regex,
+    Csv,
+    Html,
+    Xml,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct EscapeError {
+    pub message: String,
+}
+
+impl Display for EscapeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+fn error(message: String) -> EscapeError {
+    EscapeError { message }
+}
+
+pub fn escape(content: &str, format: EscapeFormat, ascii: bool) -> String {
+    match format {
+        EscapeFormat::Json | EscapeFormat::Java | EscapeFormat::Rust | EscapeFormat::C => {
+            escape_backslashes(content, format, ascii)
+        }
+        EscapeFormat::Shell => format!("'{}'", content.replace('\'', r"'\''")),
+        EscapeFormat::Sql => content.replace('\'', "''"),
+        EscapeFormat::Regex => regex::escape(content),
+        EscapeFormat::Csv => {
+            if content.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", content.replace('"', "\"\""))
+            } else {
+                content.to_string()
+            }
+        }
+        EscapeFormat::Html | EscapeFormat::Xml => {
+            let mut result = String::new();
+            for c in content.chars() {
+                match c {
+                    '&' => result.push_str("&amp;"),
+                    '<' => result.push_str("&lt;"),
+                    '>' => result.push_str("&gt;"),
+                    '"' => result.push_str("&quot;"),
+                    '\'' if format == EscapeFormat::Xml => result.push_str("&apos;"),
+                    '\'' => result.push_str("&#39;"),
+                    _ if ascii && !c.is_ascii() => result.push_str(&format!("&#x{:X};", c as u32)),
+                    _ => result.push(c),
+                }
+            }
+            result
+        }
+    }
+}
+
+fn escape_backslashes(content: &str, format: EscapeFormat, ascii: bool) -> String {
+    let mut result = String::new();
+    for c in content.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\u{8}' if format != EscapeFormat::Rust => result.push_str("\\b"),
+            '\u{c}' if format != EscapeFormat::Rust => result.push_str("\\f"),
+            '\u{7}' if format == EscapeFormat::C => result.push_str("\\a"),
+            '\u{b}' if format == EscapeFormat::C => result.push_str("\\v"),
+            '\0' if format == EscapeFormat::Rust => result.push_str("\\0"),
+            _ if c.is_control() || (ascii && !c.is_ascii()) => match format {
+                EscapeFormat::Rust => result.push_str(&format!("\\u{{{:x}}}", c as u32)),
+                // Hex escapes are greedy in C, octal ones stop after three digits
+                EscapeFormat::C if (c as u32) < 0x80 => {
+                    result.push_str(&format!("\\{:03o}", c as u32))
+                }
+                EscapeFormat::C if (c as u32) > 0xFFFF => {
+                    result.push_str(&format!("\\U{:08x}", c as u32))
+                }
+                _ => {
+                    let mut units = [0; 2];
+                    for unit in c.encode_utf16(&mut units) {
+                        result.push_str(&format!("\\u{:04x}", unit));
+                    }
+                }
+            },
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+pub fn unescape(content: &str, format: EscapeFormat) -> Result<String, EscapeError> {
+    match format {
+        EscapeFormat::Json | EscapeFormat::Java | EscapeFormat::Rust | EscapeFormat::C => {
+            unescape_backslashes(content, format)
+        }
+        EscapeFormat::Shell => unescape_shell(content),
+        EscapeFormat::Sql => Ok(content.replace("''", "'")),
+        EscapeFormat::Regex => {
+            let mut result = String::new();
+            let mut chars = content.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => match chars.next() {
+                        Some(e) if !e.is_alphanumeric() => result.push(e),
+                        Some(e) => {
+                            return Err(error(format!(
+                                "\\{} is a regex construct, not an escaped character",
+                                e
+                            )))
+                        }
+                        None => return Err(error("Trailing backslash".to_string())),
+                    },
+                    _ => result.push(c),
+                }
+            }
+            Ok(result)
+        }
+        EscapeFormat::Csv => {
+            if content.len() >= 2 && content.starts_with('"') && content.ends_with('"') {
+                Ok(content[1..content.len() - 1].replace("\"\"", "\""))
+            } else {
+                Ok(content.to_string())
+            }
+        }
+        EscapeFormat::Html | EscapeFormat::Xml => {
+            Ok(html_escape::decode_html_entities(content).to_string())
+        }
+    }
+}
+
+fn take_hex(chars: &mut Peekable<Chars>, min: usize, max: usize) -> Result<u32, EscapeError> {
+    let mut digits = String::new();
+    while digits.len() < max {
+        match chars.peek() {
+            Some(c) if c.is_ascii_hexdigit() => {
+                digits.push(*c);
+                chars.next();
+            }
+            _ => break,
+        }
+    }
+    if digits.len() < min {
+        return Err(error(format!(
+            "Expected {} hex digits, found `{}`",
+            min, digits
+        )));
+    }
+    u32::from_str_radix(&digits, 16).map_err(|e| error(e.to_string()))
+}
+
+fn to_char(code: u32) -> Result<char, EscapeError> {
+    match char::from_u32(code) {
+        Some(c) => Ok(c),
+        None => Err(error(format!("Invalid code point U+{:04X}", code))),
+    }
+}
+
+// Reads the rest of a `\uXXXX` escape, pairing UTF-16 surrogates with the escape that follows
+fn take_utf16_escape(chars: &mut Peekable<Chars>) -> Result<char, EscapeError> {
+    let high = take_hex(chars, 4, 4)?;
+    if !(0xD800..=0xDFFF).contains(&high) {
+        return to_char(high);
+    }
+    if high >= 0xDC00 {
+        return Err(error(format!("Unpaired low surrogate \\u{:04x}", high)));
+    }
+    if chars.next() != Some('\\') || chars.next() != Some('u') {
+        return Err(error(format!("Unpaired high surrogate \\u{:04x}", high)));
+    }
+    let low = take_hex(chars, 4, 4)?;
+    if !(0xDC00..=0xDFFF).contains(&low) {
+        return Err(error(format!("Unpaired high surrogate \\u{:04x}", high)));
+    }
+    to_char(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+}
+
+fn unescape_backslashes(content: &str, format: EscapeFormat) -> Result<String, EscapeError> {
+    let mut result = String::new();
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        let escaped = match chars.next() {
+            Some(e) => e,
+            None => return Err(error("Trailing backslash".to_string())),
+        };
+        let unescaped = match (escaped, format) {
+            ('n', _) => '\n',
+            ('r', _) => '\r',
+            ('t', _) => '\t',
+            ('\\', _) => '\\',
+            ('"', _) => '"',
+            ('/', EscapeFormat::Json) => '/',
+            ('\'', EscapeFormat::Rust | EscapeFormat::C | EscapeFormat::Java) => '\'',
+            ('b', EscapeFormat::Json | EscapeFormat::Java | EscapeFormat::C) => '\u{8}',
+            ('f', EscapeFormat::Json | EscapeFormat::Java | EscapeFormat::C) => '\u{c}',
+            ('a', EscapeFormat::C) => '\u{7}',
+            ('v', EscapeFormat::C) => '\u{b}',
+            ('?', EscapeFormat::C) => '?',
+            ('0', EscapeFormat::Rust) => '\0',
+            ('0'..='7', EscapeFormat::C | EscapeFormat::Java) => {
+                let mut code = escaped.to_digit(8).unwrap();
+                // Java only takes a third digit after 0 to 3, so \477 is \47 followed by 7
+                let digits = match (format, code) {
+                    (EscapeFormat::Java, 4..=7) => 1,
+                    _ => 2,
+                };
+                for _ in 0..digits {
+                    match chars.peek().and_then(|d| d.to_digit(8)) {
+                        Some(d) => {
+                            code = code * 8 + d;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                if code > 0o377 {
+                    return Err(error(format!(
+                        "Octal escape \\{:o} is out of range, the largest is \\377",
+                        code
+                    )));
+                }
+                to_char(code)?
+            }
+            ('x', EscapeFormat::Rust) => to_char(take_hex(&mut chars, 2, 2)?)?,
+            ('x', EscapeFormat::C) => to_char(take_hex(&mut chars, 1, 2)?)?,
+            ('u', EscapeFormat::Rust) => {
+                if chars.next() != Some('{') {
+                    return Err(error("Expected `{` after \\u".to_string()));
+                }
+                let code = take_hex(&mut chars, 1, 6)?;
+                if chars.next() != Some('}') {
+                    return Err(error("Expected `}` to close \\u{".to_string()));
+                }
+                to_char(code)?
+            }
+            ('u', _) => take_utf16_escape(&mut chars)?,
+            ('U', EscapeFormat::C) => to_char(take_hex(&mut chars, 8, 8)?)?,
+            _ => return Err(error(format!("Invalid escape sequence \\{}", escaped))),
+        };
+        result.push(unescaped);
+    }
+
+    Ok(result)
+}
+
+// Reads a single POSIX shell word, honouring single quotes, double quotes and backslashes
+fn unescape_shell(content: &str) -> Result<String, EscapeError> {
+    let mut result = String::new();
+    let mut chars = content.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => loop {
+                match chars.next() {
+                    Some('\'') => break,
+                    Some(q) => result.push(q),
+                    None => return Err(error("Unterminated single quote".to_string())),
+                }
+            },
+            '"' => loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => match chars.next() {
+                        Some(e @ ('$' | '`' | '"' | '\\')) => result.push(e),
+                        Some('\n') => (),
+                        Some(e) => {
+                            result.push('\\');
+                            result.push(e);
+                        }
+                        None => return Err(error("Unterminated double quote".to_string())),
+                    },
+                    Some(q) => result.push(q),
+                    None => return Err(error("Unterminated double quote".to_string())),
+                }
+            },
+            '\\' => match chars.next() {
+                Some('\n') => (),
+                Some(e) => result.push(e),
+                None => return Err(error("Trailing backslash".to_string())),
+            },
+            _ => result.push(c),
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+#[path = "./escape_test.rs"]
+mod escape_test;
@@ -0,0 +1,148 @@
+use crate::dev_utils::escape::*;
+
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_escape() {
+        let text = "say \"hi\"\n\tto C:\\ and 'you'";
+        assert_eq!(
+            escape(text, EscapeFormat::Json, false),
+            r#"say \"hi\"\n\tto C:\\ and 'you'"#
+        );
+        assert_eq!(
+            escape(text, EscapeFormat::Shell, false),
+            r#"'say "hi"
+	to C:\ and '\''you'\'''"#
+        );
+        assert_eq!(escape("O'Reilly", EscapeFormat::Sql, false), "O''Reilly");
+        assert_eq!(escape("1+1=2?", EscapeFormat::Regex, false), r"1\+1=2\?");
+        assert_eq!(escape("a,\"b\"", EscapeFormat::Csv, false), r#""a,""b""""#);
+        assert_eq!(escape("plain", EscapeFormat::Csv, false), "plain");
+        assert_eq!(
+            escape("<a href='x'>&</a>", EscapeFormat::Html, false),
+            "&lt;a href=&#39;x&#39;&gt;&amp;&lt;/a&gt;"
+        );
+        assert_eq!(escape("'\"", EscapeFormat::Xml, false), "&apos;&quot;");
+        assert_eq!(escape("\u{1}\0", EscapeFormat::C, false), r"\001\000");
+        assert_eq!(escape("\u{1}\0", EscapeFormat::Rust, false), r"\u{1}\0");
+    }
+
+    #[test]
+    fn test_escape_ascii() {
+        assert_eq!(
+            escape("é😀", EscapeFormat::Json, true),
+            r"\u00e9\ud83d\ude00"
+        );
+        assert_eq!(
+            escape("é😀", EscapeFormat::Java, true),
+            r"\u00e9\ud83d\ude00"
+        );
+        assert_eq!(escape("é😀", EscapeFormat::Rust, true), r"\u{e9}\u{1f600}");
+        assert_eq!(escape("é😀", EscapeFormat::C, true), r"\u00e9\U0001f600");
+        assert_eq!(escape("é", EscapeFormat::Html, true), "&#xE9;");
+        assert_eq!(escape("é😀", EscapeFormat::Json, false), "é😀");
+    }
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(
+            unescape(r"\ud83d\ude00 \u00e9 \/", EscapeFormat::Json).unwrap(),
+            "😀 é /"
+        );
+        assert_eq!(
+            unescape(r"\u{1F600}\x41\0", EscapeFormat::Rust).unwrap(),
+            "😀A\0"
+        );
+        assert_eq!(
+            unescape(r"\101\x42\U0001F600\a", EscapeFormat::C).unwrap(),
+            "AB😀\u{7}"
+        );
+        assert_eq!(
+            unescape(r"\477\377\0", EscapeFormat::Java).unwrap(),
+            "\u{27}7\u{ff}\0"
+        );
+        assert_eq!(
+            unescape(r#"'it'\''s' "a \"b\"" c\ d"#, EscapeFormat::Shell).unwrap(),
+            "it's a \"b\" c d"
+        );
+        assert_eq!(
+            unescape(
+                "&lt;&#x41;&#66;&nbsp;&eacute;&copy;&gt;",
+                EscapeFormat::Html
+            )
+            .unwrap(),
+            "<AB\u{a0}é©>"
+        );
+        assert_eq!(
+            unescape("O''Reilly", EscapeFormat::Sql).unwrap(),
+            "O'Reilly"
+        );
+        assert_eq!(
+            unescape(r#""a,""b""""#, EscapeFormat::Csv).unwrap(),
+            "a,\"b\""
+        );
+        assert_eq!(
+            unescape(r"1\+1=2\?", EscapeFormat::Regex).unwrap(),
+            "1+1=2?"
+        );
+    }
+
+    #[test]
+    fn test_unescape_errors() {
+        assert_eq!(
+            unescape(r"\ud83d", EscapeFormat::Json),
+            Err(EscapeError {
+                message: r"Unpaired high surrogate \ud83d".to_string()
+            })
+        );
+        assert_eq!(
+            unescape(r"\ude00", EscapeFormat::Java),
+            Err(EscapeError {
+                message: r"Unpaired low surrogate \ude00".to_string()
+            })
+        );
+        assert_eq!(
+            unescape(r"\q", EscapeFormat::Json),
+            Err(EscapeError {
+                message: r"Invalid escape sequence \q".to_string()
+            })
+        );
+        assert_eq!(
+            unescape(r"\477", EscapeFormat::C),
+            Err(EscapeError {
+                message: r"Octal escape \477 is out of range, the largest is \377".to_string()
+            })
+        );
+        assert_eq!(
+            unescape("'open", EscapeFormat::Shell),
+            Err(EscapeError {
+                message: "Unterminated single quote".to_string()
+            })
+        );
+        assert!(unescape(r"\d+", EscapeFormat::Regex).is_err());
+        assert!(unescape(r"\u12", EscapeFormat::Json).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let samples = [
+            "plain",
+            "quotes \" and ' and \\ backslash",
+            "lines\nand\ttabs\r\n",
+            "control \u{1}\u{7}\u{1b}",
+            "unicode é 中文 😀 \u{200b}",
+            "markup <b>&amp;</b>",
+            "csv, \"quoted\"",
+        ];
+        for format in EscapeFormat::iter() {
+            for ascii in [false, true] {
+                for sample in samples {
+                    let escaped = escape(sample, format, ascii);
+                    assert_eq!(unescape(&escaped, format).unwrap(), sample, "{}", escaped);
+                }
+            }
+        }
+    }
+}
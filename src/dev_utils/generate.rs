@@ -1,6 +1,6 @@
 use std::fmt::{Display, Formatter};
 
-use clap::{arg, Subcommand};
+use clap::Subcommand;
 use rand::Rng;
 use strum_macros::{EnumIter, EnumString, EnumVariantNames};
 
@@ -53,7 +53,11 @@ pub enum GenerateError {
 
 impl Display for GenerateError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            GenerateError::Token(message) | GenerateError::Uuid(message) => {
+                write!(f, "{}", message)
+            }
+        }
     }
 }
 
@@ -0,0 +1,255 @@
+use regex::Regex;
+
+use super::convert::ConversionError;
+use super::number::NumberLocale;
+use super::units::{self, Dimension};
+
+const IEC_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+const SI_UNITS: [&str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+
+// Components of a humanized duration, largest first
+const DURATION_UNITS: [(&str, u64); 4] = [("d", 86400), ("h", 3600), ("m", 60), ("s", 1)];
+
+fn trim_decimals(value: String) -> String {
+    if value.contains('.') {
+        value
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        value
+    }
+}
+
+pub fn bytes2human(data: &str, si: bool, precision: u8) -> Result<String, ConversionError> {
+    let bytes = match data.trim().parse::<f64>() {
+        Ok(v) => v,
+        Err(_) => {
+            return Err(ConversionError::ByteSizeConversion(format!(
+                "Cannot convert {} to a number",
+                data
+            )))
+        }
+    };
+
+    let (base, symbols) = if si {
+        (1000.0, SI_UNITS)
+    } else {
+        (1024.0, IEC_UNITS)
+    };
+
+    let mut value = bytes;
+    let mut index = 0;
+    while value.abs() >= base && index < symbols.len() - 1 {
+        value /= base;
+        index += 1;
+    }
+
+    let value = trim_decimals(format!("{:.*}", precision as usize, value));
+    Ok(format!("{} {}", value, symbols[index]))
+}
+
+pub fn human2bytes(data: &str) -> Result<String, ConversionError> {
+    if let Ok(bytes) = data.trim().parse::<f64>() {
+        return Ok(bytes.to_string());
+    }
+
+    let (value, unit) = units::parse_quantity(data, NumberLocale::Plain)?;
+
+    if unit.dimension != Dimension::Data {
+        return Err(ConversionError::ByteSizeConversion(format!(
+            "{} is not a data size unit",
+            unit.symbol
+        )));
+    }
+
+    // Multiplied out directly since the significant digit rounding of unit conversions would
+    // change large byte counts, then rounded to a whole byte
+    let bytes = (value * unit.factor).round();
+    Ok(format!("{:.0}", bytes))
+}
+
+pub fn seconds2human(data: &str) -> Result<String, ConversionError> {
+    let seconds = match data.trim().parse::<i64>() {
+        Ok(v) => v,
+        Err(_) => {
+            return Err(ConversionError::DurationConversion(format!(
+                "Cannot convert {} to a whole number of seconds",
+                data
+            )))
+        }
+    };
+
+    if seconds == 0 {
+        return Ok("0s".to_string());
+    }
+
+    let mut remainder = seconds.unsigned_abs();
+    let mut parts = vec![];
+    for (symbol, size) in DURATION_UNITS {
+        if remainder >= size {
+            parts.push(format!("{}{}", remainder / size, symbol));
+            remainder %= size;
+        }
+    }
+
+    let sign = if seconds < 0 { "-" } else { "" };
+    Ok(format!("{}{}", sign, parts.join(" ")))
+}
+
+pub fn seconds2iso8601(data: &str) -> Result<String, ConversionError> {
+    let seconds = match data.trim().parse::<i64>() {
+        Ok(v) => v,
+        Err(_) => {
+            return Err(ConversionError::DurationConversion(format!(
+                "Cannot convert {} to a whole number of seconds",
+                data
+            )))
+        }
+    };
+
+    Ok(iso8601_duration(seconds))
+}
+
+pub fn iso8601_duration(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let mut remainder = seconds.unsigned_abs();
+    let days = remainder / 86400;
+    remainder %= 86400;
+    let hours = remainder / 3600;
+    remainder %= 3600;
+    let minutes = remainder / 60;
+    let secs = remainder % 60;
+
+    let mut result = format!("{}P", sign);
+    if days > 0 {
+        result.push_str(&format!("{}D", days));
+    }
+    if hours > 0 || minutes > 0 || secs > 0 || days == 0 {
+        result.push('T');
+        if hours > 0 {
+            result.push_str(&format!("{}H", hours));
+        }
+        if minutes > 0 {
+            result.push_str(&format!("{}M", minutes));
+        }
+        if secs > 0 || (hours == 0 && minutes == 0) {
+            result.push_str(&format!("{}S", secs));
+        }
+    }
+    result
+}
+
+pub fn human2seconds(data: &str) -> Result<String, ConversionError> {
+    let data = data.trim();
+
+    if let Ok(seconds) = data.parse::<f64>() {
+        return Ok(seconds.to_string());
+    }
+
+    let seconds = if data.trim_start_matches(['-', '+']).starts_with(['P', 'p']) {
+        parse_iso8601_duration(data)?
+    } else {
+        parse_go_duration(data)?
+    };
+
+    Ok(seconds.to_string())
+}
+
+fn parse_number(value: &str) -> Result<f64, ConversionError> {
+    match value.replace(',', ".").parse::<f64>() {
+        Ok(v) => Ok(v),
+        Err(_) => Err(ConversionError::DurationConversion(format!(
+            "Cannot convert {} to a number",
+            value
+        ))),
+    }
+}
+
+// Years and months have no fixed length without a reference date, so they use the same
+// averages as the `yr` and `mo` units of the unit conversion table.
+pub fn parse_iso8601_duration(data: &str) -> Result<f64, ConversionError> {
+    let pattern = match Regex::new(
+        r"^(?i)(?<sign>[-+]?)P(?:(?<years>[0-9.,]+)Y)?(?:(?<months>[0-9.,]+)M)?(?:(?<weeks>[0-9.,]+)W)?(?:(?<days>[0-9.,]+)D)?(?:T(?:(?<hours>[0-9.,]+)H)?(?:(?<minutes>[0-9.,]+)M)?(?:(?<seconds>[0-9.,]+)S)?)?$",
+    ) {
+        Ok(p) => p,
+        Err(e) => return Err(ConversionError::DurationConversion(e.to_string())),
+    };
+
+    let captures = match pattern.captures(data) {
+        Some(c) if data.len() > 1 && !data.ends_with(['T', 't']) => c,
+        _ => {
+            return Err(ConversionError::DurationConversion(format!(
+                "Invalid ISO 8601 duration {}",
+                data
+            )))
+        }
+    };
+
+    let mut total = 0.0;
+    for (group, unit) in [
+        ("years", "yr"),
+        ("months", "mo"),
+        ("weeks", "wk"),
+        ("days", "d"),
+        ("hours", "h"),
+        ("minutes", "min"),
+        ("seconds", "s"),
+    ] {
+        if let Some(value) = captures.name(group) {
+            total += units::convert_value(parse_number(value.as_str())?, unit, "s")?;
+        }
+    }
+
+    if &captures["sign"] == "-" {
+        total = -total;
+    }
+    Ok(total)
+}
+
+// Go's time.ParseDuration syntax (`1h30m`, `1.5h`, `300ms`), extended with days and weeks and
+// tolerant of whitespace between components so that humanized output can be read back.
+pub fn parse_go_duration(data: &str) -> Result<f64, ConversionError> {
+    let pattern = match Regex::new(
+        r"(?<value>[0-9]+(?:\.[0-9]*)?|\.[0-9]+)\s*(?<unit>ns|us|µs|ms|s|m|h|d|w)",
+    ) {
+        Ok(p) => p,
+        Err(e) => return Err(ConversionError::DurationConversion(e.to_string())),
+    };
+
+    let (sign, body) = match data.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, data.strip_prefix('+').unwrap_or(data)),
+    };
+
+    let mut total = 0.0;
+    let mut position = 0;
+    for captures in pattern.captures_iter(body) {
+        let whole = captures.get(0).unwrap();
+        if !body[position..whole.start()].trim().is_empty() {
+            break;
+        }
+        position = whole.end();
+
+        let unit = match &captures["unit"] {
+            "m" => "min",
+            "w" => "wk",
+            "µs" => "us",
+            other => other,
+        };
+        total += units::convert_value(parse_number(&captures["value"])?, unit, "s")?;
+    }
+
+    if position == 0 || !body[position..].trim().is_empty() {
+        return Err(ConversionError::DurationConversion(format!(
+            "Invalid duration {}",
+            data
+        )));
+    }
+
+    Ok(sign * total)
+}
+
+#[cfg(test)]
+#[path = "./humanize_test.rs"]
+mod humanize_test;
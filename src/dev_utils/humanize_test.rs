@@ -0,0 +1,82 @@
+use crate::dev_utils::humanize::*;
+
+mod tests {
+    use super::*;
+    use crate::dev_utils::convert::ConversionError;
+
+    #[test]
+    fn test_bytes2human() {
+        assert_eq!(bytes2human("1572864", false, 1).unwrap(), "1.5 MiB");
+        assert_eq!(bytes2human("1572864", true, 2).unwrap(), "1.57 MB");
+        assert_eq!(bytes2human("512", false, 1).unwrap(), "512 B");
+        assert_eq!(bytes2human("1024", false, 1).unwrap(), "1 KiB");
+        assert_eq!(bytes2human("1000", true, 0).unwrap(), "1 kB");
+        assert_eq!(bytes2human("1099511627776", false, 3).unwrap(), "1 TiB");
+        assert_eq!(bytes2human("-2048", false, 1).unwrap(), "-2 KiB");
+        match bytes2human("foo", false, 1) {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::ByteSizeConversion(s) => {
+                    assert_eq!(s, "Cannot convert foo to a number");
+                }
+                _ => panic!("{:#?}", e),
+            },
+        }
+    }
+
+    #[test]
+    fn test_human2bytes() {
+        assert_eq!(human2bytes("1.5 MiB").unwrap(), "1572864");
+        assert_eq!(human2bytes("1.5MB").unwrap(), "1500000");
+        assert_eq!(human2bytes("2 kB").unwrap(), "2000");
+        assert_eq!(human2bytes("42").unwrap(), "42");
+        assert_eq!(human2bytes("1.5 TiB").unwrap(), "1649267441664");
+        assert_eq!(human2bytes("1 PiB").unwrap(), "1125899906842624");
+        assert_eq!(human2bytes("3.7 PiB").unwrap(), "4165829655317709");
+        assert_eq!(human2bytes("12 b").unwrap(), "2");
+        match human2bytes("3 km") {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::ByteSizeConversion(s) => {
+                    assert_eq!(s, "km is not a data size unit");
+                }
+                _ => panic!("{:#?}", e),
+            },
+        }
+    }
+
+    #[test]
+    fn test_seconds2human() {
+        assert_eq!(seconds2human("90061").unwrap(), "1d 1h 1m 1s");
+        assert_eq!(seconds2human("3600").unwrap(), "1h");
+        assert_eq!(seconds2human("0").unwrap(), "0s");
+        assert_eq!(seconds2human("-90").unwrap(), "-1m 30s");
+        assert!(seconds2human("1.5").is_err());
+    }
+
+    #[test]
+    fn test_seconds2iso8601() {
+        assert_eq!(seconds2iso8601("93600").unwrap(), "P1DT2H");
+        assert_eq!(seconds2iso8601("90061").unwrap(), "P1DT1H1M1S");
+        assert_eq!(seconds2iso8601("86400").unwrap(), "P1D");
+        assert_eq!(seconds2iso8601("0").unwrap(), "PT0S");
+        assert_eq!(seconds2iso8601("-60").unwrap(), "-PT1M");
+    }
+
+    #[test]
+    fn test_human2seconds() {
+        assert_eq!(human2seconds("1d 1h 1m 1s").unwrap(), "90061");
+        assert_eq!(human2seconds("1h30m").unwrap(), "5400");
+        assert_eq!(human2seconds("1.5h").unwrap(), "5400");
+        assert_eq!(human2seconds("300ms").unwrap(), "0.3");
+        assert_eq!(human2seconds("-2m").unwrap(), "-120");
+        assert_eq!(human2seconds("P1DT2H").unwrap(), "93600");
+        assert_eq!(human2seconds("PT1M30.5S").unwrap(), "90.5");
+        assert_eq!(human2seconds("P2W").unwrap(), "1209600");
+        assert_eq!(human2seconds("120").unwrap(), "120");
+        assert!(human2seconds("P").is_err());
+        assert!(human2seconds("P1DT").is_err());
+        assert!(human2seconds("1h foo").is_err());
+        assert!(human2seconds("foo").is_err());
+    }
+}
@@ -0,0 +1,280 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use strum_macros::{Display, EnumIter, EnumString, EnumVariantNames};
+use ulid::Ulid;
+use uuid::{Uuid, Variant};
+
+use super::datetime::{self, ZoneOptions};
+
+#[derive(Clone, Copy, Debug, PartialEq, Display, EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum IdKind {
+    // Guesses the kind from the length and alphabet of the identifier
+    Auto,
+    Ulid,
+    Uuid,
+    Snowflake,
+    #[strum(to_string = "objectid", serialize = "object-id")]
+    ObjectId,
+    Ksuid,
+}
+
+#[derive(Clone, Copy, EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum SnowflakeEpoch {
+    Twitter,
+    Discord,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct IdError {
+    pub message: String,
+}
+
+impl Display for IdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+// 100ns intervals between the Gregorian calendar reform, where UUID time starts, and 1970
+const UUID_TICKS_TO_UNIX: u64 = 0x01B2_1DD2_1381_4000;
+// KSUID timestamps are seconds since 2014-05-13T16:53:20Z
+const KSUID_EPOCH: i64 = 1_400_000_000;
+const BASE62: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+impl SnowflakeEpoch {
+    fn millis(&self) -> i64 {
+        match self {
+            SnowflakeEpoch::Twitter => 1_288_834_974_657,
+            SnowflakeEpoch::Discord => 1_420_070_400_000,
+        }
+    }
+}
+
+// Reads the Snowflake epoch as a known service name, or any date the `datetime` auto format
+// understands, such as `2015-01-01T00:00:00Z` or a millisecond epoch
+pub fn snowflake_epoch(epoch: &str) -> Result<i64, IdError> {
+    if let Ok(known) = SnowflakeEpoch::from_str(&epoch.to_lowercase()) {
+        return Ok(known.millis());
+    }
+    match datetime::detect(epoch, &ZoneOptions::default()) {
+        Ok((_, from_dt)) => Ok(from_dt.timestamp_millis()),
+        Err(e) => Err(IdError {
+            message: format!("Invalid Snowflake epoch {}: {}", epoch, e),
+        }),
+    }
+}
+
+fn timestamp(format: &str, value: impl ToString) -> Result<String, IdError> {
+    datetime::convert(
+        format,
+        "rfc3339",
+        &value.to_string(),
+        &ZoneOptions::default(),
+    )
+    .map_err(|e| IdError {
+        message: e.to_string(),
+    })
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn report(fields: Vec<(&str, String)>) -> String {
+    fields
+        .iter()
+        .map(|(name, value)| format!("{}: {}", name, value))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn detect(id: &str) -> Result<IdKind, IdError> {
+    let is_hex = id.chars().all(|c| c.is_ascii_hexdigit());
+    let kind = match id.len() {
+        _ if !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()) && id.len() <= 20 => {
+            IdKind::Snowflake
+        }
+        24 if is_hex => IdKind::ObjectId,
+        26 => IdKind::Ulid,
+        27 if id.chars().all(|c| c.is_ascii_alphanumeric()) => IdKind::Ksuid,
+        _ if Uuid::parse_str(id).is_ok() => IdKind::Uuid,
+        _ => {
+            return Err(IdError {
+                message: format!(
+                    "Cannot recognise {} as a ULID, UUID, Snowflake, ObjectId or KSUID",
+                    id
+                ),
+            })
+        }
+    };
+    Ok(kind)
+}
+
+pub fn inspect(content: &str, kind: IdKind, epoch: i64) -> Result<String, IdError> {
+    let id = content.trim();
+    let kind = match kind {
+        IdKind::Auto => detect(id)?,
+        kind => kind,
+    };
+    match kind {
+        IdKind::Ulid => ulid(id),
+        IdKind::Uuid => uuid(id),
+        IdKind::Snowflake => snowflake(id, epoch),
+        IdKind::ObjectId => object_id(id),
+        IdKind::Ksuid => ksuid(id),
+        IdKind::Auto => unreachable!("auto is resolved above"),
+    }
+}
+
+fn ulid(id: &str) -> Result<String, IdError> {
+    let ulid = Ulid::from_string(id).map_err(|e| IdError {
+        message: format!("Invalid ULID {}: {}", id, e),
+    })?;
+    Ok(report(vec![
+        ("type", "ulid".to_string()),
+        ("timestamp", timestamp("epoch-ms", ulid.timestamp_ms())?),
+        ("random", format!("{:020x}", ulid.random())),
+    ]))
+}
+
+fn uuid(id: &str) -> Result<String, IdError> {
+    let uuid = Uuid::parse_str(id).map_err(|e| IdError {
+        message: format!("Invalid UUID {}: {}", id, e),
+    })?;
+    let bytes = uuid.as_bytes();
+    let variant = match uuid.get_variant() {
+        Variant::NCS => "NCS",
+        Variant::RFC4122 => "RFC 4122",
+        Variant::Microsoft => "Microsoft",
+        _ => "reserved",
+    };
+    let version = uuid.get_version_num();
+    let mut fields = vec![
+        ("type", "uuid".to_string()),
+        ("version", version.to_string()),
+        ("variant", variant.to_string()),
+    ];
+    if uuid.is_nil() || uuid.is_max() {
+        fields[1].1 = if uuid.is_nil() { "nil" } else { "max" }.to_string();
+        return Ok(report(fields));
+    }
+
+    let field = |range: std::ops::Range<usize>| {
+        bytes[range]
+            .iter()
+            .fold(0u64, |acc, b| (acc << 8) | *b as u64)
+    };
+    match version {
+        1 | 6 => {
+            let ticks = if version == 1 {
+                ((field(6..8) & 0x0FFF) << 48) | (field(4..6) << 32) | field(0..4)
+            } else {
+                (field(0..4) << 28) | (field(4..6) << 12) | (field(6..8) & 0x0FFF)
+            };
+            let nanos = (ticks as i128 - UUID_TICKS_TO_UNIX as i128) * 100;
+            // A set multicast bit means the node is random rather than a real MAC address
+            let node = bytes[10..16]
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<Vec<String>>()
+                .join(":");
+            let node_kind = if bytes[10] & 1 == 1 { "random" } else { "MAC" };
+            fields.push(("timestamp", timestamp("epoch-ns", nanos)?));
+            fields.push(("clock sequence", (field(8..10) & 0x3FFF).to_string()));
+            fields.push(("node", format!("{} ({})", node, node_kind)));
+        }
+        7 => {
+            fields.push(("timestamp", timestamp("epoch-ms", field(0..6))?));
+            fields.push((
+                "random",
+                format!(
+                    "{:03x}{:016x}",
+                    field(6..8) & 0x0FFF,
+                    field(8..16) & (u64::MAX >> 2)
+                ),
+            ));
+        }
+        3 => fields.push(("hash", "MD5 of a namespace and name".to_string())),
+        5 => fields.push(("hash", "SHA-1 of a namespace and name".to_string())),
+        4 => fields.push(("random", "122 bits".to_string())),
+        _ => {}
+    }
+    Ok(report(fields))
+}
+
+// Twitter's layout: 41 bits of milliseconds since the epoch, 5 bits of datacenter, 5 bits of
+// worker and a 12 bit sequence. Discord uses the same bits but calls them worker and process.
+fn snowflake(id: &str, epoch: i64) -> Result<String, IdError> {
+    let value = id.parse::<u64>().map_err(|_| IdError {
+        message: format!(
+            "Invalid Snowflake {}: expected a 64-bit unsigned integer",
+            id
+        ),
+    })?;
+    let millis = (value >> 22) as i64 + epoch;
+    Ok(report(vec![
+        ("type", "snowflake".to_string()),
+        ("timestamp", timestamp("epoch-ms", millis)?),
+        ("datacenter", ((value >> 17) & 0x1F).to_string()),
+        ("worker", ((value >> 12) & 0x1F).to_string()),
+        ("sequence", (value & 0xFFF).to_string()),
+    ]))
+}
+
+// MongoDB's layout: 4 bytes of seconds since 1970, 5 random bytes fixed per process and a 3
+// byte counter
+fn object_id(id: &str) -> Result<String, IdError> {
+    if id.len() != 24 || !id.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(IdError {
+            message: format!("Invalid ObjectId {}: expected 24 hexadecimal digits", id),
+        });
+    }
+    let field = |range: std::ops::Range<usize>| u64::from_str_radix(&id[range], 16).unwrap_or(0);
+    Ok(report(vec![
+        ("type", "objectid".to_string()),
+        ("timestamp", timestamp("epoch", field(0..8))?),
+        ("random", id[8..18].to_lowercase()),
+        ("counter", field(18..24).to_string()),
+    ]))
+}
+
+// A KSUID is 27 base62 characters encoding 20 bytes: a 4 byte timestamp and 16 random bytes
+fn ksuid(id: &str) -> Result<String, IdError> {
+    let invalid = |reason: &str| IdError {
+        message: format!("Invalid KSUID {}: {}", id, reason),
+    };
+    if id.len() != 27 {
+        return Err(invalid("expected 27 base62 characters"));
+    }
+    let mut bytes = [0u8; 20];
+    for c in id.bytes() {
+        let digit = match BASE62.iter().position(|b| *b == c) {
+            Some(d) => d as u32,
+            None => return Err(invalid("expected 27 base62 characters")),
+        };
+        let mut carry = digit;
+        for byte in bytes.iter_mut().rev() {
+            let value = *byte as u32 * 62 + carry;
+            *byte = value as u8;
+            carry = value >> 8;
+        }
+        if carry != 0 {
+            return Err(invalid("value is larger than 160 bits"));
+        }
+    }
+    let seconds = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as i64;
+    Ok(report(vec![
+        ("type", "ksuid".to_string()),
+        ("timestamp", timestamp("epoch", seconds + KSUID_EPOCH)?),
+        ("payload", hex(&bytes[4..])),
+    ]))
+}
+
+#[cfg(test)]
+#[path = "./id_test.rs"]
+mod id_test;
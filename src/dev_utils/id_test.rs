@@ -0,0 +1,164 @@
+use crate::dev_utils::id::*;
+
+mod tests {
+    use super::*;
+
+    const TWITTER: i64 = 1_288_834_974_657;
+
+    #[test]
+    fn test_ulid() {
+        let result = inspect("01ARZ3NDEKTSV4RRFFQ69G5FAV", IdKind::Auto, TWITTER);
+        match result {
+            Ok(s) => assert_eq!(
+                s,
+                "type: ulid\n\
+                 timestamp: 2016-07-30T23:54:10.259+00:00\n\
+                 random: d6764c61efb99302bd5b"
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_uuid_v1_and_v6() {
+        let expected_fields = "timestamp: 2022-02-22T19:22:22+00:00\n\
+                               clock sequence: 13256\n\
+                               node: 9f:6b:de:ce:d8:46 (random)";
+        let result = inspect(
+            "C232AB00-9414-11EC-B3C8-9F6BDECED846",
+            IdKind::Auto,
+            TWITTER,
+        );
+        match result {
+            Ok(s) => assert_eq!(
+                s,
+                format!(
+                    "type: uuid\nversion: 1\nvariant: RFC 4122\n{}",
+                    expected_fields
+                )
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = inspect(
+            "1EC9414C-232A-6B00-B3C8-9F6BDECED846",
+            IdKind::Uuid,
+            TWITTER,
+        );
+        match result {
+            Ok(s) => assert_eq!(
+                s,
+                format!(
+                    "type: uuid\nversion: 6\nvariant: RFC 4122\n{}",
+                    expected_fields
+                )
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_uuid_v7_and_v4() {
+        let result = inspect(
+            "017F22E2-79B0-7CC3-98C4-DC0C0C07398F",
+            IdKind::Auto,
+            TWITTER,
+        );
+        match result {
+            Ok(s) => assert_eq!(
+                s,
+                "type: uuid\n\
+                 version: 7\n\
+                 variant: RFC 4122\n\
+                 timestamp: 2022-02-22T19:22:22+00:00\n\
+                 random: cc318c4dc0c0c07398f"
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = inspect(
+            "919108f7-52d1-4320-9bac-f847db4148a8",
+            IdKind::Auto,
+            TWITTER,
+        );
+        match result {
+            Ok(s) => assert_eq!(
+                s,
+                "type: uuid\nversion: 4\nvariant: RFC 4122\nrandom: 122 bits"
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = inspect(
+            "00000000-0000-0000-0000-000000000000",
+            IdKind::Auto,
+            TWITTER,
+        );
+        match result {
+            Ok(s) => assert_eq!(s, "type: uuid\nversion: nil\nvariant: NCS"),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_snowflake() {
+        let discord = snowflake_epoch("discord").unwrap();
+        let result = inspect("175928847299117063", IdKind::Auto, discord);
+        match result {
+            Ok(s) => assert_eq!(
+                s,
+                "type: snowflake\n\
+                 timestamp: 2016-04-30T11:18:25.796+00:00\n\
+                 datacenter: 1\n\
+                 worker: 0\n\
+                 sequence: 7"
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        assert_eq!(snowflake_epoch("Twitter"), Ok(TWITTER));
+        assert_eq!(
+            snowflake_epoch("2015-01-01T00:00:00Z"),
+            Ok(1_420_070_400_000)
+        );
+        assert!(snowflake_epoch("someday").is_err());
+        assert!(inspect("-12", IdKind::Snowflake, TWITTER).is_err());
+    }
+
+    #[test]
+    fn test_object_id() {
+        let result = inspect("507f1f77bcf86cd799439011", IdKind::Auto, TWITTER);
+        match result {
+            Ok(s) => assert_eq!(
+                s,
+                "type: objectid\n\
+                 timestamp: 2012-10-17T21:13:27+00:00\n\
+                 random: bcf86cd799\n\
+                 counter: 4427793"
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+        assert!(inspect("507f1f77bcf86cd79943901", IdKind::ObjectId, TWITTER).is_err());
+    }
+
+    #[test]
+    fn test_ksuid() {
+        let result = inspect("0ujtsYcgvSTl8PAuAdqWYSMnLOv", IdKind::Auto, TWITTER);
+        match result {
+            Ok(s) => assert_eq!(
+                s,
+                "type: ksuid\n\
+                 timestamp: 2017-10-10T04:00:47+00:00\n\
+                 payload: b5a1cd34b5f99d1154fb6853345c9735"
+            ),
+            Err(e) => panic!("{:#?}", e),
+        }
+        assert!(inspect("zzzzzzzzzzzzzzzzzzzzzzzzzzz", IdKind::Ksuid, TWITTER).is_err());
+    }
+
+    #[test]
+    fn test_unrecognised() {
+        assert!(inspect("hello", IdKind::Auto, TWITTER).is_err());
+        assert!(inspect("01ARZ3NDEKTSV4RRFFQ69G5FAV", IdKind::Uuid, TWITTER).is_err());
+    }
+}
@@ -1,10 +1,15 @@
 use capitalize;
 use capitalize::Capitalize;
 use rand::seq::SliceRandom;
+use regex::Regex;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use strum_macros::{EnumIter, EnumString, EnumVariantNames};
 // TODO https://github.com/chrislearn/cruet might be more flexible
 use itertools::Itertools;
 
+use super::case::{self, AcronymMode, CaseAction};
+
 #[derive(EnumIter, EnumString, EnumVariantNames)]
 #[strum(serialize_all = "lowercase")]
 pub enum ListAction {
@@ -20,92 +25,580 @@ pub enum ListAction {
     Shuffle,
     Slice,
     Count,
+    Union,
+    Intersect,
+    Difference,
+    #[strum(to_string = "symmetric-difference", serialize = "symmetricdifference")]
+    SymmetricDifference,
+    #[strum(to_string = "is-subset", serialize = "issubset")]
+    IsSubset,
+    Frequency,
+    Histogram,
+    #[strum(to_string = "group-by", serialize = "groupby")]
+    GroupBy,
+}
+
+// How `group_by` finds the group of an item
+pub enum GroupKey {
+    // The first capture group of the match, or the whole match without groups
+    Pattern(Regex),
+    // The first characters of the item
+    Prefix(usize),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SortMode {
+    #[default]
+    Lexical,
+    Numeric,
+    // Runs of digits compare as numbers, so `file2` comes before `file10`
+    Natural,
+    Length,
+    // Dotted versions with an optional `v` prefix and semver pre-release, `1.0.0-rc.1 < 1.0.0`
+    Version,
+}
+
+#[derive(Default)]
+pub struct SortOptions {
+    pub mode: SortMode,
+    pub ignore_case: bool,
+    pub reverse: bool,
+    // Items tied on their key keep their input order instead of falling back to the whole item
+    pub stable: bool,
+    // Sorts by the first capture group of the match, or the whole match without groups
+    pub key: Option<Regex>,
+}
+
+pub enum Split {
+    Literal(String),
+    Pattern(Regex),
+}
+
+// How a list is split into items and joined back together
+pub struct Separator {
+    pub input: Split,
+    pub output: String,
+    // Strips whitespace around each item
+    pub trim: bool,
+    // Leaves out empty items, after trimming
+    pub drop_empty: bool,
+    // Items starting with a single or double quote run to the closing quote, separators
+    // included, as in CSV. A doubled quote inside stands for the quote itself.
+    pub quoted: bool,
+}
+
+// Decodes `\n`, `\t`, `\r`, `\\`, `\0`, `\xNN` and `\u{..}` in a separator typed on the command
+// line. Any other backslash, trailing ones included, is kept so separators like `\|` still work.
+fn unescape_separator(separator: &str) -> Result<String, regex::Error> {
+    let escape = Regex::new(r"\\(?:([ntr0\\])|x([0-9a-fA-F]{2})|u\{([0-9a-fA-F]{1,6})\})")?;
+    Ok(escape
+        .replace_all(separator, |captures: &regex::Captures| {
+            let code = match (captures.get(1), captures.get(2), captures.get(3)) {
+                (Some(c), _, _) => match c.as_str() {
+                    "n" => Some('\n'),
+                    "t" => Some('\t'),
+                    "r" => Some('\r'),
+                    "0" => Some('\0'),
+                    _ => Some('\\'),
+                },
+                (_, Some(hex), _) | (_, _, Some(hex)) => u32::from_str_radix(hex.as_str(), 16)
+                    .ok()
+                    .and_then(char::from_u32),
+                _ => None,
+            };
+            code.map_or(captures[0].to_string(), |c| c.to_string())
+        })
+        .to_string())
+}
+
+impl Separator {
+    // Splits on and joins with `separator` without any other processing
+    pub fn new(separator: &str) -> Separator {
+        Separator {
+            input: Split::Literal(separator.to_string()),
+            output: separator.to_string(),
+            trim: false,
+            drop_empty: false,
+            quoted: false,
+        }
+    }
+
+    // The separator options of the command line: `separator` with its escapes decoded, or
+    // `pattern` as a regex, joining with `output` or else the separator itself, `,` for a regex
+    pub fn parse(
+        separator: &str,
+        pattern: Option<&str>,
+        output: Option<&str>,
+    ) -> Result<Separator, regex::Error> {
+        let input = match pattern {
+            Some(pattern) => Split::Pattern(Regex::new(pattern)?),
+            None => Split::Literal(unescape_separator(separator)?),
+        };
+        let output = match (output, &input) {
+            (Some(output), _) => unescape_separator(output)?,
+            (None, Split::Literal(separator)) => separator.clone(),
+            (None, Split::Pattern(_)) => ",".to_string(),
+        };
+        Ok(Separator {
+            input,
+            output,
+            ..Separator::new("")
+        })
+    }
+
+    pub fn items(&self, content: &str) -> Vec<String> {
+        if content.is_empty() {
+            return vec![];
+        }
+        let items = match (&self.input, self.quoted) {
+            (_, true) => self.split_quoted(content),
+            (Split::Literal(separator), false) => content
+                .split(separator.as_str())
+                .map(str::to_string)
+                .collect(),
+            (Split::Pattern(pattern), false) => {
+                pattern.split(content).map(str::to_string).collect()
+            }
+        };
+        items
+            .into_iter()
+            .map(|item| match self.trim {
+                true => item.trim().to_string(),
+                false => item,
+            })
+            .filter(|item| !(self.drop_empty && item.is_empty()))
+            .collect()
+    }
+
+    // With `quoted`, items holding the output separator or a quote are quoted again so the
+    // output splits back into the same items
+    pub fn join<T: AsRef<str>>(&self, items: impl IntoIterator<Item = T>) -> String {
+        items
+            .into_iter()
+            .map(|item| {
+                let item = item.as_ref();
+                let needs_quotes = (!self.output.is_empty() && item.contains(&self.output))
+                    || item.contains(['"', '\'']);
+                match self.quoted && needs_quotes {
+                    true => format!("\"{}\"", item.replace('"', "\"\"")),
+                    false => item.to_string(),
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(&self.output)
+    }
+
+    // The end of the separator starting at `index`, if there is one. `next` caches the next
+    // regex match so the search does not start over at every character.
+    fn separator_at(
+        &self,
+        content: &str,
+        index: usize,
+        next: &mut Option<(usize, usize)>,
+    ) -> Option<usize> {
+        match &self.input {
+            Split::Literal(separator) => (!separator.is_empty()
+                && content[index..].starts_with(separator.as_str()))
+            .then(|| index + separator.len()),
+            Split::Pattern(pattern) => {
+                if next.is_none_or(|(start, _)| start < index) {
+                    *next = pattern
+                        .find_iter(&content[index..])
+                        .find(|m| !m.is_empty())
+                        .map(|m| (index + m.start(), index + m.end()));
+                }
+                next.filter(|(start, _)| *start == index)
+                    .map(|(_, end)| end)
+            }
+        }
+    }
+
+    fn split_quoted(&self, content: &str) -> Vec<String> {
+        let mut items = vec![];
+        let mut item = String::new();
+        let mut quote = None;
+        let mut next = None;
+        let mut index = 0;
+        while let Some(c) = content[index..].chars().next() {
+            let len = c.len_utf8();
+            match quote {
+                Some(q) if c == q && content[index + len..].starts_with(q) => {
+                    item.push(q);
+                    index += len * 2;
+                }
+                Some(q) if c == q => {
+                    quote = None;
+                    index += len;
+                }
+                Some(_) => {
+                    item.push(c);
+                    index += len;
+                }
+                None => match self.separator_at(content, index, &mut next) {
+                    Some(end) => {
+                        items.push(std::mem::take(&mut item));
+                        index = end;
+                    }
+                    // Quotes only count at the start of an item, so `don't` stays one word
+                    None if (c == '"' || c == '\'') && item.trim().is_empty() => {
+                        item.clear();
+                        quote = Some(c);
+                        index += len;
+                    }
+                    None => {
+                        item.push(c);
+                        index += len;
+                    }
+                },
+            }
+        }
+        items.push(item);
+        items
+    }
+}
+
+fn sort_key<'a>(token: &'a str, key: &Option<Regex>) -> &'a str {
+    let pattern = match key {
+        Some(p) => p,
+        None => return token,
+    };
+    match pattern.captures(token) {
+        Some(captures) => captures
+            .get(1)
+            .or_else(|| captures.get(0))
+            .map_or(token, |m| m.as_str()),
+        None => token,
+    }
+}
+
+// Items that are not numbers sort after all numbers, among themselves lexically
+fn compare_numeric(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.total_cmp(&y),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+fn compare_digits(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+fn compare_natural(a: &str, b: &str) -> Ordering {
+    let chunks = |s: &str| {
+        s.chars()
+            .group_by(|c| c.is_ascii_digit())
+            .into_iter()
+            .map(|(digits, chunk)| (digits, chunk.collect::<String>()))
+            .collect::<Vec<(bool, String)>>()
+    };
+    let (a_chunks, b_chunks) = (chunks(a), chunks(b));
+    for (x, y) in a_chunks.iter().zip(b_chunks.iter()) {
+        let ordering = match (x, y) {
+            ((true, x), (true, y)) => compare_digits(x, y),
+            ((_, x), (_, y)) => x.cmp(y),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    a_chunks.len().cmp(&b_chunks.len())
+}
+
+fn compare_version(a: &str, b: &str) -> Ordering {
+    let split = |s: &'_ str| -> (String, Option<String>) {
+        let s = s.trim();
+        let s = s.strip_prefix(['v', 'V']).unwrap_or(s);
+        let s = s.split('+').next().unwrap_or(s);
+        match s.split_once('-') {
+            Some((release, pre)) => (release.to_string(), Some(pre.to_string())),
+            None => (s.to_string(), None),
+        }
+    };
+    let ((a_release, a_pre), (b_release, b_pre)) = (split(a), split(b));
+    compare_natural(&a_release, &b_release).then_with(|| match (a_pre, b_pre) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(x), Some(y)) => {
+            // Numeric identifiers sort before alphanumeric ones, as in semver
+            let identifiers = |s: &str| s.split('.').map(str::to_string).collect::<Vec<String>>();
+            let (x, y) = (identifiers(&x), identifiers(&y));
+            for (i, j) in x.iter().zip(y.iter()) {
+                let numeric = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+                let ordering = match (numeric(i), numeric(j)) {
+                    (true, true) => compare_digits(i, j),
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) => i.cmp(j),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            x.len().cmp(&y.len())
+        }
+    })
 }
 
-pub fn sort(content: &str, separator: &str) -> String {
-    let mut tokens = content.split(separator).collect::<Vec<&str>>();
-    tokens.sort();
+pub fn sort(content: &str, separator: &Separator, options: &SortOptions) -> String {
+    let mut tokens = separator.items(content);
+    let compare = |a: &String, b: &String| {
+        let (mut x, mut y) = (
+            sort_key(a, &options.key).to_string(),
+            sort_key(b, &options.key).to_string(),
+        );
+        if options.ignore_case {
+            x = x.to_lowercase();
+            y = y.to_lowercase();
+        }
+        let ordering = match options.mode {
+            SortMode::Lexical => x.cmp(&y),
+            SortMode::Numeric => compare_numeric(&x, &y),
+            SortMode::Natural => compare_natural(&x, &y),
+            SortMode::Length => x.chars().count().cmp(&y.chars().count()),
+            SortMode::Version => compare_version(&x, &y),
+        };
+        let ordering = if options.stable {
+            ordering
+        } else {
+            ordering.then_with(|| a.cmp(b))
+        };
+        if options.reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    };
+    tokens.sort_by(compare);
 
-    tokens.join(separator)
+    separator.join(tokens)
 }
 
-pub fn lowercase(content: &str, separator: &str) -> String {
-    let tokens = content
-        .split(separator)
+pub fn lowercase(content: &str, separator: &Separator) -> String {
+    let tokens = separator
+        .items(content)
+        .iter()
         .map(|t| t.to_lowercase())
         .collect::<Vec<String>>();
 
-    tokens.join(separator)
+    separator.join(tokens)
 }
 
-pub fn uppercase(content: &str, separator: &str) -> String {
-    let tokens = content
-        .split(separator)
+pub fn uppercase(content: &str, separator: &Separator) -> String {
+    let tokens = separator
+        .items(content)
+        .iter()
         .map(|t| t.to_uppercase())
         .collect::<Vec<String>>();
 
-    tokens.join(separator)
+    separator.join(tokens)
 }
 
-pub fn capitalise(content: &str, separator: &str) -> String {
-    let tokens = content
-        .split(separator)
+pub fn capitalise(content: &str, separator: &Separator) -> String {
+    let tokens = separator
+        .items(content)
+        .iter()
         .map(|t| t.capitalize())
         .collect::<Vec<String>>();
 
-    tokens.join(separator)
+    separator.join(tokens)
 }
 
-pub fn reverse(content: &str, separator: &str) -> String {
-    let mut tokens = content
-        .split(separator)
-        .map(|s| s.to_string())
+pub fn convert_case(
+    content: &str,
+    separator: &Separator,
+    case: CaseAction,
+    acronyms: AcronymMode,
+) -> String {
+    let tokens = separator
+        .items(content)
+        .iter()
+        .map(|t| case::convert(t, case, acronyms))
         .collect::<Vec<String>>();
 
+    separator.join(tokens)
+}
+
+pub fn reverse(content: &str, separator: &Separator) -> String {
+    let mut tokens = separator.items(content);
+
     tokens.reverse();
 
-    tokens.join(separator)
+    separator.join(tokens)
 }
 
-pub fn deduplicate(content: &str, separator: &str) -> String {
-    let tokens = content
-        .split(separator)
-        .map(|s| s.to_string())
-        .collect::<Vec<String>>();
+pub fn deduplicate(content: &str, separator: &Separator) -> String {
+    let tokens = separator.items(content);
 
-    tokens.iter().unique().join(separator)
+    separator.join(tokens.iter().unique())
 }
 
-pub fn shuffle(content: &str, separator: &str) -> String {
-    let mut tokens = content
-        .split(separator)
-        .map(|s| s.to_string())
-        .collect::<Vec<String>>();
+pub fn shuffle(content: &str, separator: &Separator) -> String {
+    let mut tokens = separator.items(content);
 
     tokens.shuffle(&mut rand::thread_rng());
-    tokens.join(separator)
+    separator.join(tokens)
 }
 
-pub fn slice(content: &str, separator: &str, index: usize, length: usize) -> String {
-    let tokens = content
-        .split(separator)
-        .map(|s| s.to_string())
-        .collect::<Vec<String>>();
+pub fn slice(content: &str, separator: &Separator, index: usize, length: usize) -> String {
+    let tokens = separator.items(content);
 
-    tokens
-        .iter()
-        .skip(index)
-        .take(length)
-        .cloned()
-        .collect::<Vec<String>>()
-        .join(separator)
+    separator.join(tokens.iter().skip(index).take(length))
+}
+
+pub fn count(content: &str, separator: &Separator) -> usize {
+    separator.items(content).len()
+}
+
+// The set operations compare whole items and keep the order in which items first appear, first
+// in `content` and then in `other`
+
+pub fn union(content: &str, other: &str, separator: &Separator) -> String {
+    let (tokens, other) = (separator.items(content), separator.items(other));
+    separator.join(tokens.iter().chain(other.iter()).unique())
+}
+
+pub fn intersect(content: &str, other: &str, separator: &Separator) -> String {
+    let other = separator.items(other);
+    let other = other.iter().collect::<HashSet<&String>>();
+    let tokens = separator.items(content);
+    separator.join(tokens.iter().filter(|t| other.contains(t)).unique())
 }
 
-pub fn count(content: &str, separator: &str) -> usize {
-    if content.is_empty() {
-        return 0;
+pub fn difference(content: &str, other: &str, separator: &Separator) -> String {
+    let other = separator.items(other);
+    let other = other.iter().collect::<HashSet<&String>>();
+    let tokens = separator.items(content);
+    separator.join(tokens.iter().filter(|t| !other.contains(t)).unique())
+}
+
+pub fn symmetric_difference(content: &str, other: &str, separator: &Separator) -> String {
+    let (tokens, other) = (separator.items(content), separator.items(other));
+    let token_set = tokens.iter().collect::<HashSet<&String>>();
+    let other_set = other.iter().collect::<HashSet<&String>>();
+    separator.join(
+        tokens
+            .iter()
+            .filter(|t| !other_set.contains(t))
+            .chain(other.iter().filter(|t| !token_set.contains(t)))
+            .unique(),
+    )
+}
+
+pub fn is_subset(content: &str, other: &str, separator: &Separator) -> bool {
+    let other = separator.items(other);
+    let other = other.iter().collect::<HashSet<&String>>();
+    separator.items(content).iter().all(|t| other.contains(t))
+}
+
+// Longest bar `histogram` draws, for the most frequent item
+const HISTOGRAM_WIDTH: usize = 40;
+const NO_GROUP: &str = "(no match)";
+
+// Items with how often they appear, most frequent first and ties in order of first appearance
+fn tally<'a>(items: impl Iterator<Item = &'a str>) -> Vec<(&'a str, Vec<&'a str>)> {
+    let mut groups: Vec<(&str, Vec<&str>)> = vec![];
+    let mut positions: HashMap<&str, usize> = HashMap::new();
+    for item in items {
+        match positions.get(item) {
+            Some(position) => groups[*position].1.push(item),
+            None => {
+                positions.insert(item, groups.len());
+                groups.push((item, vec![item]));
+            }
+        }
     }
-    content.split(separator).count()
+    groups.sort_by_key(|(_, members)| std::cmp::Reverse(members.len()));
+    groups
+}
+
+pub fn frequency(content: &str, separator: &Separator, top: Option<usize>) -> String {
+    let tokens = separator.items(content);
+    let counts = tally(tokens.iter().map(String::as_str));
+    let total = counts.iter().map(|(_, items)| items.len()).sum::<usize>();
+    let rows = counts.iter().take(top.unwrap_or(usize::MAX));
+    let item_width = rows.clone().map(|(item, _)| item.chars().count()).max();
+    let count_width = total.to_string().len();
+
+    rows.map(|(item, items)| {
+        format!(
+            "{:<item_width$}  {:>count_width$}  {:>5.1}%",
+            item,
+            items.len(),
+            items.len() as f64 * 100.0 / total as f64,
+            item_width = item_width.unwrap_or(0),
+        )
+    })
+    .collect::<Vec<String>>()
+    .join("\n")
+}
+
+pub fn histogram(content: &str, separator: &Separator, top: Option<usize>) -> String {
+    let tokens = separator.items(content);
+    let counts = tally(tokens.iter().map(String::as_str));
+    let rows = counts.iter().take(top.unwrap_or(usize::MAX));
+    let max = counts.first().map_or(0, |(_, items)| items.len());
+    let item_width = rows.clone().map(|(item, _)| item.chars().count()).max();
+    let count_width = max.to_string().len();
+
+    rows.map(|(item, items)| {
+        // Every item gets at least one mark however rare it is
+        let bar = (items.len() * HISTOGRAM_WIDTH / max).max(1);
+        format!(
+            "{:<item_width$}  {:>count_width$}  {}",
+            item,
+            items.len(),
+            "#".repeat(bar),
+            item_width = item_width.unwrap_or(0),
+        )
+    })
+    .collect::<Vec<String>>()
+    .join("\n")
+}
+
+// Lists each group with its size and then its items, largest groups first
+pub fn group_by(
+    content: &str,
+    separator: &Separator,
+    key: &GroupKey,
+    top: Option<usize>,
+) -> String {
+    let group = |item: &'_ str| -> String {
+        match key {
+            GroupKey::Pattern(pattern) => match pattern.captures(item) {
+                Some(captures) => captures
+                    .get(1)
+                    .or_else(|| captures.get(0))
+                    .map_or(NO_GROUP.to_string(), |m| m.as_str().to_string()),
+                None => NO_GROUP.to_string(),
+            },
+            GroupKey::Prefix(length) => item.chars().take(*length).collect(),
+        }
+    };
+    let tokens = separator.items(content);
+    let keys = tokens
+        .iter()
+        .map(|item| (group(item), item.as_str()))
+        .collect::<Vec<(String, &str)>>();
+    let groups = tally(keys.iter().map(|(key, _)| key.as_str()));
+
+    groups
+        .iter()
+        .take(top.unwrap_or(usize::MAX))
+        .map(|(name, members)| {
+            let items = keys
+                .iter()
+                .filter(|(key, _)| key == name)
+                .map(|(_, item)| format!("  {}", item))
+                .collect::<Vec<String>>();
+            format!("{} ({})\n{}", name, members.len(), items.join("\n"))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
 }
 
 #[cfg(test)]
@@ -2,28 +2,117 @@ use crate::dev_utils::list::*;
 
 mod tests {
     use super::*;
+    use crate::dev_utils::case::{AcronymMode, CaseAction};
 
     #[test]
     fn test_sort() {
         assert_eq!(
-            sort("one,two,three,four,five", ","),
+            sort(
+                "one,two,three,four,five",
+                &Separator::new(","),
+                &SortOptions::default()
+            ),
             "five,four,one,three,two"
         );
 
         assert_eq!(
-            sort("one two three four five", " "),
+            sort(
+                "one two three four five",
+                &Separator::new(" "),
+                &SortOptions::default()
+            ),
             "five four one three two"
         );
     }
 
+    #[test]
+    fn test_sort_modes() {
+        let options = |mode: SortMode| SortOptions {
+            mode,
+            ..SortOptions::default()
+        };
+        assert_eq!(
+            sort(
+                "10,9,100,x,-2.5",
+                &Separator::new(","),
+                &options(SortMode::Numeric)
+            ),
+            "-2.5,9,10,100,x"
+        );
+        assert_eq!(
+            sort(
+                "file10,file2,file01",
+                &Separator::new(","),
+                &options(SortMode::Natural)
+            ),
+            "file01,file2,file10"
+        );
+        assert_eq!(
+            sort(
+                "ccc,a,bb,aa",
+                &Separator::new(","),
+                &options(SortMode::Length)
+            ),
+            "a,aa,bb,ccc"
+        );
+        assert_eq!(
+            sort(
+                "v1.10.0,1.2.0,1.0.0,1.0.0-rc.1,1.0.0-alpha.2,1.0.0-alpha,1.0.0-alpha.beta",
+                &Separator::new(","),
+                &options(SortMode::Version)
+            ),
+            "1.0.0-alpha,1.0.0-alpha.2,1.0.0-alpha.beta,1.0.0-rc.1,1.0.0,1.2.0,v1.10.0"
+        );
+    }
+
+    #[test]
+    fn test_sort_options() {
+        let ignore_case = SortOptions {
+            ignore_case: true,
+            ..SortOptions::default()
+        };
+        assert_eq!(
+            sort(
+                "banana,apple,Cherry",
+                &Separator::new(","),
+                &SortOptions::default()
+            ),
+            "Cherry,apple,banana"
+        );
+        assert_eq!(
+            sort("banana,apple,Cherry", &Separator::new(","), &ignore_case),
+            "apple,banana,Cherry"
+        );
+
+        let by_id = |stable: bool| SortOptions {
+            mode: SortMode::Numeric,
+            reverse: true,
+            stable,
+            key: Some(regex::Regex::new("id=(\\d+)").unwrap()),
+            ..SortOptions::default()
+        };
+        assert_eq!(
+            sort(
+                "b id=10,a id=9,c id=10",
+                &Separator::new(","),
+                &by_id(false)
+            ),
+            "c id=10,b id=10,a id=9"
+        );
+        assert_eq!(
+            sort("b id=10,a id=9,c id=10", &Separator::new(","), &by_id(true)),
+            "b id=10,c id=10,a id=9"
+        );
+    }
+
     #[test]
     fn test_lowercase() {
         assert_eq!(
-            lowercase("One Two Three Four FIVE", " "),
+            lowercase("One Two Three Four FIVE", &Separator::new(" ")),
             "one two three four five"
         );
         assert_eq!(
-            lowercase("One,Two,Three,Four,FIVE", ","),
+            lowercase("One,Two,Three,Four,FIVE", &Separator::new(",")),
             "one,two,three,four,five"
         );
     }
@@ -31,11 +120,11 @@ mod tests {
     #[test]
     fn test_uppercase() {
         assert_eq!(
-            uppercase("One Two Three Four FIVE", " "),
+            uppercase("One Two Three Four FIVE", &Separator::new(" ")),
             "ONE TWO THREE FOUR FIVE"
         );
         assert_eq!(
-            uppercase("One,Two,Three,Four,FIVE", ","),
+            uppercase("One,Two,Three,Four,FIVE", &Separator::new(",")),
             "ONE,TWO,THREE,FOUR,FIVE"
         );
     }
@@ -43,19 +132,42 @@ mod tests {
     #[test]
     fn test_capitalise() {
         assert_eq!(
-            capitalise("One Two Three Four FIVE", " "),
+            capitalise("One Two Three Four FIVE", &Separator::new(" ")),
             "One Two Three Four Five"
         );
         assert_eq!(
-            capitalise("One,Two,Three,Four,FIVE", ","),
+            capitalise("One,Two,Three,Four,FIVE", &Separator::new(",")),
             "One,Two,Three,Four,Five"
         );
     }
 
+    #[test]
+    fn test_convert_case() {
+        assert_eq!(
+            convert_case(
+                "userId,HTTPServer,created-at",
+                &Separator::new(","),
+                CaseAction::Snake,
+                AcronymMode::Split
+            ),
+            "user_id,http_server,created_at"
+        );
+        // Separators are read the same way as for the list actions
+        assert_eq!(
+            convert_case(
+                "fooBar\nbazQux",
+                &Separator::parse("\\n", None, None).unwrap(),
+                CaseAction::Snake,
+                AcronymMode::Split
+            ),
+            "foo_bar\nbaz_qux"
+        );
+    }
+
     #[test]
     fn test_reverse() {
         assert_eq!(
-            reverse("One Two Three Four Five", " "),
+            reverse("One Two Three Four Five", &Separator::new(" ")),
             "Five Four Three Two One"
         )
     }
@@ -63,11 +175,11 @@ mod tests {
     #[test]
     fn test_deduplicate() {
         assert_eq!(
-            deduplicate("One Two Three Four Five Five", " "),
+            deduplicate("One Two Three Four Five Five", &Separator::new(" ")),
             "One Two Three Four Five"
         );
         assert_eq!(
-            deduplicate("One Two Three Four Five Five Four", " "),
+            deduplicate("One Two Three Four Five Five Four", &Separator::new(" ")),
             "One Two Three Four Five"
         );
     }
@@ -75,20 +187,216 @@ mod tests {
     #[test]
     fn test_slice() {
         assert_eq!(
-            slice("One Two Three Four Five Five Four", " ", 0, 3),
+            slice(
+                "One Two Three Four Five Five Four",
+                &Separator::new(" "),
+                0,
+                3
+            ),
             "One Two Three"
         );
         assert_eq!(
-            slice("One Two Three Four Five Five Four", " ", 1, 3),
+            slice(
+                "One Two Three Four Five Five Four",
+                &Separator::new(" "),
+                1,
+                3
+            ),
             "Two Three Four"
         );
-        assert_eq!(slice("One Two Three Four Five Five Four", " ", 1, 1), "Two");
-        assert_eq!(slice("One Two Three Four Five Five Four", " ", 8, 1), "");
+        assert_eq!(
+            slice(
+                "One Two Three Four Five Five Four",
+                &Separator::new(" "),
+                1,
+                1
+            ),
+            "Two"
+        );
+        assert_eq!(
+            slice(
+                "One Two Three Four Five Five Four",
+                &Separator::new(" "),
+                8,
+                1
+            ),
+            ""
+        );
     }
 
     #[test]
     fn test_count() {
-        assert_eq!(count("one,two,three,four,five", ","), 5);
-        assert_eq!(count("", " "), 0);
+        assert_eq!(count("one,two,three,four,five", &Separator::new(",")), 5);
+        assert_eq!(count("", &Separator::new(" ")), 0);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let deployed = "api,web,worker,api,cron";
+        let expected = "web,api,db";
+        assert_eq!(
+            union(deployed, expected, &Separator::new(",")),
+            "api,web,worker,cron,db"
+        );
+        assert_eq!(
+            intersect(deployed, expected, &Separator::new(",")),
+            "api,web"
+        );
+        assert_eq!(
+            difference(deployed, expected, &Separator::new(",")),
+            "worker,cron"
+        );
+        assert_eq!(difference(expected, deployed, &Separator::new(",")), "db");
+        assert_eq!(
+            symmetric_difference(deployed, expected, &Separator::new(",")),
+            "worker,cron,db"
+        );
+        assert_eq!(intersect("a b", "c d", &Separator::new(" ")), "");
+    }
+
+    #[test]
+    fn test_is_subset() {
+        assert!(is_subset("api,web", "web,api,db", &Separator::new(",")));
+        assert!(is_subset("api,api", "api", &Separator::new(",")));
+        assert!(!is_subset("api,worker", "web,api,db", &Separator::new(",")));
+    }
+
+    #[test]
+    fn test_frequency() {
+        assert_eq!(
+            frequency("b,a,b,c,a,b,dd", &Separator::new(","), None),
+            "b   3   42.9%\n\
+             a   2   28.6%\n\
+             c   1   14.3%\n\
+             dd  1   14.3%"
+        );
+        assert_eq!(
+            frequency("b,a,b,c,a,b,dd", &Separator::new(","), Some(2)),
+            "b  3   42.9%\na  2   28.6%"
+        );
+        assert_eq!(frequency("", &Separator::new(","), None), "");
+    }
+
+    #[test]
+    fn test_histogram() {
+        let content = format!("x y z {}", "x ".repeat(39).trim_end());
+        assert_eq!(
+            histogram(&content, &Separator::new(" "), Some(2)),
+            format!("x  40  {}\ny   1  #", "#".repeat(40))
+        );
+        assert_eq!(
+            histogram("ok,ok,ok,ok,fail", &Separator::new(","), None),
+            format!("ok    4  {}\nfail  1  {}", "#".repeat(40), "#".repeat(10))
+        );
+    }
+
+    #[test]
+    fn test_group_by() {
+        let lines = "ERROR db timeout\nINFO started\nERROR disk full\nplain";
+        let by_level = GroupKey::Pattern(regex::Regex::new("^([A-Z]+) ").unwrap());
+        assert_eq!(
+            group_by(lines, &Separator::new("\n"), &by_level, None),
+            "ERROR (2)\n  ERROR db timeout\n  ERROR disk full\n\
+             INFO (1)\n  INFO started\n\
+             (no match) (1)\n  plain"
+        );
+        assert_eq!(
+            group_by(
+                "us-east-1,eu-west-1,us-west-2",
+                &Separator::new(","),
+                &GroupKey::Prefix(2),
+                Some(1)
+            ),
+            "us (2)\n  us-east-1\n  us-west-2"
+        );
+    }
+
+    #[test]
+    fn test_separator_regex() {
+        let separator = Separator {
+            input: Split::Pattern(regex::Regex::new("[,;\\s]+").unwrap()),
+            output: "\n".to_string(),
+            ..Separator::new(",")
+        };
+        assert_eq!(separator.items("a, b;c  d"), vec!["a", "b", "c", "d"]);
+        assert_eq!(
+            sort("d, b;c  a", &separator, &SortOptions::default()),
+            "a\nb\nc\nd"
+        );
+        assert_eq!(count("", &separator), 0);
+    }
+
+    #[test]
+    fn test_separator_parse() {
+        let parse = |separator: &str| Separator::parse(separator, None, None).unwrap();
+        let sort_options = SortOptions::default();
+        assert_eq!(sort("b\na", &parse("\\n"), &sort_options), "a\nb");
+        assert_eq!(sort("b\ta", &parse("\\x09"), &sort_options), "a\tb");
+        assert_eq!(
+            sort("b\u{2028}a", &parse("\\u{2028}"), &sort_options),
+            "a\u{2028}b"
+        );
+        assert_eq!(sort("b\\a", &parse("\\\\"), &sort_options), "a\\b");
+        // Other escapes and a trailing backslash are taken literally
+        assert_eq!(sort("a|b", &parse("\\|"), &sort_options), "a|b");
+        assert_eq!(sort("b\\|a", &parse("\\|"), &sort_options), "a\\|b");
+        assert_eq!(sort("b\\a", &parse("\\"), &sort_options), "a\\b");
+        assert_eq!(sort("b\\qa", &parse("\\q"), &sort_options), "a\\qb");
+
+        let separator = Separator::parse(",", Some(";\\s*"), None).unwrap();
+        assert_eq!(separator.join(separator.items("a; b")), "a,b");
+        let separator = Separator::parse("\\t", None, Some(" | ")).unwrap();
+        assert_eq!(separator.join(separator.items("a\tb")), "a | b");
+        assert!(Separator::parse(",", Some("("), None).is_err());
+    }
+
+    #[test]
+    fn test_separator_trim_and_drop_empty() {
+        let separator = Separator {
+            trim: true,
+            drop_empty: true,
+            ..Separator::new(",")
+        };
+        assert_eq!(separator.items(" a , b,, ,c "), vec!["a", "b", "c"]);
+        assert_eq!(deduplicate(" a , b,,a ,", &separator), "a,b");
+        assert_eq!(Separator::new(",").items("a,,b"), vec!["a", "", "b"]);
+    }
+
+    #[test]
+    fn test_separator_quoted() {
+        let separator = Separator {
+            quoted: true,
+            ..Separator::new(",")
+        };
+        assert_eq!(separator.items("\"a,b\",c"), vec!["a,b", "c"]);
+        assert_eq!(
+            separator.items("'x,y',\"say \"\"hi\"\"\",don't,"),
+            vec!["x,y", "say \"hi\"", "don't", ""]
+        );
+        assert_eq!(count("\"a,b\",c", &Separator::new(",")), 3);
+        // Items are quoted again on the way out, so the output reads back the same
+        assert_eq!(
+            sort(
+                "c,\"a,b\",'say \"hi\"'",
+                &separator,
+                &SortOptions::default()
+            ),
+            "\"a,b\",c,\"say \"\"hi\"\"\""
+        );
+        let sorted = sort(
+            "\"b,1\",don't,\"a\"\"\"",
+            &separator,
+            &SortOptions::default(),
+        );
+        assert_eq!(sorted, "\"a\"\"\",\"b,1\",\"don't\"");
+        assert_eq!(separator.items(&sorted), vec!["a\"", "b,1", "don't"]);
+
+        let separator = Separator {
+            input: Split::Pattern(regex::Regex::new(";\\s*").unwrap()),
+            quoted: true,
+            trim: true,
+            ..Separator::new(";")
+        };
+        assert_eq!(separator.items(" \"a; b\";  c;d"), vec!["a; b", "c", "d"]);
     }
 }
@@ -0,0 +1,271 @@
+use std::fmt::{Display, Formatter};
+
+use strum_macros::{EnumIter, EnumString, EnumVariantNames};
+
+#[derive(Clone, Copy, PartialEq, EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum NumberLocale {
+    // No grouping and a `.` decimal point, as Rust prints numbers
+    #[strum(serialize = "plain", serialize = "none")]
+    Plain,
+    En,
+    De,
+    Fr,
+    Es,
+    Pt,
+    #[strum(serialize = "de-ch", serialize = "dech")]
+    DeCh,
+    // Indian numbering groups the thousands and then every two digits: 12,34,567
+    #[strum(serialize = "en-in", serialize = "enin")]
+    EnIn,
+}
+
+#[derive(Clone, Copy, PartialEq, EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum Notation {
+    Standard,
+    Scientific,
+    // Scientific notation with the exponent restricted to multiples of three
+    Engineering,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct NumberError {
+    pub message: String,
+}
+
+impl Display for NumberError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+// Characters that only ever group digits, whatever the locale
+const GROUPING_MARKS: [char; 6] = [' ', '\u{A0}', '\u{202F}', '\'', '’', '_'];
+
+impl NumberLocale {
+    fn separators(&self) -> (&'static str, char) {
+        match self {
+            NumberLocale::Plain => ("", '.'),
+            NumberLocale::En | NumberLocale::EnIn => (",", '.'),
+            NumberLocale::De | NumberLocale::Es | NumberLocale::Pt => (".", ','),
+            NumberLocale::Fr => ("\u{202F}", ','),
+            NumberLocale::DeCh => ("'", '.'),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct NumberFormat {
+    pub locale: NumberLocale,
+    pub notation: Notation,
+    // Fixed number of decimal places, takes precedence over `significant`
+    pub decimals: Option<u8>,
+    pub significant: Option<u8>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat {
+            locale: NumberLocale::Plain,
+            notation: Notation::Standard,
+            decimals: None,
+            significant: None,
+        }
+    }
+}
+
+// Rounds to the given number of significant digits, returning the value and the number of
+// decimal places needed to show all of them
+fn round_significant(value: f64, digits: u8) -> (f64, usize) {
+    if value == 0.0 {
+        return (0.0, digits.saturating_sub(1) as usize);
+    }
+    let magnitude = value.abs().log10().floor() as i32;
+    let decimals = digits.max(1) as i32 - 1 - magnitude;
+    let scale = 10f64.powi(decimals);
+    let rounded = (value * scale).round() / scale;
+    // Rounding can carry into the next power of ten, 9.99 -> 10.0
+    let carried = rounded.abs().log10().floor() as i32;
+    (rounded, (decimals - (carried - magnitude)).max(0) as usize)
+}
+
+fn group_digits(digits: &str, separator: &str, indian: bool) -> String {
+    if separator.is_empty() || digits.len() <= 3 {
+        return digits.to_string();
+    }
+    let (head, last) = digits.split_at(digits.len() - 3);
+    let size = if indian { 2 } else { 3 };
+    let mut groups = vec![last.to_string()];
+    let mut rest = head;
+    while !rest.is_empty() {
+        let split = rest.len().saturating_sub(size);
+        groups.push(rest[split..].to_string());
+        rest = &rest[..split];
+    }
+    groups.reverse();
+    groups.join(separator)
+}
+
+impl NumberFormat {
+    pub fn fixed(decimals: u8) -> Self {
+        NumberFormat {
+            decimals: Some(decimals),
+            ..NumberFormat::default()
+        }
+    }
+
+    // Writes the value with the requested precision and a `.` decimal point, leaving the
+    // locale to `localise`
+    fn plain(&self, value: f64) -> String {
+        match (self.decimals, self.significant) {
+            (Some(decimals), _) => format!("{:.*}", decimals as usize, value),
+            (None, Some(digits)) => {
+                let (rounded, decimals) = round_significant(value, digits);
+                format!("{:.*}", decimals, rounded)
+            }
+            // Large whole numbers print every digit, 1152921504606846976 rather than
+            // 1152921504606847000
+            (None, None) if value.fract() == 0.0 => format!("{:.0}", value),
+            (None, None) => value.to_string(),
+        }
+    }
+
+    fn localise(&self, number: &str) -> String {
+        let (grouping, decimal) = self.locale.separators();
+        let (sign, unsigned) = match number.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", number),
+        };
+        let (whole, fraction) = match unsigned.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let mut result = format!(
+            "{}{}",
+            sign,
+            group_digits(whole, grouping, self.locale == NumberLocale::EnIn)
+        );
+        if let Some(fraction) = fraction {
+            result.push(decimal);
+            result.push_str(fraction);
+        }
+        result
+    }
+
+    fn exponential(&self, value: f64, engineering: bool) -> String {
+        let (value, significant_decimals) = match (self.decimals, self.significant) {
+            (None, Some(digits)) => {
+                let (rounded, _) = round_significant(value, digits);
+                (rounded, Some(digits.max(1) as i32 - 1))
+            }
+            _ => (value, None),
+        };
+
+        let mut exponent = if value == 0.0 {
+            0
+        } else {
+            value.abs().log10().floor() as i32
+        };
+        if engineering {
+            exponent -= exponent.rem_euclid(3);
+        }
+        let mantissa = value / 10f64.powi(exponent);
+
+        let mantissa = match (self.decimals, significant_decimals) {
+            (Some(decimals), _) => format!("{:.*}", decimals as usize, mantissa),
+            (None, Some(decimals)) => {
+                // Digits left of the point in an engineering mantissa count as significant too
+                let whole_digits = mantissa.abs().log10().floor().max(0.0) as i32;
+                format!("{:.*}", (decimals - whole_digits).max(0) as usize, mantissa)
+            }
+            // Dividing by a power of ten can leave noise in the last digits
+            (None, None) => round_significant(mantissa, 15).0.to_string(),
+        };
+        format!("{}e{}", self.localise(&mantissa), exponent)
+    }
+
+    pub fn format(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+        match self.notation {
+            Notation::Standard => self.localise(&self.plain(value)),
+            Notation::Scientific => self.exponential(value, false),
+            Notation::Engineering => self.exponential(value, true),
+        }
+    }
+
+    pub fn parse(&self, data: &str) -> Result<f64, NumberError> {
+        parse(data, self.locale)
+    }
+}
+
+// Works out the decimal separator of a number written without a known locale: when both `,`
+// and `.` appear the last one is the decimal separator, a repeated one is always grouping, and
+// a lone comma is grouping only when exactly three digits follow it (`1,234` but `1,5`)
+fn guess_decimal_separator(number: &str) -> Option<char> {
+    let last_comma = number.rfind(',');
+    let last_dot = number.rfind('.');
+    match (last_comma, last_dot) {
+        (Some(comma), Some(dot)) => Some(if comma > dot { ',' } else { '.' }),
+        (Some(comma), None) => {
+            let digits_after = number[comma + 1..]
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+            if number.matches(',').count() > 1 || digits_after == 3 {
+                None
+            } else {
+                Some(',')
+            }
+        }
+        (None, Some(_)) if number.matches('.').count() > 1 => None,
+        (None, Some(_)) => Some('.'),
+        (None, None) => None,
+    }
+}
+
+pub fn parse(data: &str, locale: NumberLocale) -> Result<f64, NumberError> {
+    let invalid = || NumberError {
+        message: format!("Cannot convert {} to a number", data),
+    };
+
+    let trimmed = data.trim().replace('−', "-");
+    let (decimal, grouping) = match locale {
+        NumberLocale::Plain => match guess_decimal_separator(&trimmed) {
+            Some(',') => (Some(','), vec!['.']),
+            Some(_) => (Some('.'), vec![',']),
+            None => (None, vec![',', '.']),
+        },
+        _ => {
+            let (grouping, decimal) = locale.separators();
+            (Some(decimal), grouping.chars().collect())
+        }
+    };
+
+    let (whole, fraction) = match decimal.and_then(|d| trimmed.split_once(d)) {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (trimmed.as_str(), None),
+    };
+    let is_grouping = |c: char| grouping.contains(&c) || GROUPING_MARKS.contains(&c);
+    if fraction.is_some_and(|f| f.contains(is_grouping) || decimal.is_some_and(|d| f.contains(d))) {
+        return Err(invalid());
+    }
+
+    let mut normalised = whole.replace(is_grouping, "");
+    if let Some(fraction) = fraction {
+        normalised.push('.');
+        normalised.push_str(fraction);
+    }
+
+    match normalised.parse::<f64>() {
+        Ok(v) if !normalised.is_empty() => Ok(v),
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(test)]
+#[path = "./number_test.rs"]
+mod number_test;
@@ -0,0 +1,118 @@
+use crate::dev_utils::number::*;
+
+mod tests {
+    use super::*;
+
+    fn locale(locale: NumberLocale) -> NumberFormat {
+        NumberFormat {
+            locale,
+            ..NumberFormat::default()
+        }
+    }
+
+    #[test]
+    fn test_format_locales() {
+        let value = 1234567.891;
+        assert_eq!(NumberFormat::default().format(value), "1234567.891");
+        assert_eq!(locale(NumberLocale::En).format(value), "1,234,567.891");
+        assert_eq!(locale(NumberLocale::De).format(value), "1.234.567,891");
+        assert_eq!(
+            locale(NumberLocale::Fr).format(value),
+            "1\u{202F}234\u{202F}567,891"
+        );
+        assert_eq!(locale(NumberLocale::DeCh).format(value), "1'234'567.891");
+        assert_eq!(locale(NumberLocale::EnIn).format(value), "12,34,567.891");
+        assert_eq!(locale(NumberLocale::En).format(-999.5), "-999.5");
+        assert_eq!(locale(NumberLocale::En).format(-1000.0), "-1,000");
+    }
+
+    #[test]
+    fn test_format_precision() {
+        let format = NumberFormat {
+            locale: NumberLocale::En,
+            decimals: Some(2),
+            ..NumberFormat::default()
+        };
+        assert_eq!(format.format(1234.5), "1,234.50");
+        assert_eq!(NumberFormat::fixed(0).format(2.5), "2");
+
+        let significant = |digits| NumberFormat {
+            significant: Some(digits),
+            ..NumberFormat::default()
+        };
+        assert_eq!(significant(3).format(0.621371192237), "0.621");
+        assert_eq!(significant(3).format(1.5), "1.50");
+        assert_eq!(significant(2).format(123456.0), "120000");
+        assert_eq!(significant(2).format(9.99), "10");
+        assert_eq!(significant(3).format(0.0), "0.00");
+    }
+
+    #[test]
+    fn test_format_notation() {
+        let notation = |notation, significant| NumberFormat {
+            notation,
+            significant,
+            ..NumberFormat::default()
+        };
+        assert_eq!(
+            notation(Notation::Scientific, None).format(12345.0),
+            "1.2345e4"
+        );
+        assert_eq!(
+            notation(Notation::Scientific, Some(3)).format(12345.0),
+            "1.23e4"
+        );
+        assert_eq!(
+            notation(Notation::Scientific, Some(2)).format(-0.00042),
+            "-4.2e-4"
+        );
+        assert_eq!(
+            notation(Notation::Engineering, None).format(12345.0),
+            "12.345e3"
+        );
+        assert_eq!(
+            notation(Notation::Engineering, Some(3)).format(0.00042),
+            "420e-6"
+        );
+        assert_eq!(notation(Notation::Engineering, None).format(0.0), "0e0");
+
+        let german = NumberFormat {
+            locale: NumberLocale::De,
+            notation: Notation::Scientific,
+            decimals: Some(1),
+            significant: None,
+        };
+        assert_eq!(german.format(1500.0), "1,5e3");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("1,234,567.891", NumberLocale::En), Ok(1234567.891));
+        assert_eq!(parse("1.234.567,891", NumberLocale::De), Ok(1234567.891));
+        assert_eq!(parse("1 234 567,891", NumberLocale::Fr), Ok(1234567.891));
+        assert_eq!(parse("1'234.5", NumberLocale::DeCh), Ok(1234.5));
+        assert_eq!(parse("12,34,567", NumberLocale::EnIn), Ok(1234567.0));
+        assert_eq!(parse("−2,5", NumberLocale::Es), Ok(-2.5));
+        assert_eq!(parse("1,5e3", NumberLocale::Pt), Ok(1500.0));
+        assert!(parse("1.234,5", NumberLocale::En).is_err());
+        assert!(parse("", NumberLocale::En).is_err());
+        assert_eq!(
+            parse("abc", NumberLocale::En),
+            Err(NumberError {
+                message: "Cannot convert abc to a number".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_plain_guesses_separators() {
+        assert_eq!(parse("1234.5", NumberLocale::Plain), Ok(1234.5));
+        assert_eq!(parse("1,234.5", NumberLocale::Plain), Ok(1234.5));
+        assert_eq!(parse("1.234,5", NumberLocale::Plain), Ok(1234.5));
+        assert_eq!(parse("1,234", NumberLocale::Plain), Ok(1234.0));
+        assert_eq!(parse("1,5", NumberLocale::Plain), Ok(1.5));
+        assert_eq!(parse("1.234.567", NumberLocale::Plain), Ok(1234567.0));
+        assert_eq!(parse("1_000_000", NumberLocale::Plain), Ok(1000000.0));
+        assert_eq!(parse("-3.5e-2", NumberLocale::Plain), Ok(-0.035));
+    }
+}
@@ -1,5 +1,7 @@
 use strum_macros::{EnumIter, EnumString, EnumVariantNames};
 
+use super::number::NumberFormat;
+
 #[derive(EnumIter, EnumString, EnumVariantNames)]
 #[strum(serialize_all = "lowercase")]
 pub enum PercentageAction {
@@ -8,27 +10,21 @@ pub enum PercentageAction {
     Change,
 }
 
-pub fn to(from_number: f64, to_number: f64, precision: u8) -> Result<String, String> {
+pub fn to(from_number: f64, to_number: f64, format: &NumberFormat) -> Result<String, String> {
     Ok(format!(
-        "{value:.precision$}%",
-        value = (to_number / from_number) * 100.0,
-        precision = precision as usize,
+        "{}%",
+        format.format((to_number / from_number) * 100.0)
     ))
 }
 
-pub fn of(percentage: f32, of_number: f64, precision: u8) -> Result<String, String> {
-    Ok(format!(
-        "{value:.precision$}",
-        value = (percentage as f64 / 100.0) * of_number,
-        precision = precision as usize
-    ))
+pub fn of(percentage: f32, of_number: f64, format: &NumberFormat) -> Result<String, String> {
+    Ok(format.format((percentage as f64 / 100.0) * of_number))
 }
 
-pub fn change(from_number: f64, to_number: f64, precision: u8) -> Result<String, String> {
+pub fn change(from_number: f64, to_number: f64, format: &NumberFormat) -> Result<String, String> {
     Ok(format!(
-        "{value:.precision$}%",
-        value = ((to_number - from_number) / from_number.abs()) * 100.0,
-        precision = precision as usize,
+        "{}%",
+        format.format(((to_number - from_number) / from_number.abs()) * 100.0)
     ))
 }
 
@@ -2,23 +2,41 @@ use crate::dev_utils::percentage::*;
 
 mod tests {
     use super::*;
+    use crate::dev_utils::number::{NumberFormat, NumberLocale};
 
     #[test]
     fn test_to() {
-        assert_eq!(to(100.0, 50.0, 0).unwrap(), "50%");
-        assert_eq!(to(150.0, 50.0, 2).unwrap(), "33.33%");
+        assert_eq!(to(100.0, 50.0, &NumberFormat::fixed(0)).unwrap(), "50%");
+        assert_eq!(to(150.0, 50.0, &NumberFormat::fixed(2)).unwrap(), "33.33%");
     }
 
     #[test]
     fn test_of() {
-        assert_eq!(of(50.0, 100.0, 0).unwrap(), "50");
-        assert_eq!(of(33.33, 100.0, 2).unwrap(), "33.33");
-        assert_eq!(of(25.0, 200.0, 3).unwrap(), "50.000");
+        assert_eq!(of(50.0, 100.0, &NumberFormat::fixed(0)).unwrap(), "50");
+        assert_eq!(of(33.33, 100.0, &NumberFormat::fixed(2)).unwrap(), "33.33");
+        assert_eq!(of(25.0, 200.0, &NumberFormat::fixed(3)).unwrap(), "50.000");
+    }
+
+    #[test]
+    fn test_localized() {
+        let format = NumberFormat {
+            locale: NumberLocale::De,
+            decimals: Some(2),
+            ..NumberFormat::default()
+        };
+        assert_eq!(to(150.0, 50.0, &format).unwrap(), "33,33%");
+        assert_eq!(of(10.0, 123456.0, &format).unwrap(), "12.345,60");
     }
 
     #[test]
     fn test_change() {
-        assert_eq!(change(100.0, 50.0, 0).unwrap(), "-50%");
-        assert_eq!(change(50.0, 100.0, 0).unwrap(), "100%");
+        assert_eq!(
+            change(100.0, 50.0, &NumberFormat::fixed(0)).unwrap(),
+            "-50%"
+        );
+        assert_eq!(
+            change(50.0, 100.0, &NumberFormat::fixed(0)).unwrap(),
+            "100%"
+        );
     }
 }
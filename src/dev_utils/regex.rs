@@ -25,7 +25,11 @@ pub enum RegexError {
 
 impl Display for RegexError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            RegexError::InvalidDateFormat(message) | RegexError::InvalidTimeFormat(message) => {
+                write!(f, "{}", message)
+            }
+        }
     }
 }
 
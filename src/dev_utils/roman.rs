@@ -0,0 +1,190 @@
+use std::fmt::{Display, Formatter};
+
+// Largest value written with plain letters
+pub const MAX_STANDARD: u32 = 3999;
+// Largest value written with a vinculum, where an overlined letter is worth a thousand times more
+pub const MAX_VINCULUM: u32 = 3_999_999;
+
+const COMBINING_OVERLINE: char = '\u{305}';
+const COMBINING_MACRON: char = '\u{304}';
+
+const NUMERALS: [(&str, u32); 13] = [
+    ("M", 1000),
+    ("CM", 900),
+    ("D", 500),
+    ("CD", 400),
+    ("C", 100),
+    ("XC", 90),
+    ("L", 50),
+    ("XL", 40),
+    ("X", 10),
+    ("IX", 9),
+    ("V", 5),
+    ("IV", 4),
+    ("I", 1),
+];
+
+#[derive(Debug, PartialEq)]
+pub enum RomanError {
+    Empty,
+    OutOfRange { value: i64, max: u32 },
+    InvalidCharacter(char),
+    NonCanonical { numeral: String, canonical: String },
+}
+
+impl Display for RomanError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RomanError::Empty => write!(f, "Empty Roman numeral"),
+            RomanError::OutOfRange { value, max } => {
+                write!(f, "{} is out of range, expected 1 to {}", value, max)
+            }
+            RomanError::InvalidCharacter(c) => {
+                write!(f, "{} is not a Roman numeral character", c)
+            }
+            RomanError::NonCanonical { numeral, canonical } => write!(
+                f,
+                "{} is not a canonical Roman numeral, did you mean {}?",
+                numeral, canonical
+            ),
+        }
+    }
+}
+
+fn standard(mut value: u32) -> String {
+    let mut result = String::new();
+    for (letters, size) in NUMERALS {
+        while value >= size {
+            value -= size;
+            result.push_str(letters);
+        }
+    }
+    result
+}
+
+pub fn to_roman(value: i64, vinculum: bool) -> Result<String, RomanError> {
+    let max = if vinculum { MAX_VINCULUM } else { MAX_STANDARD };
+    if value < 1 || value > max as i64 {
+        return Err(RomanError::OutOfRange { value, max });
+    }
+
+    let value = value as u32;
+    if value <= MAX_STANDARD {
+        return Ok(standard(value));
+    }
+
+    let mut result = String::new();
+    for c in standard(value / 1000).chars() {
+        result.push(c);
+        result.push(COMBINING_OVERLINE);
+    }
+    result.push_str(&standard(value % 1000));
+    Ok(result)
+}
+
+// Rewrites the Unicode Number Forms block (Ⅻ, ⅻ, ↁ...) into plain letters
+fn expand_number_form(c: char) -> Option<&'static str> {
+    let expanded = match c {
+        'Ⅰ' | 'ⅰ' => "I",
+        'Ⅱ' | 'ⅱ' => "II",
+        'Ⅲ' | 'ⅲ' => "III",
+        'Ⅳ' | 'ⅳ' => "IV",
+        'Ⅴ' | 'ⅴ' => "V",
+        'Ⅵ' | 'ⅵ' | 'ↅ' => "VI",
+        'Ⅶ' | 'ⅶ' => "VII",
+        'Ⅷ' | 'ⅷ' => "VIII",
+        'Ⅸ' | 'ⅸ' => "IX",
+        'Ⅹ' | 'ⅹ' => "X",
+        'Ⅺ' | 'ⅺ' => "XI",
+        'Ⅻ' | 'ⅻ' => "XII",
+        'Ⅼ' | 'ⅼ' | 'ↆ' => "L",
+        'Ⅽ' | 'ⅽ' => "C",
+        'Ⅾ' | 'ⅾ' => "D",
+        'Ⅿ' | 'ⅿ' | 'ↀ' => "M",
+        'ↁ' => "V\u{305}",
+        'ↂ' => "X\u{305}",
+        'ↇ' => "L\u{305}",
+        'ↈ' => "C\u{305}",
+        _ => return None,
+    };
+    Some(expanded)
+}
+
+fn letter_value(c: char) -> Option<u32> {
+    match c {
+        'I' => Some(1),
+        'V' => Some(5),
+        'X' => Some(10),
+        'L' => Some(50),
+        'C' => Some(100),
+        'D' => Some(500),
+        'M' => Some(1000),
+        _ => None,
+    }
+}
+
+// Uppercases the numeral and expands Unicode numerals, writing every vinculum as a combining
+// overline so that it can be compared with the output of `to_roman`
+pub fn normalise(numeral: &str) -> String {
+    let mut result = String::new();
+    for c in numeral.trim().chars() {
+        match expand_number_form(c) {
+            Some(expanded) => result.push_str(expanded),
+            None if c == COMBINING_MACRON => result.push(COMBINING_OVERLINE),
+            None => result.extend(c.to_uppercase()),
+        }
+    }
+    result
+}
+
+pub fn from_roman(numeral: &str) -> Result<u32, RomanError> {
+    let normalised = normalise(numeral);
+    if normalised.is_empty() {
+        return Err(RomanError::Empty);
+    }
+
+    let mut values: Vec<u64> = vec![];
+    let mut chars = normalised.chars().peekable();
+    while let Some(c) = chars.next() {
+        let value = match letter_value(c) {
+            Some(v) => v as u64,
+            None => return Err(RomanError::InvalidCharacter(c)),
+        };
+        if chars.peek() == Some(&COMBINING_OVERLINE) {
+            chars.next();
+            values.push(value * 1000);
+        } else {
+            values.push(value);
+        }
+    }
+
+    let mut total: i64 = 0;
+    for (i, value) in values.iter().enumerate() {
+        match values.get(i + 1) {
+            Some(next) if next > value => total -= *value as i64,
+            _ => total += *value as i64,
+        }
+    }
+
+    let canonical = match to_roman(total, true) {
+        Ok(c) => c,
+        Err(_) => {
+            return Err(RomanError::OutOfRange {
+                value: total,
+                max: MAX_VINCULUM,
+            })
+        }
+    };
+    if canonical != normalised {
+        return Err(RomanError::NonCanonical {
+            numeral: numeral.trim().to_string(),
+            canonical,
+        });
+    }
+
+    Ok(total as u32)
+}
+
+#[cfg(test)]
+#[path = "./roman_test.rs"]
+mod roman_test;
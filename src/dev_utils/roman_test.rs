@@ -0,0 +1,67 @@
+use crate::dev_utils::roman::*;
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_roman() {
+        assert_eq!(to_roman(1994, false), Ok("MCMXCIV".to_string()));
+        assert_eq!(to_roman(3999, false), Ok("MMMCMXCIX".to_string()));
+        assert_eq!(to_roman(3999, true), Ok("MMMCMXCIX".to_string()));
+        assert_eq!(
+            to_roman(4000, false),
+            Err(RomanError::OutOfRange {
+                value: 4000,
+                max: 3999
+            })
+        );
+        assert_eq!(
+            to_roman(12_345, true),
+            Ok("X\u{305}I\u{305}I\u{305}CCCXLV".to_string())
+        );
+        assert_eq!(
+            to_roman(4_000_000, true),
+            Err(RomanError::OutOfRange {
+                value: 4_000_000,
+                max: 3_999_999
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_roman() {
+        assert_eq!(from_roman("MCMXCIV"), Ok(1994));
+        assert_eq!(from_roman(" mcmxciv "), Ok(1994));
+        assert_eq!(from_roman("Ⅻ"), Ok(12));
+        assert_eq!(from_roman("ⅿⅽⅿⅹⅽⅳ"), Ok(1994));
+        assert_eq!(from_roman("X\u{305}I\u{305}I\u{305}CCCXLV"), Ok(12_345));
+        assert_eq!(from_roman("x\u{304}"), Ok(10_000));
+        assert_eq!(from_roman("ↂ"), Ok(10_000));
+        assert_eq!(from_roman(""), Err(RomanError::Empty));
+        assert_eq!(from_roman("XIZ"), Err(RomanError::InvalidCharacter('Z')));
+    }
+
+    #[test]
+    fn test_from_roman_non_canonical() {
+        for (numeral, canonical) in [
+            ("IIII", "IV"),
+            ("IC", "XCIX"),
+            ("VX", "V"),
+            ("MMMM", "I\u{305}V\u{305}"),
+            ("I\u{305}", "M"),
+        ] {
+            assert_eq!(
+                from_roman(numeral),
+                Err(RomanError::NonCanonical {
+                    numeral: numeral.to_string(),
+                    canonical: canonical.to_string()
+                })
+            );
+        }
+
+        assert_eq!(
+            from_roman("IIII").unwrap_err().to_string(),
+            "IIII is not a canonical Roman numeral, did you mean IV?"
+        );
+    }
+}
@@ -0,0 +1,913 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeZone, Timelike, Utc, Weekday,
+};
+use regex::Regex;
+use strum_macros::{Display, EnumIter, EnumString, EnumVariantNames};
+
+use super::cron::{join_english, ordinal};
+use super::datetime::{self, Zone};
+
+#[derive(EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum RruleAction {
+    Explain,
+    Expand,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RruleError {
+    pub message: String,
+}
+
+impl Display for RruleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, PartialOrd, Display, EnumString)]
+#[strum(serialize_all = "UPPERCASE")]
+enum Frequency {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+const WEEKDAY_CODES: [&str; 7] = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"];
+// Rules that have not fired after a full Gregorian cycle never will
+const HORIZON_YEARS: i32 = 400;
+// Nor do rules that go this many periods in a row without an occurrence, which stops
+// FREQ=SECONDLY;BYSETPOS=2 from scanning 400 years a second at a time. Days without an
+// occurrence are skipped whole, so a firing rule never has more empty periods in a row than
+// there are seconds in a day.
+const MAX_EMPTY_PERIODS: u32 = 100_000;
+
+// A BYDAY entry such as `MO`, `1MO` or `-1FR`. `nth` is 0 for every such weekday.
+struct ByDay {
+    nth: i32,
+    weekday: Weekday,
+}
+
+struct Rule {
+    frequency: Frequency,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<DateTime<FixedOffset>>,
+    by_second: Vec<u32>,
+    by_minute: Vec<u32>,
+    by_hour: Vec<u32>,
+    by_day: Vec<ByDay>,
+    by_month_day: Vec<i32>,
+    by_year_day: Vec<i32>,
+    by_week_no: Vec<i32>,
+    by_month: Vec<u32>,
+    by_set_pos: Vec<i32>,
+    week_start: Weekday,
+}
+
+// DTSTART with its RRULE, RDATE and EXDATE properties. Rules expand in the wall clock time of
+// `zone`, so a daily 09:00 stays at 09:00 across daylight saving changes.
+pub struct RecurrenceSet {
+    start: NaiveDateTime,
+    zone: Zone,
+    rules: Vec<Rule>,
+    dates: Vec<DateTime<FixedOffset>>,
+    exdates: Vec<DateTime<FixedOffset>>,
+    exdays: Vec<NaiveDate>,
+}
+
+// A DATE or DATE-TIME property value
+enum Value {
+    Date(NaiveDate),
+    DateTime(NaiveDateTime, Zone),
+}
+
+fn error(message: String) -> RruleError {
+    RruleError { message }
+}
+
+fn weekday(code: &str) -> Result<Weekday, RruleError> {
+    WEEKDAY_CODES
+        .iter()
+        .position(|c| *c == code)
+        .and_then(|index| Weekday::try_from(index as u8).ok())
+        .ok_or_else(|| error(format!("Invalid weekday {}", code)))
+}
+
+fn weekday_name(weekday: Weekday) -> String {
+    NaiveDate::from_isoywd_opt(2026, 1, weekday)
+        .map_or(String::new(), |d| d.format("%A").to_string())
+}
+
+fn month_name(month: u32) -> String {
+    chrono::Month::try_from(month as u8).map_or(String::new(), |m| m.name().to_string())
+}
+
+// `1st`, `last`, `2nd to last`
+fn nth(n: i32) -> String {
+    match n {
+        -1 => "last".to_string(),
+        n if n < 0 => format!("{} to last", ordinal(n.unsigned_abs())),
+        n => ordinal(n as u32),
+    }
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    match date.month() {
+        12 => 31,
+        month => {
+            let next = NaiveDate::from_ymd_opt(date.year(), month + 1, 1);
+            next.and_then(|d| d.pred_opt()).map_or(31, |d| d.day())
+        }
+    }
+}
+
+fn days_in_year(year: i32) -> u32 {
+    NaiveDate::from_ymd_opt(year, 12, 31).map_or(365, |d| d.ordinal())
+}
+
+// The first day of week 1 of `year`, the first week with at least four days in the year as in
+// ISO 8601, when weeks start on `week_start`
+fn first_week_start(year: i32, week_start: Weekday) -> Option<NaiveDate> {
+    let first = NaiveDate::from_ymd_opt(year, 1, 1)?;
+    let offset =
+        (7 + first.weekday().num_days_from_monday() - week_start.num_days_from_monday()) % 7;
+    match offset {
+        0..=3 => first.checked_sub_signed(Duration::days(offset as i64)),
+        _ => first.checked_add_signed(Duration::days(7 - offset as i64)),
+    }
+}
+
+// Whether `value` is one of `positions`, where negative positions count back from `len`
+fn matches_position(positions: &[i32], value: u32, len: u32) -> bool {
+    positions
+        .iter()
+        .any(|p| (*p > 0 && *p as u32 == value) || (*p < 0 && len as i32 + 1 + *p == value as i32))
+}
+
+// Resolves a wall clock time the way RFC 5545 does: a repeated time is its first occurrence
+// and a skipped time uses the offset from before the gap
+fn resolve(zone: &Zone, naive: NaiveDateTime) -> Option<DateTime<FixedOffset>> {
+    match zone.local(&naive) {
+        LocalResult::Single(t) => Some(t),
+        LocalResult::Ambiguous(earliest, _) => Some(earliest),
+        LocalResult::None => {
+            let before = zone.local(&(naive - Duration::hours(3))).earliest()?;
+            let instant = before.offset().from_local_datetime(&naive).single()?;
+            Some(zone.at(&instant.with_timezone(&Utc)))
+        }
+    }
+}
+
+fn parse_value(value: &str, zone: Zone) -> Result<Value, RruleError> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y%m%d") {
+        return Ok(Value::Date(date));
+    }
+    let (text, zone) = match value.strip_suffix('Z') {
+        Some(text) => (text, Zone::Named(chrono_tz::UTC)),
+        None => (value, zone),
+    };
+    match NaiveDateTime::parse_from_str(text, "%Y%m%dT%H%M%S") {
+        Ok(naive) => Ok(Value::DateTime(naive, zone)),
+        Err(_) => Err(error(format!(
+            "Invalid date {}, expected YYYYMMDD or YYYYMMDDTHHMMSS with an optional Z",
+            value
+        ))),
+    }
+}
+
+fn numbers(key: &str, value: &str, min: i32, max: i32) -> Result<Vec<i32>, RruleError> {
+    value
+        .split(',')
+        .map(|n| match n.parse::<i32>() {
+            // Zero is only a value where the range starts at it, elsewhere counting starts at 1
+            Ok(n) if n >= min && n <= max && (n != 0 || min == 0) => Ok(n),
+            _ => Err(error(format!(
+                "Invalid {} {}, expected {} to {}",
+                key, n, min, max
+            ))),
+        })
+        .collect()
+}
+
+fn unsigned(key: &str, value: &str, max: i32) -> Result<Vec<u32>, RruleError> {
+    Ok(numbers(key, value, 0, max)?
+        .iter()
+        .map(|n| *n as u32)
+        .collect())
+}
+
+impl Rule {
+    fn parse(value: &str, zone: Zone) -> Result<Rule, RruleError> {
+        let by_day_pattern =
+            Regex::new("^([+-]?\\d{1,2})?([A-Z]{2})$").map_err(|e| error(e.to_string()))?;
+        let mut frequency = None;
+        let mut rule = Rule {
+            frequency: Frequency::Yearly,
+            interval: 1,
+            count: None,
+            until: None,
+            by_second: vec![],
+            by_minute: vec![],
+            by_hour: vec![],
+            by_day: vec![],
+            by_month_day: vec![],
+            by_year_day: vec![],
+            by_week_no: vec![],
+            by_month: vec![],
+            by_set_pos: vec![],
+            week_start: Weekday::Mon,
+        };
+
+        for part in value.to_uppercase().split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| error(format!("Invalid rule part {}, expected NAME=VALUE", part)))?;
+            match key {
+                "FREQ" => {
+                    frequency = Some(
+                        Frequency::from_str(value)
+                            .map_err(|_| error(format!("Invalid FREQ {}", value)))?,
+                    )
+                }
+                "INTERVAL" => match value.parse::<u32>() {
+                    Ok(n) if n > 0 => rule.interval = n,
+                    _ => return Err(error(format!("Invalid INTERVAL {}", value))),
+                },
+                "COUNT" => match value.parse::<u32>() {
+                    Ok(n) => rule.count = Some(n),
+                    _ => return Err(error(format!("Invalid COUNT {}", value))),
+                },
+                // A date UNTIL includes the whole day
+                "UNTIL" => {
+                    let until = match parse_value(value, zone)? {
+                        Value::Date(date) => {
+                            resolve(&zone, date.and_time(NaiveTime::MIN) + Duration::days(1))
+                                .map(|t| t - Duration::seconds(1))
+                        }
+                        Value::DateTime(naive, zone) => resolve(&zone, naive),
+                    };
+                    rule.until = until;
+                }
+                "BYSECOND" => rule.by_second = unsigned(key, value, 59)?,
+                "BYMINUTE" => rule.by_minute = unsigned(key, value, 59)?,
+                "BYHOUR" => rule.by_hour = unsigned(key, value, 23)?,
+                "BYDAY" => {
+                    for day in value.split(',') {
+                        let captures = by_day_pattern
+                            .captures(day)
+                            .ok_or_else(|| error(format!("Invalid BYDAY {}", day)))?;
+                        let nth = match captures.get(1) {
+                            Some(n) => numbers(key, n.as_str(), -53, 53)?[0],
+                            None => 0,
+                        };
+                        rule.by_day.push(ByDay {
+                            nth,
+                            weekday: weekday(&captures[2])?,
+                        });
+                    }
+                }
+                "BYMONTHDAY" => rule.by_month_day = numbers(key, value, -31, 31)?,
+                "BYYEARDAY" => rule.by_year_day = numbers(key, value, -366, 366)?,
+                "BYWEEKNO" => rule.by_week_no = numbers(key, value, -53, 53)?,
+                "BYMONTH" => {
+                    rule.by_month = numbers(key, value, 1, 12)?
+                        .iter()
+                        .map(|n| *n as u32)
+                        .collect()
+                }
+                "BYSETPOS" => rule.by_set_pos = numbers(key, value, -366, 366)?,
+                "WKST" => rule.week_start = weekday(value)?,
+                _ => return Err(error(format!("Unknown rule part {}", key))),
+            }
+        }
+
+        rule.frequency = frequency.ok_or_else(|| error("FREQ is required".to_string()))?;
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err(error("COUNT and UNTIL cannot both be set".to_string()));
+        }
+        let ordinals_allowed = match rule.frequency {
+            Frequency::Monthly => true,
+            Frequency::Yearly => rule.by_week_no.is_empty(),
+            _ => false,
+        };
+        if !ordinals_allowed && rule.by_day.iter().any(|d| d.nth != 0) {
+            return Err(error(format!(
+                "BYDAY cannot number weekdays with FREQ={}{}",
+                rule.frequency,
+                if rule.by_week_no.is_empty() {
+                    ""
+                } else {
+                    " and BYWEEKNO"
+                }
+            )));
+        }
+        Ok(rule)
+    }
+
+    fn is_sub_daily(&self) -> bool {
+        self.frequency < Frequency::Daily
+    }
+
+    fn step(&self) -> Duration {
+        let interval = self.interval as i64;
+        match self.frequency {
+            Frequency::Hourly => Duration::hours(interval),
+            Frequency::Minutely => Duration::minutes(interval),
+            Frequency::Secondly => Duration::seconds(interval),
+            Frequency::Weekly => Duration::weeks(interval),
+            _ => Duration::days(interval),
+        }
+    }
+
+    fn first_period(&self, start: NaiveDateTime) -> Option<NaiveDateTime> {
+        let date = start.date();
+        let period = match self.frequency {
+            Frequency::Yearly => {
+                NaiveDate::from_ymd_opt(date.year(), 1, 1)?.and_time(NaiveTime::MIN)
+            }
+            Frequency::Monthly => {
+                NaiveDate::from_ymd_opt(date.year(), date.month(), 1)?.and_time(NaiveTime::MIN)
+            }
+            Frequency::Weekly => {
+                let days = (7 + date.weekday().num_days_from_monday()
+                    - self.week_start.num_days_from_monday())
+                    % 7;
+                (date - Duration::days(days as i64)).and_time(NaiveTime::MIN)
+            }
+            Frequency::Daily => date.and_time(NaiveTime::MIN),
+            Frequency::Hourly => date.and_hms_opt(start.hour(), 0, 0)?,
+            Frequency::Minutely => date.and_hms_opt(start.hour(), start.minute(), 0)?,
+            Frequency::Secondly => start,
+        };
+        Some(period)
+    }
+
+    fn next_period(&self, period: NaiveDateTime) -> Option<NaiveDateTime> {
+        match self.frequency {
+            Frequency::Yearly => {
+                NaiveDate::from_ymd_opt(period.year() + self.interval as i32, 1, 1)
+                    .map(|d| d.and_time(NaiveTime::MIN))
+            }
+            Frequency::Monthly => {
+                let months = period.year() * 12 + period.month0() as i32 + self.interval as i32;
+                NaiveDate::from_ymd_opt(months / 12, months as u32 % 12 + 1, 1)
+                    .map(|d| d.and_time(NaiveTime::MIN))
+            }
+            _ => period.checked_add_signed(self.step()),
+        }
+    }
+
+    // Skips sub-daily periods to the first one on the next day, keeping to the interval
+    fn next_day_period(&self, period: NaiveDateTime) -> Option<NaiveDateTime> {
+        let midnight = period.date().succ_opt()?.and_time(NaiveTime::MIN);
+        let step = self.step().num_seconds();
+        let periods = ((midnight - period).num_seconds() + step - 1) / step;
+        period.checked_add_signed(Duration::seconds(periods * step))
+    }
+
+    fn weekday_matches(&self, by_day: &ByDay, day: NaiveDate) -> bool {
+        if day.weekday() != by_day.weekday {
+            return false;
+        }
+        if by_day.nth == 0 {
+            return true;
+        }
+        // Numbered weekdays count within the month, or the year for yearly rules without BYMONTH
+        let (position, len) = if self.frequency == Frequency::Monthly || !self.by_month.is_empty() {
+            (day.day(), days_in_month(day))
+        } else {
+            (day.ordinal(), days_in_year(day.year()))
+        };
+        let from_start = (position as i32 - 1) / 7 + 1;
+        let from_end = (len as i32 - position as i32) / 7 + 1;
+        by_day.nth == from_start || by_day.nth == -from_end
+    }
+
+    // The week of `day` within its week numbering year and the number of weeks in that year,
+    // with weeks starting on WKST
+    fn week_number(&self, day: NaiveDate) -> Option<(u32, u32)> {
+        (day.year() - 1..=day.year() + 1).find_map(|year| {
+            let first = first_week_start(year, self.week_start)?;
+            let next = first_week_start(year + 1, self.week_start)?;
+            (first..next).contains(&day).then(|| {
+                (
+                    (day - first).num_days() as u32 / 7 + 1,
+                    (next - first).num_days() as u32 / 7,
+                )
+            })
+        })
+    }
+
+    fn day_matches(&self, day: NaiveDate, start: NaiveDateTime) -> bool {
+        if !self.by_month.is_empty() && !self.by_month.contains(&day.month()) {
+            return false;
+        }
+        if !self.by_week_no.is_empty()
+            && !self
+                .week_number(day)
+                .is_some_and(|(week, weeks)| matches_position(&self.by_week_no, week, weeks))
+        {
+            return false;
+        }
+        if !self.by_year_day.is_empty()
+            && !matches_position(&self.by_year_day, day.ordinal(), days_in_year(day.year()))
+        {
+            return false;
+        }
+        if !self.by_month_day.is_empty()
+            && !matches_position(&self.by_month_day, day.day(), days_in_month(day))
+        {
+            return false;
+        }
+        if !self.by_day.is_empty() && !self.by_day.iter().any(|d| self.weekday_matches(d, day)) {
+            return false;
+        }
+
+        // Without any day parts the rule repeats on the day of DTSTART
+        let no_days =
+            self.by_day.is_empty() && self.by_month_day.is_empty() && self.by_year_day.is_empty();
+        match self.frequency {
+            Frequency::Yearly if no_days && !self.by_week_no.is_empty() => {
+                day.weekday() == start.weekday()
+            }
+            Frequency::Yearly if no_days => {
+                day.day() == start.day()
+                    && (!self.by_month.is_empty() || day.month() == start.month())
+            }
+            Frequency::Monthly if no_days => day.day() == start.day(),
+            Frequency::Weekly if self.by_day.is_empty() => day.weekday() == start.weekday(),
+            _ => true,
+        }
+    }
+
+    fn times(&self, period: NaiveDateTime, start: NaiveDateTime) -> Vec<NaiveTime> {
+        // Parts at or above the frequency expand the set, parts below it only filter the period
+        let values = |by: &[u32], default: u32, period_value: u32, expands: bool| {
+            if expands {
+                let mut values = if by.is_empty() {
+                    vec![default]
+                } else {
+                    by.to_vec()
+                };
+                values.sort_unstable();
+                values.dedup();
+                values
+            } else if by.is_empty() || by.contains(&period_value) {
+                vec![period_value]
+            } else {
+                vec![]
+            }
+        };
+        let hours = values(
+            &self.by_hour,
+            start.hour(),
+            period.hour(),
+            self.frequency >= Frequency::Daily,
+        );
+        let minutes = values(
+            &self.by_minute,
+            start.minute(),
+            period.minute(),
+            self.frequency >= Frequency::Hourly,
+        );
+        let seconds = values(
+            &self.by_second,
+            start.second(),
+            period.second(),
+            self.frequency >= Frequency::Minutely,
+        );
+        let mut times = vec![];
+        for hour in &hours {
+            for minute in &minutes {
+                for second in &seconds {
+                    times.extend(NaiveTime::from_hms_opt(*hour, *minute, *second));
+                }
+            }
+        }
+        times
+    }
+
+    fn candidates(&self, period: NaiveDateTime, start: NaiveDateTime) -> Vec<NaiveDateTime> {
+        let mut first = period.date();
+        let days = match self.frequency {
+            // Week numbers run over the week numbering year, which can start in December
+            Frequency::Yearly if !self.by_week_no.is_empty() => {
+                match (
+                    first_week_start(first.year(), self.week_start),
+                    first_week_start(first.year() + 1, self.week_start),
+                ) {
+                    (Some(week_one), Some(next)) => {
+                        first = week_one;
+                        (next - week_one).num_days() as u32
+                    }
+                    _ => return vec![],
+                }
+            }
+            Frequency::Yearly => days_in_year(first.year()),
+            Frequency::Monthly => days_in_month(first),
+            Frequency::Weekly => 7,
+            _ => 1,
+        };
+        let times = self.times(period, start);
+        let set = first
+            .iter_days()
+            .take(days as usize)
+            .filter(|day| self.day_matches(*day, start))
+            .flat_map(|day| times.iter().map(move |time| day.and_time(*time)))
+            .collect::<Vec<NaiveDateTime>>();
+        if self.by_set_pos.is_empty() {
+            return set;
+        }
+
+        let len = set.len() as i32;
+        let mut picked = self
+            .by_set_pos
+            .iter()
+            .filter_map(|pos| {
+                let index = if *pos > 0 { pos - 1 } else { len + pos };
+                (0..len).contains(&index).then(|| set[index as usize])
+            })
+            .collect::<Vec<NaiveDateTime>>();
+        picked.sort();
+        picked.dedup();
+        picked
+    }
+
+    // Occurrences from `start` on, counting towards COUNT whether or not they are kept, until
+    // `limit` occurrences in the `from`..`until` range pass `keep`
+    fn expand(
+        &self,
+        start: NaiveDateTime,
+        zone: &Zone,
+        range: (Option<DateTime<FixedOffset>>, Option<DateTime<FixedOffset>>),
+        limit: usize,
+        keep: &dyn Fn(&DateTime<FixedOffset>) -> bool,
+    ) -> Vec<DateTime<FixedOffset>> {
+        let (from, until) = range;
+        let mut kept = vec![];
+        let mut produced = 0;
+        let mut pending_start = true;
+        let horizon = start.year() + HORIZON_YEARS;
+
+        // DTSTART is always the first occurrence, even when the rule would not produce it
+        let mut emit = |naive: NaiveDateTime| {
+            let instant = match resolve(zone, naive) {
+                Some(t) => t,
+                None => return true,
+            };
+            if self.count.is_some_and(|count| produced >= count)
+                || self.until.is_some_and(|u| instant > u)
+                || until.is_some_and(|u| instant > u)
+            {
+                return false;
+            }
+            produced += 1;
+            if from.is_none_or(|f| instant >= f) && keep(&instant) {
+                kept.push(instant);
+            }
+            kept.len() < limit
+        };
+
+        let mut period = self.first_period(start);
+        let mut empty_periods = 0;
+        'periods: while let Some(current) = period {
+            if current.year() > horizon || empty_periods > MAX_EMPTY_PERIODS {
+                break;
+            }
+            if self.is_sub_daily() && !self.day_matches(current.date(), start) {
+                period = self.next_day_period(current);
+                continue;
+            }
+            let candidates = self.candidates(current, start);
+            empty_periods = match candidates.is_empty() {
+                true => empty_periods + 1,
+                false => 0,
+            };
+            for naive in candidates {
+                if naive < start {
+                    continue;
+                }
+                if pending_start {
+                    pending_start = false;
+                    if naive != start && !emit(start) {
+                        break 'periods;
+                    }
+                }
+                if !emit(naive) {
+                    break 'periods;
+                }
+            }
+            period = self.next_period(current);
+        }
+        kept
+    }
+
+    fn explain(&self, start: NaiveDateTime, start_text: &str) -> String {
+        let unit = match self.frequency {
+            Frequency::Yearly => "year",
+            Frequency::Monthly => "month",
+            Frequency::Weekly => "week",
+            Frequency::Daily => "day",
+            Frequency::Hourly => "hour",
+            Frequency::Minutely => "minute",
+            Frequency::Secondly => "second",
+        };
+        let mut sentence = match self.interval {
+            1 => format!("Every {}", unit),
+            n => format!("Every {} {}s", n, unit),
+        };
+
+        if !self.by_week_no.is_empty() {
+            let weeks = self
+                .by_week_no
+                .iter()
+                .map(|w| nth(*w))
+                .collect::<Vec<String>>();
+            sentence.push_str(&format!(
+                " in the {} week of the year",
+                join_english(&weeks)
+            ));
+        }
+        if !self.by_year_day.is_empty() {
+            let days = self
+                .by_year_day
+                .iter()
+                .map(|d| nth(*d))
+                .collect::<Vec<String>>();
+            sentence.push_str(&format!(" on the {} day of the year", join_english(&days)));
+        }
+        if !self.by_day.is_empty() {
+            let days = self
+                .by_day
+                .iter()
+                .map(|d| match d.nth {
+                    0 => weekday_name(d.weekday),
+                    n => format!("the {} {}", nth(n), weekday_name(d.weekday)),
+                })
+                .collect::<Vec<String>>();
+            sentence.push_str(&format!(" on {}", join_english(&days)));
+            if self.frequency == Frequency::Yearly
+                && self.by_month.is_empty()
+                && self.by_day.iter().any(|d| d.nth != 0)
+            {
+                sentence.push_str(" of the year");
+            }
+        }
+        if !self.by_month_day.is_empty() {
+            let days = self
+                .by_month_day
+                .iter()
+                .map(|d| nth(*d))
+                .collect::<Vec<String>>();
+            // With BYDAY the month days narrow the weekdays down rather than add to them
+            let days = match (self.by_day.is_empty(), days.as_slice()) {
+                (false, [init @ .., last]) if !init.is_empty() => {
+                    format!("that is the {} or {}", init.join(", "), last)
+                }
+                (false, _) => format!("that is the {}", join_english(&days)),
+                (true, _) => format!("on the {}", join_english(&days)),
+            };
+            sentence.push_str(&format!(" {} day of the month", days));
+        }
+        if !self.by_month.is_empty() {
+            let months = self
+                .by_month
+                .iter()
+                .map(|m| month_name(*m))
+                .collect::<Vec<String>>();
+            sentence.push_str(&format!(" in {}", join_english(&months)));
+        }
+
+        // Daily and longer rules fire at a handful of times of day, shorter ones at parts of
+        // each hour or minute
+        let times = self.times(start, start);
+        if !self.is_sub_daily() && times.len() <= 6 {
+            let times = times
+                .iter()
+                .map(|t| match t.second() {
+                    0 => t.format("%H:%M").to_string(),
+                    _ => t.format("%H:%M:%S").to_string(),
+                })
+                .collect::<Vec<String>>();
+            sentence.push_str(&format!(" at {}", join_english(&times)));
+        } else {
+            let fields = [
+                ("hour", &self.by_hour),
+                ("minute", &self.by_minute),
+                ("second", &self.by_second),
+            ];
+            for (name, values) in fields {
+                if !values.is_empty() {
+                    let values = values
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<String>>();
+                    sentence.push_str(&format!(" at {} {}", name, join_english(&values)));
+                }
+            }
+        }
+        if !self.by_set_pos.is_empty() {
+            let positions = self
+                .by_set_pos
+                .iter()
+                .map(|p| nth(*p))
+                .collect::<Vec<String>>();
+            sentence.push_str(&format!(
+                ", only the {} of each {}",
+                join_english(&positions),
+                unit
+            ));
+        }
+        sentence.push_str(&format!(", starting {}", start_text));
+        match (self.count, self.until) {
+            (Some(1), _) => sentence.push_str(", once"),
+            (Some(count), _) => sentence.push_str(&format!(", {} times", count)),
+            (_, Some(until)) => sentence.push_str(&format!(", until {}", until.to_rfc3339())),
+            _ => {}
+        }
+        sentence.push('.');
+        sentence
+    }
+}
+
+// Reads DTSTART, RRULE, RDATE and EXDATE lines as found in an iCalendar event, or just the rule
+// such as `FREQ=MONTHLY;BYDAY=-1FR`. Without DTSTART the set starts at `start`, and values
+// without a TZID or `Z` are read in `zone`.
+pub fn parse(
+    content: &str,
+    start: DateTime<FixedOffset>,
+    zone: Zone,
+) -> Result<RecurrenceSet, RruleError> {
+    let mut properties = vec![];
+    for line in content.split_whitespace() {
+        let (name, value) = match line.split_once(':') {
+            Some((name, value)) => (name.to_uppercase(), value),
+            None => ("RRULE".to_string(), line),
+        };
+        let mut params = name.split(';');
+        let name = params.next().unwrap_or_default().to_string();
+        let mut property_zone = zone;
+        for param in params {
+            if let Some(tzid) = param.strip_prefix("TZID=") {
+                property_zone = Zone::from_str(tzid).map_err(|e| error(e.to_string()))?;
+            }
+        }
+        properties.push((name, property_zone, value));
+    }
+
+    let mut set = RecurrenceSet {
+        start: zone
+            .at(&start.with_timezone(&Utc))
+            .naive_local()
+            .with_nanosecond(0)
+            .unwrap_or_default(),
+        zone,
+        rules: vec![],
+        dates: vec![],
+        exdates: vec![],
+        exdays: vec![],
+    };
+    for (name, zone, value) in &properties {
+        if name == "DTSTART" {
+            (set.start, set.zone) = match parse_value(value, *zone)? {
+                Value::Date(date) => (date.and_time(NaiveTime::MIN), *zone),
+                Value::DateTime(naive, zone) => (naive, zone),
+            };
+        }
+    }
+
+    for (name, zone, value) in properties {
+        match name.as_str() {
+            "DTSTART" => {}
+            "RRULE" => set.rules.push(Rule::parse(value, set.zone)?),
+            "RDATE" | "EXDATE" => {
+                for value in value.split(',') {
+                    let value = parse_value(value, zone)?;
+                    match (name.as_str(), value) {
+                        ("EXDATE", Value::Date(date)) => set.exdays.push(date),
+                        (_, Value::Date(date)) => {
+                            if let Some(t) = resolve(&zone, date.and_time(NaiveTime::MIN)) {
+                                set.dates.push(t)
+                            }
+                        }
+                        (_, Value::DateTime(naive, zone)) => {
+                            if let Some(t) = resolve(&zone, naive) {
+                                if name == "EXDATE" {
+                                    set.exdates.push(t)
+                                } else {
+                                    set.dates.push(t)
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            _ => return Err(error(format!("Unsupported property {}", name))),
+        }
+    }
+    Ok(set)
+}
+
+impl RecurrenceSet {
+    fn excluded(&self, instant: &DateTime<FixedOffset>) -> bool {
+        self.exdates.contains(instant)
+            || self
+                .exdays
+                .contains(&self.zone.at(&instant.with_timezone(&Utc)).date_naive())
+    }
+
+    // Up to `limit` occurrences between `from` and `until`, oldest first
+    pub fn expand(
+        &self,
+        from: Option<DateTime<FixedOffset>>,
+        until: Option<DateTime<FixedOffset>>,
+        limit: usize,
+    ) -> Vec<DateTime<FixedOffset>> {
+        let keep = |instant: &DateTime<FixedOffset>| !self.excluded(instant);
+        let in_range = |instant: &DateTime<FixedOffset>| {
+            from.is_none_or(|f| *instant >= f) && until.is_none_or(|u| *instant <= u)
+        };
+        let mut occurrences = self
+            .rules
+            .iter()
+            .flat_map(|rule| rule.expand(self.start, &self.zone, (from, until), limit, &keep))
+            .collect::<Vec<DateTime<FixedOffset>>>();
+        occurrences.extend(resolve(&self.zone, self.start));
+        occurrences.extend(self.dates.iter().copied());
+        occurrences.retain(|t| in_range(t) && keep(t));
+        occurrences.sort();
+        occurrences.dedup();
+        occurrences.truncate(limit);
+        occurrences
+    }
+
+    pub fn explain(&self) -> String {
+        let start = match resolve(&self.zone, self.start) {
+            Some(t) => t.to_rfc3339(),
+            None => self.start.to_string(),
+        };
+        let mut lines = self
+            .rules
+            .iter()
+            .map(|rule| rule.explain(self.start, &start))
+            .collect::<Vec<String>>();
+        if self.rules.is_empty() {
+            lines.push(format!("Once at {}.", start));
+        }
+        let also = self
+            .dates
+            .iter()
+            .map(|t| t.to_rfc3339())
+            .collect::<Vec<String>>();
+        if !also.is_empty() {
+            lines.push(format!("Also on {}.", join_english(&also)));
+        }
+        let except = self
+            .exdates
+            .iter()
+            .map(|t| t.to_rfc3339())
+            .chain(self.exdays.iter().map(|d| d.format("%Y-%m-%d").to_string()))
+            .collect::<Vec<String>>();
+        if !except.is_empty() {
+            lines.push(format!("Except on {}.", join_english(&except)));
+        }
+        lines.join("\n")
+    }
+}
+
+// Expands the set and writes each occurrence in `format`, one of the `datetime` formats or a
+// strftime pattern
+pub fn expand(
+    set: &RecurrenceSet,
+    from: Option<DateTime<FixedOffset>>,
+    until: Option<DateTime<FixedOffset>>,
+    limit: usize,
+    format: &str,
+) -> Result<String, RruleError> {
+    let occurrences = set.expand(from, until, limit);
+    if occurrences.is_empty() {
+        return Err(error(
+            "The recurrence has no occurrences in range".to_string(),
+        ));
+    }
+    occurrences
+        .iter()
+        .map(|t| datetime::format(format, t).map_err(|e| error(e.to_string())))
+        .collect::<Result<Vec<String>, RruleError>>()
+        .map(|lines| lines.join("\n"))
+}
+
+#[cfg(test)]
+#[path = "./rrule_test.rs"]
+mod rrule_test;
@@ -0,0 +1,178 @@
+use crate::dev_utils::rrule::*;
+
+mod tests {
+    use super::*;
+    use crate::dev_utils::datetime::Zone;
+    use chrono::{DateTime, FixedOffset};
+    use std::str::FromStr;
+
+    fn start() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2026-01-01T09:00:00Z").unwrap()
+    }
+
+    fn utc() -> Zone {
+        Zone::from_str("UTC").unwrap()
+    }
+
+    fn expand_all(content: &str, limit: usize, format: &str) -> String {
+        let set = match parse(content, start(), utc()) {
+            Ok(set) => set,
+            Err(e) => panic!("{:#?}", e),
+        };
+        match expand(&set, None, None, limit, format) {
+            Ok(s) => s,
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_expand_monthly() {
+        assert_eq!(
+            expand_all(
+                "DTSTART:20260130T090000Z\nRRULE:FREQ=MONTHLY;BYDAY=-1FR",
+                4,
+                "%Y-%m-%d"
+            ),
+            "2026-01-30\n2026-02-27\n2026-03-27\n2026-04-24"
+        );
+        // The second to last weekday of each month
+        assert_eq!(
+            expand_all(
+                "DTSTART:19970929T090000 RRULE:FREQ=MONTHLY;COUNT=7;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-2",
+                10,
+                "%Y-%m-%d"
+            ),
+            "1997-09-29\n1997-10-30\n1997-11-27\n1997-12-30\n1998-01-29\n1998-02-26\n1998-03-30"
+        );
+        // Months without a 31st are skipped
+        assert_eq!(
+            expand_all(
+                "DTSTART:20260131T000000Z RRULE:FREQ=MONTHLY;COUNT=3",
+                10,
+                "%Y-%m-%d"
+            ),
+            "2026-01-31\n2026-03-31\n2026-05-31"
+        );
+    }
+
+    #[test]
+    fn test_expand_weekly_and_yearly() {
+        let content = "DTSTART;TZID=America/New_York:19970902T090000\n\
+                       RRULE:FREQ=WEEKLY;INTERVAL=2;UNTIL=19971224T000000Z;WKST=SU;BYDAY=MO,WE,FR";
+        let occurrences = expand_all(content, 100, "rfc3339");
+        let lines = occurrences.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 25);
+        assert_eq!(lines[0], "1997-09-02T09:00:00-04:00");
+        assert_eq!(lines[1], "1997-09-03T09:00:00-04:00");
+        assert_eq!(lines[24], "1997-12-22T09:00:00-05:00");
+
+        assert_eq!(
+            expand_all(
+                "DTSTART:19970512T090000 RRULE:FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO",
+                3,
+                "%Y-%m-%d"
+            ),
+            "1997-05-12\n1998-05-11\n1999-05-17"
+        );
+        // Week 1 can start in the previous December, and WKST moves the week boundaries
+        assert_eq!(
+            expand_all(
+                "DTSTART:20270104T000000Z RRULE:FREQ=YEARLY;BYWEEKNO=1;BYDAY=MO;COUNT=5",
+                10,
+                "%Y-%m-%d"
+            ),
+            "2027-01-04\n2028-01-03\n2029-01-01\n2029-12-31\n2030-12-30"
+        );
+        assert_eq!(
+            expand_all(
+                "DTSTART:19971228T000000Z RRULE:FREQ=YEARLY;BYWEEKNO=53;BYDAY=SU;WKST=SU;COUNT=4",
+                10,
+                "%Y-%m-%d"
+            ),
+            "1997-12-28\n2003-12-28\n2008-12-28\n2014-12-28"
+        );
+        assert_eq!(
+            expand_all(
+                "DTSTART:19970105T083000 RRULE:FREQ=YEARLY;INTERVAL=2;BYMONTH=1;BYDAY=SU;BYHOUR=8,9;BYMINUTE=30",
+                3,
+                "%Y-%m-%dT%H:%M"
+            ),
+            "1997-01-05T08:30\n1997-01-05T09:30\n1997-01-12T08:30"
+        );
+    }
+
+    #[test]
+    fn test_rule_that_never_fires() {
+        // Each period has a single candidate, so only DTSTART is an occurrence
+        assert_eq!(
+            expand_all(
+                "DTSTART:20260101T000000Z RRULE:FREQ=SECONDLY;BYSETPOS=2",
+                10,
+                "rfc3339"
+            ),
+            "2026-01-01T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_rdate_and_exdate() {
+        let content = "DTSTART;TZID=Europe/London:20260326T013000\n\
+                       RRULE:FREQ=DAILY;COUNT=5\n\
+                       EXDATE;TZID=Europe/London:20260327T013000\n\
+                       EXDATE;VALUE=DATE:20260328\n\
+                       RDATE:20260401T120000Z";
+        // 01:30 on 2026-03-29 does not exist in London and moves an hour later
+        assert_eq!(
+            expand_all(content, 10, "rfc3339"),
+            "2026-03-26T01:30:00+00:00\n\
+             2026-03-29T02:30:00+01:00\n\
+             2026-03-30T01:30:00+01:00\n\
+             2026-04-01T12:00:00+00:00"
+        );
+
+        let set = parse(content, start(), utc()).unwrap();
+        let from = DateTime::parse_from_rfc3339("2026-03-29T12:00:00Z").ok();
+        match expand(&set, from, None, 1, "epoch") {
+            Ok(s) => assert_eq!(s, "1774830600"),
+            Err(e) => panic!("{:#?}", e),
+        }
+    }
+
+    #[test]
+    fn test_explain() {
+        let set = parse("FREQ=MONTHLY;BYDAY=-1FR;COUNT=6", start(), utc()).unwrap();
+        assert_eq!(
+            set.explain(),
+            "Every month on the last Friday at 09:00, starting 2026-01-01T09:00:00+00:00, 6 times."
+        );
+
+        let content = "DTSTART:20260105T083000Z\n\
+                       RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;UNTIL=20261231\n\
+                       EXDATE:20260107T083000Z";
+        let set = parse(content, start(), utc()).unwrap();
+        assert_eq!(
+            set.explain(),
+            "Every 2 weeks on Monday and Wednesday at 08:30, starting 2026-01-05T08:30:00+00:00, \
+             until 2026-12-31T23:59:59+00:00.\n\
+             Except on 2026-01-07T08:30:00+00:00."
+        );
+
+        let set = parse("FREQ=HOURLY;INTERVAL=4;BYMINUTE=15", start(), utc()).unwrap();
+        assert_eq!(
+            set.explain(),
+            "Every 4 hours at minute 15, starting 2026-01-01T09:00:00+00:00."
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(parse("BYDAY=MO", start(), utc()).is_err());
+        assert!(parse("FREQ=FORTNIGHTLY", start(), utc()).is_err());
+        assert!(parse("FREQ=DAILY;BYDAY=1MO", start(), utc()).is_err());
+        assert!(parse("FREQ=DAILY;COUNT=2;UNTIL=20260101", start(), utc()).is_err());
+        assert!(parse("FREQ=MONTHLY;BYMONTHDAY=0", start(), utc()).is_err());
+        assert!(parse("FREQ=DAILY;BYHOUR=24", start(), utc()).is_err());
+        assert!(parse("DTSTART:2026-01-01 FREQ=DAILY", start(), utc()).is_err());
+        assert!(parse("VEVENT:X", start(), utc()).is_err());
+    }
+}
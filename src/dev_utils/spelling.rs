@@ -0,0 +1,343 @@
+use strum_macros::{EnumIter, EnumString, EnumVariantNames};
+
+#[derive(Clone, Copy, EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum Alphabet {
+    // NATO/ICAO, with ICAO digit pronunciations
+    Nato,
+    // German DIN 5009:2022, based on city names
+    Din,
+    // Traditional German spelling alphabet, superseded by DIN 5009:2022
+    German,
+    // APCO/LAPD alphabet used by US police radio
+    Police,
+}
+
+pub struct SpellingAlphabet {
+    // Letters are stored uppercase, the first entry for a character is used when encoding
+    pub words: &'static [(char, &'static str)],
+    // Additional spellings only accepted when decoding
+    pub alternatives: &'static [(char, &'static str)],
+}
+
+const NATO: SpellingAlphabet = SpellingAlphabet {
+    words: &[
+        ('A', "Alpha"),
+        ('B', "Bravo"),
+        ('C', "Charlie"),
+        ('D', "Delta"),
+        ('E', "Echo"),
+        ('F', "Foxtrot"),
+        ('G', "Golf"),
+        ('H', "Hotel"),
+        ('I', "India"),
+        ('J', "Juliet"),
+        ('K', "Kilo"),
+        ('L', "Lima"),
+        ('M', "Mike"),
+        ('N', "November"),
+        ('O', "Oscar"),
+        ('P', "Papa"),
+        ('Q', "Quebec"),
+        ('R', "Romeo"),
+        ('S', "Sierra"),
+        ('T', "Tango"),
+        ('U', "Uniform"),
+        ('V', "Victor"),
+        ('W', "Whiskey"),
+        ('X', "X-ray"),
+        ('Y', "Yankee"),
+        ('Z', "Zulu"),
+        ('0', "Zero"),
+        ('1', "One"),
+        ('2', "Two"),
+        ('3', "Tree"),
+        ('4', "Fower"),
+        ('5', "Fife"),
+        ('6', "Six"),
+        ('7', "Seven"),
+        ('8', "Eight"),
+        ('9', "Niner"),
+        ('.', "Stop"),
+        (',', "Comma"),
+        ('-', "Dash"),
+        ('/', "Slash"),
+        ('?', "Query"),
+        ('!', "Exclamation"),
+        (':', "Colon"),
+        ('@', "At"),
+        ('(', "Brackets-on"),
+        (')', "Brackets-off"),
+    ],
+    alternatives: &[
+        ('A', "Alfa"),
+        ('J', "Juliett"),
+        ('X', "Xray"),
+        ('3', "Three"),
+        ('4', "Four"),
+        ('5', "Five"),
+        ('9', "Nine"),
+        ('.', "Decimal"),
+        ('.', "Period"),
+        ('-', "Hyphen"),
+        ('/', "Slant"),
+    ],
+};
+
+const DIN: SpellingAlphabet = SpellingAlphabet {
+    words: &[
+        ('A', "Aachen"),
+        ('B', "Berlin"),
+        ('C', "Chemnitz"),
+        ('D', "Düsseldorf"),
+        ('E', "Essen"),
+        ('F', "Frankfurt"),
+        ('G', "Goslar"),
+        ('H', "Hamburg"),
+        ('I', "Ingelheim"),
+        ('J', "Jena"),
+        ('K', "Köln"),
+        ('L', "Leipzig"),
+        ('M', "München"),
+        ('N', "Nürnberg"),
+        ('O', "Offenbach"),
+        ('P', "Potsdam"),
+        ('Q', "Quickborn"),
+        ('R', "Rostock"),
+        ('S', "Salzwedel"),
+        ('T', "Tübingen"),
+        ('U', "Unna"),
+        ('V', "Völklingen"),
+        ('W', "Wuppertal"),
+        ('X', "Xanten"),
+        ('Y', "Ypsilon"),
+        ('Z', "Zwickau"),
+        ('Ä', "Umlaut-Aachen"),
+        ('Ö', "Umlaut-Offenbach"),
+        ('Ü', "Umlaut-Unna"),
+        ('ß', "Eszett"),
+        ('0', "Null"),
+        ('1', "Eins"),
+        ('2', "Zwo"),
+        ('3', "Drei"),
+        ('4', "Vier"),
+        ('5', "Fünf"),
+        ('6', "Sechs"),
+        ('7', "Sieben"),
+        ('8', "Acht"),
+        ('9', "Neun"),
+        ('.', "Punkt"),
+        (',', "Komma"),
+        ('-', "Bindestrich"),
+        ('/', "Schrägstrich"),
+    ],
+    alternatives: &[('2', "Zwei")],
+};
+
+const GERMAN: SpellingAlphabet = SpellingAlphabet {
+    words: &[
+        ('A', "Anton"),
+        ('B', "Berta"),
+        ('C', "Cäsar"),
+        ('D', "Dora"),
+        ('E', "Emil"),
+        ('F', "Friedrich"),
+        ('G', "Gustav"),
+        ('H', "Heinrich"),
+        ('I', "Ida"),
+        ('J', "Julius"),
+        ('K', "Kaufmann"),
+        ('L', "Ludwig"),
+        ('M', "Martha"),
+        ('N', "Nordpol"),
+        ('O', "Otto"),
+        ('P', "Paula"),
+        ('Q', "Quelle"),
+        ('R', "Richard"),
+        ('S', "Samuel"),
+        ('T', "Theodor"),
+        ('U', "Ulrich"),
+        ('V', "Viktor"),
+        ('W', "Wilhelm"),
+        ('X', "Xanthippe"),
+        ('Y', "Ypsilon"),
+        ('Z', "Zacharias"),
+        ('Ä', "Ärger"),
+        ('Ö', "Ökonom"),
+        ('Ü', "Übermut"),
+        ('ß', "Eszett"),
+        ('0', "Null"),
+        ('1', "Eins"),
+        ('2', "Zwo"),
+        ('3', "Drei"),
+        ('4', "Vier"),
+        ('5', "Fünf"),
+        ('6', "Sechs"),
+        ('7', "Sieben"),
+        ('8', "Acht"),
+        ('9', "Neun"),
+        ('.', "Punkt"),
+        (',', "Komma"),
+        ('-', "Bindestrich"),
+        ('/', "Schrägstrich"),
+    ],
+    alternatives: &[
+        ('C', "Charlotte"),
+        ('S', "Siegfried"),
+        ('Z', "Zeppelin"),
+        ('2', "Zwei"),
+    ],
+};
+
+const POLICE: SpellingAlphabet = SpellingAlphabet {
+    words: &[
+        ('A', "Adam"),
+        ('B', "Boy"),
+        ('C', "Charles"),
+        ('D', "David"),
+        ('E', "Edward"),
+        ('F', "Frank"),
+        ('G', "George"),
+        ('H', "Henry"),
+        ('I', "Ida"),
+        ('J', "John"),
+        ('K', "King"),
+        ('L', "Lincoln"),
+        ('M', "Mary"),
+        ('N', "Nora"),
+        ('O', "Ocean"),
+        ('P', "Peter"),
+        ('Q', "Queen"),
+        ('R', "Robert"),
+        ('S', "Sam"),
+        ('T', "Tom"),
+        ('U', "Union"),
+        ('V', "Victor"),
+        ('W', "William"),
+        ('X', "X-ray"),
+        ('Y', "Young"),
+        ('Z', "Zebra"),
+        ('0', "Zero"),
+        ('1', "One"),
+        ('2', "Two"),
+        ('3', "Three"),
+        ('4', "Four"),
+        ('5', "Five"),
+        ('6', "Six"),
+        ('7', "Seven"),
+        ('8', "Eight"),
+        ('9', "Nine"),
+    ],
+    alternatives: &[('X', "Xray")],
+};
+
+impl Alphabet {
+    pub fn table(&self) -> &'static SpellingAlphabet {
+        match self {
+            Alphabet::Nato => &NATO,
+            Alphabet::Din => &DIN,
+            Alphabet::German => &GERMAN,
+            Alphabet::Police => &POLICE,
+        }
+    }
+}
+
+impl SpellingAlphabet {
+    pub fn word(&self, c: char) -> Option<&'static str> {
+        let find = |key: char| {
+            self.words
+                .iter()
+                .find(|(letter, _)| *letter == key)
+                .map(|(_, word)| *word)
+        };
+        // `ß` uppercases to `SS`, so only single char uppercase forms are tried
+        find(c).or_else(|| {
+            let mut upper = c.to_uppercase();
+            match (upper.next(), upper.next()) {
+                (Some(key), None) => find(key),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn letter(&self, word: &str) -> Option<char> {
+        let word = word.to_lowercase();
+        self.words
+            .iter()
+            .chain(self.alternatives.iter())
+            .find(|(_, w)| w.to_lowercase() == word)
+            .map(|(letter, _)| *letter)
+    }
+}
+
+pub const MORSE: &[(char, &str)] = &[
+    ('A', ".-"),
+    ('B', "-..."),
+    ('C', "-.-."),
+    ('D', "-.."),
+    ('E', "."),
+    ('F', "..-."),
+    ('G', "--."),
+    ('H', "...."),
+    ('I', ".."),
+    ('J', ".---"),
+    ('K', "-.-"),
+    ('L', ".-.."),
+    ('M', "--"),
+    ('N', "-."),
+    ('O', "---"),
+    ('P', ".--."),
+    ('Q', "--.-"),
+    ('R', ".-."),
+    ('S', "..."),
+    ('T', "-"),
+    ('U', "..-"),
+    ('V', "...-"),
+    ('W', ".--"),
+    ('X', "-..-"),
+    ('Y', "-.--"),
+    ('Z', "--.."),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    ('.', ".-.-.-"),
+    (',', "--..--"),
+    ('?', "..--.."),
+    ('\'', ".----."),
+    ('!', "-.-.--"),
+    ('/', "-..-."),
+    ('(', "-.--."),
+    (')', "-.--.-"),
+    ('&', ".-..."),
+    (':', "---..."),
+    (';', "-.-.-."),
+    ('=', "-...-"),
+    ('+', ".-.-."),
+    ('-', "-....-"),
+    ('_', "..--.-"),
+    ('"', ".-..-."),
+    ('$', "...-..-"),
+    ('@', ".--.-."),
+];
+
+pub fn morse_code(c: char) -> Option<&'static str> {
+    let key = c.to_ascii_uppercase();
+    MORSE
+        .iter()
+        .find(|(letter, _)| *letter == key)
+        .map(|(_, code)| *code)
+}
+
+pub fn morse_letter(code: &str) -> Option<char> {
+    MORSE
+        .iter()
+        .find(|(_, c)| *c == code)
+        .map(|(letter, _)| *letter)
+}
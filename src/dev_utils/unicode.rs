@@ -0,0 +1,190 @@
+use strum_macros::{EnumIter, EnumString, EnumVariantNames};
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_normalization::UnicodeNormalization;
+use unicode_security::confusable_detection::skeleton;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum UnicodeAction {
+    Inspect,
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+    #[strum(serialize = "strip-invisibles", serialize = "stripinvisibles")]
+    StripInvisibles,
+}
+
+pub fn category_abbreviation(category: GeneralCategory) -> &'static str {
+    match category {
+        GeneralCategory::ClosePunctuation => "Pe",
+        GeneralCategory::ConnectorPunctuation => "Pc",
+        GeneralCategory::Control => "Cc",
+        GeneralCategory::CurrencySymbol => "Sc",
+        GeneralCategory::DashPunctuation => "Pd",
+        GeneralCategory::DecimalNumber => "Nd",
+        GeneralCategory::EnclosingMark => "Me",
+        GeneralCategory::FinalPunctuation => "Pf",
+        GeneralCategory::Format => "Cf",
+        GeneralCategory::InitialPunctuation => "Pi",
+        GeneralCategory::LetterNumber => "Nl",
+        GeneralCategory::LineSeparator => "Zl",
+        GeneralCategory::LowercaseLetter => "Ll",
+        GeneralCategory::MathSymbol => "Sm",
+        GeneralCategory::ModifierLetter => "Lm",
+        GeneralCategory::ModifierSymbol => "Sk",
+        GeneralCategory::NonspacingMark => "Mn",
+        GeneralCategory::OpenPunctuation => "Ps",
+        GeneralCategory::OtherLetter => "Lo",
+        GeneralCategory::OtherNumber => "No",
+        GeneralCategory::OtherPunctuation => "Po",
+        GeneralCategory::OtherSymbol => "So",
+        GeneralCategory::ParagraphSeparator => "Zp",
+        GeneralCategory::PrivateUse => "Co",
+        GeneralCategory::SpaceSeparator => "Zs",
+        GeneralCategory::SpacingMark => "Mc",
+        GeneralCategory::Surrogate => "Cs",
+        GeneralCategory::TitlecaseLetter => "Lt",
+        GeneralCategory::UppercaseLetter => "Lu",
+        _ => "Cn",
+    }
+}
+
+// Characters that render as nothing at all: format characters such as ZERO WIDTH SPACE or
+// the BOM, plus the Hangul fillers which are letters but display as blanks.
+pub fn is_invisible(c: char) -> bool {
+    get_general_category(c) == GeneralCategory::Format
+        || matches!(c, '\u{115F}' | '\u{1160}' | '\u{3164}' | '\u{FFA0}')
+}
+
+// Whitespace that looks like a regular space or line break but is not one
+pub fn is_unusual_space(c: char) -> bool {
+    matches!(
+        get_general_category(c),
+        GeneralCategory::SpaceSeparator
+            | GeneralCategory::LineSeparator
+            | GeneralCategory::ParagraphSeparator
+    ) && c != ' '
+}
+
+// A non-ASCII character whose confusable skeleton is plain ASCII, e.g. CYRILLIC SMALL
+// LETTER A, which is indistinguishable from `a`
+pub fn confusable_with(c: char) -> Option<String> {
+    if c.is_ascii() {
+        return None;
+    }
+    let mut buffer = [0; 4];
+    let lookalike = skeleton(c.encode_utf8(&mut buffer)).collect::<String>();
+    if lookalike.is_ascii() && !lookalike.trim().is_empty() {
+        Some(lookalike)
+    } else {
+        None
+    }
+}
+
+fn flags(c: char) -> Vec<String> {
+    let mut flags = vec![];
+    let category = get_general_category(c);
+    if is_invisible(c) {
+        flags.push("invisible".to_string());
+    }
+    if is_unusual_space(c) {
+        flags.push("unusual-space".to_string());
+    }
+    if category == GeneralCategory::Control {
+        flags.push("control".to_string());
+    }
+    if matches!(
+        category,
+        GeneralCategory::NonspacingMark | GeneralCategory::EnclosingMark
+    ) {
+        flags.push("combining".to_string());
+    }
+    if let Some(lookalike) = confusable_with(c) {
+        flags.push(format!("confusable with {:?}", lookalike));
+    }
+    flags
+}
+
+pub fn describe(c: char) -> String {
+    let name = match unicode_names2::name(c) {
+        Some(n) => n.to_string(),
+        None => "<unnamed>".to_string(),
+    };
+
+    let mut buffer = [0; 4];
+    let utf8 = c
+        .encode_utf8(&mut buffer)
+        .bytes()
+        .map(|b| format!("{:02x}", b))
+        .collect::<Vec<String>>()
+        .join(" ");
+    let mut units = [0; 2];
+    let utf16 = c
+        .encode_utf16(&mut units)
+        .iter()
+        .map(|u| format!("{:04x}", u))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    let mut line = format!(
+        "U+{:04X}  {}  {}  utf8: {}  utf16: {}",
+        c as u32,
+        category_abbreviation(get_general_category(c)),
+        name,
+        utf8,
+        utf16
+    );
+    let flags = flags(c);
+    if !flags.is_empty() {
+        line.push_str(&format!("  [{}]", flags.join(", ")));
+    }
+    line
+}
+
+pub fn inspect(content: &str) -> String {
+    let mut lines = vec![];
+    for (index, grapheme) in content.graphemes(true).enumerate() {
+        lines.push(format!("{}: {:?}", index, grapheme));
+        for c in grapheme.chars() {
+            lines.push(format!("  {}", describe(c)));
+        }
+    }
+    lines.join("\n")
+}
+
+pub fn nfc(content: &str) -> String {
+    content.nfc().collect()
+}
+
+pub fn nfd(content: &str) -> String {
+    content.nfd().collect()
+}
+
+pub fn nfkc(content: &str) -> String {
+    content.nfkc().collect()
+}
+
+pub fn nfkd(content: &str) -> String {
+    content.nfkd().collect()
+}
+
+// Removes invisible characters and turns unusual spaces into their plain ASCII counterpart.
+// ZERO WIDTH JOINER and ZERO WIDTH NON-JOINER are kept, as they shape emoji sequences such as
+// 👨‍👩‍👧 and the letters of Persian and Indic scripts.
+pub fn strip_invisibles(content: &str) -> String {
+    content
+        .chars()
+        .filter(|c| !is_invisible(*c) || matches!(c, '\u{200C}' | '\u{200D}'))
+        .map(|c| match get_general_category(c) {
+            GeneralCategory::LineSeparator | GeneralCategory::ParagraphSeparator => '\n',
+            _ if is_unusual_space(c) => ' ',
+            _ => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "./unicode_test.rs"]
+mod unicode_test;
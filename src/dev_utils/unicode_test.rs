@@ -0,0 +1,60 @@
+use crate::dev_utils::unicode::*;
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(
+            describe('a'),
+            "U+0061  Ll  LATIN SMALL LETTER A  utf8: 61  utf16: 0061"
+        );
+        assert_eq!(
+            describe('\u{200B}'),
+            "U+200B  Cf  ZERO WIDTH SPACE  utf8: e2 80 8b  utf16: 200b  [invisible]"
+        );
+        assert_eq!(
+            describe('\u{A0}'),
+            "U+00A0  Zs  NO-BREAK SPACE  utf8: c2 a0  utf16: 00a0  [unusual-space]"
+        );
+        assert_eq!(
+            describe('а'),
+            "U+0430  Ll  CYRILLIC SMALL LETTER A  utf8: d0 b0  utf16: 0430  [confusable with \"a\"]"
+        );
+        assert_eq!(
+            describe('😀'),
+            "U+1F600  So  GRINNING FACE  utf8: f0 9f 98 80  utf16: d83d de00"
+        );
+    }
+
+    #[test]
+    fn test_inspect() {
+        assert_eq!(
+            inspect("e\u{301}!"),
+            "0: \"e\\u{301}\"\n  U+0065  Ll  LATIN SMALL LETTER E  utf8: 65  utf16: 0065\n  U+0301  Mn  COMBINING ACUTE ACCENT  utf8: cc 81  utf16: 0301  [combining]\n1: \"!\"\n  U+0021  Po  EXCLAMATION MARK  utf8: 21  utf16: 0021"
+        );
+        assert_eq!(inspect(""), "");
+    }
+
+    #[test]
+    fn test_normalization() {
+        let decomposed = "e\u{301}";
+        assert_eq!(nfc(decomposed), "\u{e9}");
+        assert_eq!(nfd("\u{e9}"), decomposed);
+        assert_eq!(nfkc("ﬁ①"), "fi1");
+        assert_eq!(nfkd("ﬁé"), "fie\u{301}");
+    }
+
+    #[test]
+    fn test_strip_invisibles() {
+        assert_eq!(strip_invisibles("a\u{200B}b\u{FEFF}c"), "abc");
+        assert_eq!(strip_invisibles("a\u{A0}b\u{2028}c"), "a b\nc");
+        assert_eq!(strip_invisibles("e\u{301} ok"), "e\u{301} ok");
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(strip_invisibles(family), family);
+        assert_eq!(
+            strip_invisibles("\u{645}\u{6CC}\u{200C}\u{62E}\u{648}\u{627}\u{647}\u{645}\u{200B}"),
+            "\u{645}\u{6CC}\u{200C}\u{62E}\u{648}\u{627}\u{647}\u{645}"
+        );
+    }
+}
@@ -0,0 +1,550 @@
+use regex::Regex;
+use strum_macros::Display;
+
+use super::convert::ConversionError;
+use super::number::{self, NumberFormat, NumberLocale};
+
+#[derive(Clone, Copy, Debug, Display, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+pub enum Dimension {
+    Length,
+    Mass,
+    Temperature,
+    Volume,
+    Area,
+    Speed,
+    Time,
+    Pressure,
+    Energy,
+    Data,
+}
+
+// A value is converted to the dimension's base unit with `(value + offset) * factor`
+// and back with `base / factor - offset`. Only temperatures need a non-zero offset,
+// and their base unit is Celsius so the historical conversions stay exact.
+pub struct Unit {
+    pub symbol: &'static str,
+    pub aliases: &'static [&'static str],
+    pub dimension: Dimension,
+    pub factor: f64,
+    pub offset: f64,
+}
+
+const fn unit(
+    symbol: &'static str,
+    aliases: &'static [&'static str],
+    dimension: Dimension,
+    factor: f64,
+) -> Unit {
+    Unit {
+        symbol,
+        aliases,
+        dimension,
+        factor,
+        offset: 0.0,
+    }
+}
+
+const KIBI: f64 = 1024.0;
+
+pub const UNITS: &[Unit] = &[
+    // Length, base: metre
+    unit(
+        "nm",
+        &["nanometer", "nanometre", "nanometers", "nanometres"],
+        Dimension::Length,
+        1e-9,
+    ),
+    unit(
+        "um",
+        &[
+            "µm",
+            "micrometer",
+            "micrometre",
+            "micrometers",
+            "micrometres",
+            "micron",
+        ],
+        Dimension::Length,
+        1e-6,
+    ),
+    unit(
+        "mm",
+        &["millimeter", "millimetre", "millimeters", "millimetres"],
+        Dimension::Length,
+        1e-3,
+    ),
+    unit(
+        "cm",
+        &["centimeter", "centimetre", "centimeters", "centimetres"],
+        Dimension::Length,
+        1e-2,
+    ),
+    unit(
+        "m",
+        &["meter", "metre", "meters", "metres"],
+        Dimension::Length,
+        1.0,
+    ),
+    unit(
+        "km",
+        &["kilometer", "kilometre", "kilometers", "kilometres"],
+        Dimension::Length,
+        1e3,
+    ),
+    unit("in", &["inch", "inches", "\""], Dimension::Length, 0.0254),
+    unit("ft", &["foot", "feet", "'"], Dimension::Length, 0.3048),
+    unit("yd", &["yard", "yards"], Dimension::Length, 0.9144),
+    unit("mi", &["mile", "miles"], Dimension::Length, 1609.344),
+    unit(
+        "nmi",
+        &["nautical-mile", "nautical-miles"],
+        Dimension::Length,
+        1852.0,
+    ),
+    // Mass, base: kilogram
+    unit("mg", &["milligram", "milligrams"], Dimension::Mass, 1e-6),
+    unit("g", &["gram", "grams"], Dimension::Mass, 1e-3),
+    unit(
+        "kg",
+        &["kgs", "kilo", "kilos", "kilogram", "kilograms"],
+        Dimension::Mass,
+        1.0,
+    ),
+    unit(
+        "t",
+        &["tonne", "tonnes", "metric-ton"],
+        Dimension::Mass,
+        1e3,
+    ),
+    unit("oz", &["ounce", "ounces"], Dimension::Mass, 0.028349523125),
+    unit(
+        "lb",
+        &["lbs", "pound", "pounds"],
+        Dimension::Mass,
+        0.45359237,
+    ),
+    unit("st", &["stone", "stones"], Dimension::Mass, 6.35029318),
+    // Temperature, base: degree Celsius
+    Unit {
+        symbol: "C",
+        aliases: &["°C", "celsius", "centigrade"],
+        dimension: Dimension::Temperature,
+        factor: 1.0,
+        offset: 0.0,
+    },
+    Unit {
+        symbol: "F",
+        aliases: &["°F", "fahrenheit"],
+        dimension: Dimension::Temperature,
+        factor: 5.0 / 9.0,
+        offset: -32.0,
+    },
+    Unit {
+        symbol: "K",
+        aliases: &["kelvin", "kelvins"],
+        dimension: Dimension::Temperature,
+        factor: 1.0,
+        offset: -273.15,
+    },
+    Unit {
+        symbol: "R",
+        aliases: &["°R", "rankine"],
+        dimension: Dimension::Temperature,
+        factor: 5.0 / 9.0,
+        offset: -491.67,
+    },
+    // Volume, base: litre
+    unit(
+        "ml",
+        &[
+            "mL",
+            "milliliter",
+            "millilitre",
+            "milliliters",
+            "millilitres",
+        ],
+        Dimension::Volume,
+        1e-3,
+    ),
+    unit(
+        "cl",
+        &[
+            "cL",
+            "centiliter",
+            "centilitre",
+            "centiliters",
+            "centilitres",
+        ],
+        Dimension::Volume,
+        1e-2,
+    ),
+    unit(
+        "dl",
+        &["dL", "deciliter", "decilitre", "deciliters", "decilitres"],
+        Dimension::Volume,
+        1e-1,
+    ),
+    unit(
+        "l",
+        &["L", "liter", "litre", "liters", "litres"],
+        Dimension::Volume,
+        1.0,
+    ),
+    unit("cm3", &["cm³", "cc"], Dimension::Volume, 1e-3),
+    unit(
+        "m3",
+        &["m³", "cubic-meter", "cubic-metre"],
+        Dimension::Volume,
+        1e3,
+    ),
+    unit(
+        "tsp",
+        &["teaspoon", "teaspoons"],
+        Dimension::Volume,
+        0.00492892159375,
+    ),
+    unit(
+        "tbsp",
+        &["tablespoon", "tablespoons"],
+        Dimension::Volume,
+        0.01478676478125,
+    ),
+    unit(
+        "floz",
+        &["fl-oz", "fluid-ounce", "fluid-ounces"],
+        Dimension::Volume,
+        0.0295735295625,
+    ),
+    unit("cup", &["cups"], Dimension::Volume, 0.2365882365),
+    unit("pt", &["pint", "pints"], Dimension::Volume, 0.473176473),
+    unit("qt", &["quart", "quarts"], Dimension::Volume, 0.946352946),
+    unit(
+        "gal",
+        &["gallon", "gallons"],
+        Dimension::Volume,
+        3.785411784,
+    ),
+    unit(
+        "impgal",
+        &["imperial-gallon", "imperial-gallons"],
+        Dimension::Volume,
+        4.54609,
+    ),
+    // Area, base: square metre
+    unit("mm2", &["mm²"], Dimension::Area, 1e-6),
+    unit("cm2", &["cm²"], Dimension::Area, 1e-4),
+    unit("m2", &["m²", "sqm"], Dimension::Area, 1.0),
+    unit("ha", &["hectare", "hectares"], Dimension::Area, 1e4),
+    unit("km2", &["km²"], Dimension::Area, 1e6),
+    unit("in2", &["in²", "sqin"], Dimension::Area, 0.00064516),
+    unit("ft2", &["ft²", "sqft"], Dimension::Area, 0.09290304),
+    unit("yd2", &["yd²", "sqyd"], Dimension::Area, 0.83612736),
+    unit("ac", &["acre", "acres"], Dimension::Area, 4046.8564224),
+    unit("mi2", &["mi²", "sqmi"], Dimension::Area, 2589988.110336),
+    // Speed, base: metre per second
+    unit("m/s", &["mps"], Dimension::Speed, 1.0),
+    unit("km/h", &["kph", "kmh"], Dimension::Speed, 1.0 / 3.6),
+    unit("mph", &["mi/h"], Dimension::Speed, 0.44704),
+    unit("ft/s", &["fps"], Dimension::Speed, 0.3048),
+    unit(
+        "kn",
+        &["kt", "knot", "knots"],
+        Dimension::Speed,
+        1852.0 / 3600.0,
+    ),
+    // Time, base: second
+    unit("ns", &["nanosecond", "nanoseconds"], Dimension::Time, 1e-9),
+    unit(
+        "us",
+        &["µs", "microsecond", "microseconds"],
+        Dimension::Time,
+        1e-6,
+    ),
+    unit(
+        "ms",
+        &["millisecond", "milliseconds"],
+        Dimension::Time,
+        1e-3,
+    ),
+    unit(
+        "s",
+        &["sec", "secs", "second", "seconds"],
+        Dimension::Time,
+        1.0,
+    ),
+    unit("min", &["mins", "minute", "minutes"], Dimension::Time, 60.0),
+    unit(
+        "h",
+        &["hr", "hrs", "hour", "hours"],
+        Dimension::Time,
+        3600.0,
+    ),
+    unit("d", &["day", "days"], Dimension::Time, 86400.0),
+    unit("wk", &["week", "weeks"], Dimension::Time, 604800.0),
+    // Months and years are averages over the Julian year of 365.25 days
+    unit("mo", &["month", "months"], Dimension::Time, 2629800.0),
+    unit("yr", &["year", "years"], Dimension::Time, 31557600.0),
+    // Pressure, base: pascal
+    unit("Pa", &["pascal", "pascals"], Dimension::Pressure, 1.0),
+    unit(
+        "hPa",
+        &["hectopascal", "hectopascals"],
+        Dimension::Pressure,
+        1e2,
+    ),
+    unit(
+        "kPa",
+        &["kilopascal", "kilopascals"],
+        Dimension::Pressure,
+        1e3,
+    ),
+    unit(
+        "MPa",
+        &["megapascal", "megapascals"],
+        Dimension::Pressure,
+        1e6,
+    ),
+    unit("mbar", &["millibar", "millibars"], Dimension::Pressure, 1e2),
+    unit("bar", &["bars"], Dimension::Pressure, 1e5),
+    unit(
+        "atm",
+        &["atmosphere", "atmospheres"],
+        Dimension::Pressure,
+        101325.0,
+    ),
+    unit("psi", &[], Dimension::Pressure, 6894.757293168361),
+    unit("mmHg", &["torr"], Dimension::Pressure, 133.322387415),
+    unit("inHg", &[], Dimension::Pressure, 3386.389),
+    // Energy, base: joule
+    unit("J", &["joule", "joules"], Dimension::Energy, 1.0),
+    unit("kJ", &["kilojoule", "kilojoules"], Dimension::Energy, 1e3),
+    unit("MJ", &["megajoule", "megajoules"], Dimension::Energy, 1e6),
+    unit("cal", &["calorie", "calories"], Dimension::Energy, 4.184),
+    unit(
+        "kcal",
+        &["kilocalorie", "kilocalories", "Cal"],
+        Dimension::Energy,
+        4184.0,
+    ),
+    unit(
+        "Wh",
+        &["watt-hour", "watt-hours"],
+        Dimension::Energy,
+        3600.0,
+    ),
+    unit(
+        "kWh",
+        &["kilowatt-hour", "kilowatt-hours"],
+        Dimension::Energy,
+        3.6e6,
+    ),
+    unit(
+        "eV",
+        &["electronvolt", "electronvolts"],
+        Dimension::Energy,
+        1.602176634e-19,
+    ),
+    unit("BTU", &["btu"], Dimension::Energy, 1055.05585262),
+    // Data sizes, base: byte
+    unit("b", &["bit", "bits"], Dimension::Data, 0.125),
+    unit("kbit", &["kilobit", "kilobits"], Dimension::Data, 125.0),
+    unit("Mbit", &["megabit", "megabits"], Dimension::Data, 125e3),
+    unit("Gbit", &["gigabit", "gigabits"], Dimension::Data, 125e6),
+    unit("B", &["byte", "bytes"], Dimension::Data, 1.0),
+    unit("KB", &["kB", "kilobyte", "kilobytes"], Dimension::Data, 1e3),
+    unit("MB", &["megabyte", "megabytes"], Dimension::Data, 1e6),
+    unit("GB", &["gigabyte", "gigabytes"], Dimension::Data, 1e9),
+    unit("TB", &["terabyte", "terabytes"], Dimension::Data, 1e12),
+    unit("PB", &["petabyte", "petabytes"], Dimension::Data, 1e15),
+    unit("EB", &["exabyte", "exabytes"], Dimension::Data, 1e18),
+    unit("KiB", &["kibibyte", "kibibytes"], Dimension::Data, KIBI),
+    unit(
+        "MiB",
+        &["mebibyte", "mebibytes"],
+        Dimension::Data,
+        KIBI * KIBI,
+    ),
+    unit(
+        "GiB",
+        &["gibibyte", "gibibytes"],
+        Dimension::Data,
+        KIBI * KIBI * KIBI,
+    ),
+    unit(
+        "TiB",
+        &["tebibyte", "tebibytes"],
+        Dimension::Data,
+        KIBI * KIBI * KIBI * KIBI,
+    ),
+    unit(
+        "PiB",
+        &["pebibyte", "pebibytes"],
+        Dimension::Data,
+        KIBI * KIBI * KIBI * KIBI * KIBI,
+    ),
+    unit(
+        "EiB",
+        &["exbibyte", "exbibytes"],
+        Dimension::Data,
+        KIBI * KIBI * KIBI * KIBI * KIBI * KIBI,
+    ),
+];
+
+impl Unit {
+    // Whether converting to and from this unit is exact in floating point
+    fn is_exact(&self) -> bool {
+        self.offset == 0.0 && (self.factor.fract() == 0.0 || self.factor.log2().fract() == 0.0)
+    }
+
+    fn base_value(&self, value: f64) -> f64 {
+        (value + self.offset) * self.factor
+    }
+
+    fn unit_value(&self, value: f64) -> f64 {
+        value / self.factor - self.offset
+    }
+}
+
+// Symbols are matched case-sensitively first so that `b`/`B` or `mbar`/`Mbit` stay
+// distinct; only when nothing matches exactly do we fall back to a case-insensitive search.
+// For data sizes the case of a trailing `b` still counts, `mb` is a megabit and `mB` a megabyte.
+pub fn find_unit(name: &str) -> Option<&'static Unit> {
+    let name = name.trim();
+    let matches_exactly = |u: &&Unit| u.symbol == name || u.aliases.contains(&name);
+    let loose_name = match name.strip_suffix('b') {
+        Some(prefix) => format!("{}bit", prefix),
+        None => name.to_string(),
+    };
+    let matches_loosely = |u: &&Unit| {
+        let name = match u.dimension {
+            Dimension::Data => loose_name.as_str(),
+            _ => name,
+        };
+        u.symbol.eq_ignore_ascii_case(name)
+            || u.aliases.iter().any(|a| a.eq_ignore_ascii_case(name))
+    };
+
+    UNITS
+        .iter()
+        .find(matches_exactly)
+        .or_else(|| UNITS.iter().find(matches_loosely))
+}
+
+fn lookup(name: &str) -> Result<&'static Unit, ConversionError> {
+    match find_unit(name) {
+        Some(u) => Ok(u),
+        None => Err(ConversionError::UnitConversion(format!(
+            "Unknown unit: {}",
+            name
+        ))),
+    }
+}
+
+pub fn convert_value(value: f64, from: &str, to: &str) -> Result<f64, ConversionError> {
+    let from_unit = lookup(from)?;
+    let to_unit = lookup(to)?;
+
+    if from_unit.dimension != to_unit.dimension {
+        return Err(ConversionError::UnitConversion(format!(
+            "Cannot convert {} ({}) to {} ({})",
+            from_unit.symbol, from_unit.dimension, to_unit.symbol, to_unit.dimension
+        )));
+    }
+
+    if from_unit.symbol == to_unit.symbol {
+        return Ok(value);
+    }
+
+    if from_unit.is_exact() && to_unit.is_exact() {
+        let result = value * from_unit.factor / to_unit.factor;
+        return Ok(match result.fract() == 0.0 {
+            true => result,
+            false => round_significant(result, result.abs(), EXACT_SIGNIFICANT_DIGITS),
+        });
+    }
+
+    let result = to_unit.unit_value(from_unit.base_value(value));
+    let magnitude = result
+        .abs()
+        .max(from_unit.offset.abs())
+        .max(to_unit.offset.abs());
+
+    Ok(round_significant(result, magnitude, SIGNIFICANT_DIGITS))
+}
+
+// Going through the base unit accumulates floating point noise (12 in -> 0.9999999999999998 ft),
+// so results are rounded to a precision well beyond that of any factor in the table. Offsets
+// are taken into account for the magnitude so that cancelling them out (0 R -> 0 K) rounds
+// to zero.
+const SIGNIFICANT_DIGITS: i32 = 12;
+// Whole number and power of two factors, such as those of bytes and bits, add no noise of
+// their own. Only a decimal input can leave some in the last digit (1.1 KB -> 1100.0000000000002 B),
+// and whole results such as 1 EiB in bytes are kept exact.
+const EXACT_SIGNIFICANT_DIGITS: i32 = 15;
+
+fn round_significant(value: f64, magnitude: f64, digits: i32) -> f64 {
+    if magnitude == 0.0 || !value.is_finite() {
+        return value;
+    }
+    let scale = 10f64.powi(digits - 1 - magnitude.log10().floor() as i32);
+    (value * scale).round() / scale
+}
+
+// The value is read with the rules of `locale`, so `1,5 km` is 1.5 km in German and
+// `1,500 km` is 1500 km in English
+pub fn parse_quantity(
+    data: &str,
+    locale: NumberLocale,
+) -> Result<(f64, &'static Unit), ConversionError> {
+    let pattern = match Regex::new(
+        r"^\s*(?<value>[-+−]?(?:[0-9]|[.,][0-9])[0-9.,'’_\x{A0}\x{202F}]*(?:[eE][-+]?[0-9]+)?)\s*(?<unit>\S.*?)\s*$",
+    ) {
+        Ok(p) => p,
+        Err(e) => return Err(ConversionError::UnitConversion(e.to_string())),
+    };
+
+    let captures = match pattern.captures(data) {
+        Some(c) => c,
+        None => {
+            return Err(ConversionError::UnitConversion(format!(
+                "Cannot parse quantity {}",
+                data
+            )))
+        }
+    };
+
+    let value = match number::parse(&captures["value"], locale) {
+        Ok(v) => v,
+        Err(e) => return Err(ConversionError::UnitConversion(e.message)),
+    };
+
+    Ok((value, lookup(&captures["unit"])?))
+}
+
+pub fn convert(expression: &str, format: &NumberFormat) -> Result<String, ConversionError> {
+    let pattern = match Regex::new(r"^\s*(?<quantity>.+?)\s+(?:to|in|as|->)\s+(?<unit>\S+)\s*$") {
+        Ok(p) => p,
+        Err(e) => return Err(ConversionError::UnitConversion(e.to_string())),
+    };
+
+    let captures = match pattern.captures(expression) {
+        Some(c) => c,
+        None => {
+            return Err(ConversionError::UnitConversion(format!(
+                "Cannot parse {}, expected `<value> <unit> to <unit>`",
+                expression
+            )))
+        }
+    };
+
+    let (value, from_unit) = parse_quantity(&captures["quantity"], format.locale)?;
+    let to_unit = lookup(&captures["unit"])?;
+    let result = convert_value(value, from_unit.symbol, to_unit.symbol)?;
+
+    Ok(format!("{} {}", format.format(result), to_unit.symbol))
+}
+
+#[cfg(test)]
+#[path = "./units_test.rs"]
+mod units_test;
@@ -0,0 +1,107 @@
+use crate::dev_utils::units::*;
+
+mod tests {
+    use super::*;
+    use crate::dev_utils::convert::ConversionError;
+    use crate::dev_utils::number::{NumberFormat, NumberLocale};
+
+    fn convert_default(expression: &str) -> Result<String, ConversionError> {
+        convert(expression, &NumberFormat::default())
+    }
+
+    #[test]
+    fn test_convert_default() {
+        assert_eq!(convert_default("12.5km to m").unwrap(), "12500 m");
+        assert_eq!(convert_default("12.5 km to m").unwrap(), "12500 m");
+        assert_eq!(convert_default("1 mi in km").unwrap(), "1.609344 km");
+        assert_eq!(convert_default("100 C to F").unwrap(), "212 F");
+        assert_eq!(convert_default("1 kg -> g").unwrap(), "1000 g");
+        assert_eq!(convert_default("2 h to min").unwrap(), "120 min");
+        assert_eq!(convert_default("1 atm to Pa").unwrap(), "101325 Pa");
+        assert_eq!(convert_default("1 kWh to J").unwrap(), "3600000 J");
+        assert_eq!(convert_default("1 ha to m2").unwrap(), "10000 m2");
+        assert_eq!(convert_default("36 km/h to m/s").unwrap(), "10 m/s");
+        assert_eq!(convert_default("1 gal to l").unwrap(), "3.785411784 l");
+        assert_eq!(convert_default("12 in to ft").unwrap(), "1 ft");
+        assert_eq!(convert_default("1e3 m to km").unwrap(), "1 km");
+
+        let german = NumberFormat {
+            locale: NumberLocale::De,
+            ..NumberFormat::default()
+        };
+        assert_eq!(convert("1,5 km to m", &german).unwrap(), "1.500 m");
+        assert_eq!(convert("2.500,25 m to km", &german).unwrap(), "2,50025 km");
+        assert_eq!(convert_default("1,500 m to km").unwrap(), "1.5 km");
+    }
+
+    #[test]
+    fn test_convert_data_sizes() {
+        assert_eq!(convert_default("1 KiB to B").unwrap(), "1024 B");
+        assert_eq!(convert_default("1 KB to B").unwrap(), "1000 B");
+        assert_eq!(convert_default("1 GiB to MiB").unwrap(), "1024 MiB");
+        assert_eq!(convert_default("8 b to B").unwrap(), "1 B");
+        assert_eq!(convert_default("1 mb to kb").unwrap(), "1000 kbit");
+        assert_eq!(convert_default("1 Mb to KB").unwrap(), "125 KB");
+        assert_eq!(convert_default("1 mB to kB").unwrap(), "1000 KB");
+        assert_eq!(convert_default("100 Mbit to MB").unwrap(), "12.5 MB");
+        assert_eq!(convert_default("1 PiB to B").unwrap(), "1125899906842624 B");
+        assert_eq!(
+            convert_default("1 EiB to B").unwrap(),
+            "1152921504606846976 B"
+        );
+        assert_eq!(convert_default("1.5 TiB to B").unwrap(), "1649267441664 B");
+        assert_eq!(convert_default("1.1 KB to B").unwrap(), "1100 B");
+    }
+
+    #[test]
+    fn test_convert_value() {
+        assert_eq!(convert_value(0.0, "C", "F").unwrap(), 32.0);
+        assert_eq!(convert_value(0.0, "celsius", "kelvin").unwrap(), 273.15);
+        assert_eq!(convert_value(32.0, "°F", "K").unwrap(), 273.15);
+        assert_eq!(convert_value(0.0, "R", "K").unwrap(), 0.0);
+        assert_eq!(convert_value(1.0, "lb", "oz").unwrap(), 16.0);
+    }
+
+    #[test]
+    fn test_find_unit() {
+        assert_eq!(find_unit("Kilometres").unwrap().symbol, "km");
+        assert_eq!(find_unit("b").unwrap().symbol, "b");
+        assert_eq!(find_unit("B").unwrap().symbol, "B");
+        assert_eq!(find_unit("GB").unwrap().dimension, Dimension::Data);
+        assert_eq!(find_unit("Gb").unwrap().symbol, "Gbit");
+        assert_eq!(find_unit("LB").unwrap().symbol, "lb");
+        assert!(find_unit("parsec").is_none());
+    }
+
+    #[test]
+    fn test_convert_errors() {
+        match convert_default("1 km to kg") {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::UnitConversion(s) => {
+                    assert_eq!(s, "Cannot convert km (length) to kg (mass)");
+                }
+                _ => panic!("{:#?}", e),
+            },
+        }
+        match convert_default("1 parsec to km") {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::UnitConversion(s) => {
+                    assert_eq!(s, "Unknown unit: parsec");
+                }
+                _ => panic!("{:#?}", e),
+            },
+        }
+        match convert_default("km to mi") {
+            Ok(s) => panic!("{:#?}", s),
+            Err(e) => match e {
+                ConversionError::UnitConversion(s) => {
+                    assert_eq!(s, "Cannot parse quantity km");
+                }
+                _ => panic!("{:#?}", e),
+            },
+        }
+        assert!(convert_default("12km").is_err());
+    }
+}
@@ -0,0 +1,1022 @@
+use strum_macros::{EnumIter, EnumString, EnumVariantNames};
+
+use super::convert::ConversionError;
+
+#[derive(Clone, Copy, PartialEq, EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum Language {
+    #[strum(serialize = "en", serialize = "english")]
+    En,
+    #[strum(serialize = "es", serialize = "spanish")]
+    Es,
+    #[strum(serialize = "fr", serialize = "french")]
+    Fr,
+    #[strum(serialize = "de", serialize = "german")]
+    De,
+    #[strum(serialize = "pt", serialize = "portuguese")]
+    Pt,
+}
+
+#[derive(Clone, Copy, PartialEq, EnumIter, EnumString, EnumVariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum Currency {
+    Usd,
+    Eur,
+}
+
+const EN_SMALL: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const EN_TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+const EN_SCALES: [(u64, &str); 6] = [
+    (1_000_000_000_000_000_000, "quintillion"),
+    (1_000_000_000_000_000, "quadrillion"),
+    (1_000_000_000_000, "trillion"),
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1_000, "thousand"),
+];
+
+const ES_SMALL: [&str; 30] = [
+    "cero",
+    "uno",
+    "dos",
+    "tres",
+    "cuatro",
+    "cinco",
+    "seis",
+    "siete",
+    "ocho",
+    "nueve",
+    "diez",
+    "once",
+    "doce",
+    "trece",
+    "catorce",
+    "quince",
+    "dieciséis",
+    "diecisiete",
+    "dieciocho",
+    "diecinueve",
+    "veinte",
+    "veintiuno",
+    "veintidós",
+    "veintitrés",
+    "veinticuatro",
+    "veinticinco",
+    "veintiséis",
+    "veintisiete",
+    "veintiocho",
+    "veintinueve",
+];
+const ES_TENS: [&str; 10] = [
+    "",
+    "",
+    "veinte",
+    "treinta",
+    "cuarenta",
+    "cincuenta",
+    "sesenta",
+    "setenta",
+    "ochenta",
+    "noventa",
+];
+const ES_HUNDREDS: [&str; 10] = [
+    "",
+    "ciento",
+    "doscientos",
+    "trescientos",
+    "cuatrocientos",
+    "quinientos",
+    "seiscientos",
+    "setecientos",
+    "ochocientos",
+    "novecientos",
+];
+// Spanish uses the long scale, a billón is a million millions
+const ES_SCALES: [(u64, &str, &str); 3] = [
+    (1_000_000_000_000_000_000, "trillón", "trillones"),
+    (1_000_000_000_000, "billón", "billones"),
+    (1_000_000, "millón", "millones"),
+];
+const ES_ORDINAL_UNITS: [&str; 10] = [
+    "", "primero", "segundo", "tercero", "cuarto", "quinto", "sexto", "séptimo", "octavo", "noveno",
+];
+const ES_ORDINAL_TEENS: [&str; 10] = [
+    "décimo",
+    "undécimo",
+    "duodécimo",
+    "decimotercero",
+    "decimocuarto",
+    "decimoquinto",
+    "decimosexto",
+    "decimoséptimo",
+    "decimoctavo",
+    "decimonoveno",
+];
+const ES_ORDINAL_TENS: [&str; 10] = [
+    "",
+    "décimo",
+    "vigésimo",
+    "trigésimo",
+    "cuadragésimo",
+    "quincuagésimo",
+    "sexagésimo",
+    "septuagésimo",
+    "octogésimo",
+    "nonagésimo",
+];
+const ES_ORDINAL_HUNDREDS: [&str; 10] = [
+    "",
+    "centésimo",
+    "ducentésimo",
+    "tricentésimo",
+    "cuadringentésimo",
+    "quingentésimo",
+    "sexcentésimo",
+    "septingentésimo",
+    "octingentésimo",
+    "noningentésimo",
+];
+
+const FR_SMALL: [&str; 17] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize",
+];
+const FR_TENS: [&str; 7] = [
+    "",
+    "",
+    "vingt",
+    "trente",
+    "quarante",
+    "cinquante",
+    "soixante",
+];
+const FR_SCALES: [(u64, &str, &str); 5] = [
+    (1_000_000_000_000_000_000, "trillion", "trillions"),
+    (1_000_000_000_000_000, "billiard", "billiards"),
+    (1_000_000_000_000, "billion", "billions"),
+    (1_000_000_000, "milliard", "milliards"),
+    (1_000_000, "million", "millions"),
+];
+
+const DE_SMALL: [&str; 20] = [
+    "null",
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+const DE_TENS: [&str; 10] = [
+    "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+const DE_SCALES: [(u64, &str, &str); 5] = [
+    (1_000_000_000_000_000_000, "Trillion", "Trillionen"),
+    (1_000_000_000_000_000, "Billiarde", "Billiarden"),
+    (1_000_000_000_000, "Billion", "Billionen"),
+    (1_000_000_000, "Milliarde", "Milliarden"),
+    (1_000_000, "Million", "Millionen"),
+];
+
+const PT_SMALL: [&str; 20] = [
+    "zero",
+    "um",
+    "dois",
+    "três",
+    "quatro",
+    "cinco",
+    "seis",
+    "sete",
+    "oito",
+    "nove",
+    "dez",
+    "onze",
+    "doze",
+    "treze",
+    "catorze",
+    "quinze",
+    "dezesseis",
+    "dezessete",
+    "dezoito",
+    "dezenove",
+];
+const PT_TENS: [&str; 10] = [
+    "",
+    "",
+    "vinte",
+    "trinta",
+    "quarenta",
+    "cinquenta",
+    "sessenta",
+    "setenta",
+    "oitenta",
+    "noventa",
+];
+const PT_HUNDREDS: [&str; 10] = [
+    "",
+    "cento",
+    "duzentos",
+    "trezentos",
+    "quatrocentos",
+    "quinhentos",
+    "seiscentos",
+    "setecentos",
+    "oitocentos",
+    "novecentos",
+];
+const PT_SCALES: [(u64, &str, &str); 5] = [
+    (1_000_000_000_000_000_000, "quintilhão", "quintilhões"),
+    (1_000_000_000_000_000, "quatrilhão", "quatrilhões"),
+    (1_000_000_000_000, "trilhão", "trilhões"),
+    (1_000_000_000, "bilhão", "bilhões"),
+    (1_000_000, "milhão", "milhões"),
+];
+const PT_ORDINAL_UNITS: [&str; 10] = [
+    "", "primeiro", "segundo", "terceiro", "quarto", "quinto", "sexto", "sétimo", "oitavo", "nono",
+];
+const PT_ORDINAL_TENS: [&str; 10] = [
+    "",
+    "décimo",
+    "vigésimo",
+    "trigésimo",
+    "quadragésimo",
+    "quinquagésimo",
+    "sexagésimo",
+    "septuagésimo",
+    "octogésimo",
+    "nonagésimo",
+];
+const PT_ORDINAL_HUNDREDS: [&str; 10] = [
+    "",
+    "centésimo",
+    "ducentésimo",
+    "trecentésimo",
+    "quadringentésimo",
+    "quingentésimo",
+    "sexcentésimo",
+    "septingentésimo",
+    "octingentésimo",
+    "nongentésimo",
+];
+
+// Spanish and Portuguese ordinals are built from Latin forms that stop being usable in practice
+// past the thousands
+const LATIN_ORDINAL_LIMIT: u64 = 999_999;
+
+fn minus(lang: Language) -> &'static str {
+    match lang {
+        Language::En | Language::De => "minus",
+        Language::Es | Language::Pt => "menos",
+        Language::Fr => "moins",
+    }
+}
+
+fn decimal_point(lang: Language) -> &'static str {
+    match lang {
+        Language::En => "point",
+        Language::Es => "coma",
+        Language::Fr => "virgule",
+        Language::De => "Komma",
+        Language::Pt => "vírgula",
+    }
+}
+
+fn en_below_thousand(n: u64) -> String {
+    let mut parts = vec![];
+    if n >= 100 {
+        parts.push(format!("{} hundred", EN_SMALL[(n / 100) as usize]));
+    }
+    let rest = n % 100;
+    if rest >= 20 {
+        let tens = EN_TENS[(rest / 10) as usize];
+        match rest % 10 {
+            0 => parts.push(tens.to_string()),
+            u => parts.push(format!("{}-{}", tens, EN_SMALL[u as usize])),
+        }
+    } else if rest > 0 || parts.is_empty() {
+        parts.push(EN_SMALL[rest as usize].to_string());
+    }
+    parts.join(" ")
+}
+
+fn en_cardinal(mut n: u64) -> String {
+    if n == 0 {
+        return EN_SMALL[0].to_string();
+    }
+    let mut parts = vec![];
+    for (size, name) in EN_SCALES {
+        if n >= size {
+            parts.push(format!("{} {}", en_below_thousand(n / size), name));
+            n %= size;
+        }
+    }
+    if n > 0 {
+        parts.push(en_below_thousand(n));
+    }
+    parts.join(" ")
+}
+
+fn en_ordinal(n: u64) -> String {
+    let cardinal = en_cardinal(n);
+    let split = cardinal.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = cardinal.split_at(split);
+    let last = match last {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        _ if last.ends_with('y') => format!("{}ieth", &last[..last.len() - 1]),
+        _ => format!("{}th", last),
+    };
+    format!("{}{}", head, last)
+}
+
+// `uno` is shortened before masculine nouns, including `mil` and `millones`
+fn es_apocope(words: String) -> String {
+    if let Some(head) = words.strip_suffix("veintiuno") {
+        format!("{}veintiún", head)
+    } else if let Some(head) = words.strip_suffix("uno") {
+        format!("{}un", head)
+    } else {
+        words
+    }
+}
+
+fn es_below_thousand(n: u64) -> String {
+    if n == 100 {
+        return "cien".to_string();
+    }
+    let mut parts = vec![];
+    if n >= 100 {
+        parts.push(ES_HUNDREDS[(n / 100) as usize].to_string());
+    }
+    let rest = n % 100;
+    if rest >= 30 {
+        let tens = ES_TENS[(rest / 10) as usize];
+        match rest % 10 {
+            0 => parts.push(tens.to_string()),
+            u => parts.push(format!("{} y {}", tens, ES_SMALL[u as usize])),
+        }
+    } else if rest > 0 || parts.is_empty() {
+        parts.push(ES_SMALL[rest as usize].to_string());
+    }
+    parts.join(" ")
+}
+
+fn es_below_million(n: u64) -> String {
+    let thousands = n / 1000;
+    let rest = n % 1000;
+    let mut parts = vec![];
+    match thousands {
+        0 => (),
+        1 => parts.push("mil".to_string()),
+        _ => parts.push(format!("{} mil", es_apocope(es_below_thousand(thousands)))),
+    }
+    if rest > 0 || parts.is_empty() {
+        parts.push(es_below_thousand(rest));
+    }
+    parts.join(" ")
+}
+
+fn es_cardinal(mut n: u64) -> String {
+    if n == 0 {
+        return ES_SMALL[0].to_string();
+    }
+    let mut parts = vec![];
+    for (size, singular, plural) in ES_SCALES {
+        let count = n / size;
+        n %= size;
+        match count {
+            0 => (),
+            1 => parts.push(format!("un {}", singular)),
+            _ => parts.push(format!(
+                "{} {}",
+                es_apocope(es_below_million(count)),
+                plural
+            )),
+        }
+    }
+    if n > 0 {
+        parts.push(es_below_million(n));
+    }
+    parts.join(" ")
+}
+
+fn latin_ordinal_below_thousand(
+    n: u64,
+    hundreds: &[&str; 10],
+    tens: &[&str; 10],
+    units: &[&str; 10],
+    teens: Option<&[&str; 10]>,
+) -> Vec<String> {
+    let mut parts = vec![];
+    if n >= 100 {
+        parts.push(hundreds[(n / 100) as usize].to_string());
+    }
+    let rest = n % 100;
+    match teens {
+        Some(teens) if (10..20).contains(&rest) => {
+            parts.push(teens[(rest - 10) as usize].to_string())
+        }
+        _ => {
+            if rest >= 10 {
+                parts.push(tens[(rest / 10) as usize].to_string());
+            }
+            if !rest.is_multiple_of(10) {
+                parts.push(units[(rest % 10) as usize].to_string());
+            }
+        }
+    }
+    parts
+}
+
+fn es_ordinal(n: u64) -> String {
+    let thousands = n / 1000;
+    let mut parts = vec![];
+    match thousands {
+        0 => (),
+        1 => parts.push("milésimo".to_string()),
+        _ => parts.push(format!(
+            "{}milésimo",
+            es_apocope(es_below_thousand(thousands)).replace(' ', "")
+        )),
+    }
+    parts.extend(latin_ordinal_below_thousand(
+        n % 1000,
+        &ES_ORDINAL_HUNDREDS,
+        &ES_ORDINAL_TENS,
+        &ES_ORDINAL_UNITS,
+        Some(&ES_ORDINAL_TEENS),
+    ));
+    parts.join(" ")
+}
+
+fn fr_below_hundred(n: u64) -> String {
+    match n {
+        0..=16 => FR_SMALL[n as usize].to_string(),
+        17..=19 => format!("dix-{}", FR_SMALL[(n - 10) as usize]),
+        20..=69 => {
+            let tens = FR_TENS[(n / 10) as usize];
+            match n % 10 {
+                0 => tens.to_string(),
+                1 => format!("{} et un", tens),
+                u => format!("{}-{}", tens, FR_SMALL[u as usize]),
+            }
+        }
+        71 => "soixante et onze".to_string(),
+        70..=79 => format!("soixante-{}", fr_below_hundred(n - 60)),
+        80 => "quatre-vingts".to_string(),
+        _ => format!("quatre-vingt-{}", fr_below_hundred(n - 80)),
+    }
+}
+
+// `vingts` and `cents` only keep their plural s at the very end of a number or before a noun
+// such as `millions`, never before `mille`
+fn fr_below_thousand(n: u64, plural: bool) -> String {
+    let hundreds = n / 100;
+    let rest = n % 100;
+    let mut result = match hundreds {
+        0 => String::new(),
+        1 => "cent".to_string(),
+        h if rest == 0 && plural => format!("{} cents", FR_SMALL[h as usize]),
+        h => format!("{} cent", FR_SMALL[h as usize]),
+    };
+    if rest > 0 || hundreds == 0 {
+        if !result.is_empty() {
+            result.push(' ');
+        }
+        let tens = fr_below_hundred(rest);
+        match tens.strip_suffix("vingts") {
+            Some(head) if !plural => result.push_str(&format!("{}vingt", head)),
+            _ => result.push_str(&tens),
+        }
+    }
+    result
+}
+
+fn fr_cardinal(mut n: u64) -> String {
+    if n == 0 {
+        return FR_SMALL[0].to_string();
+    }
+    let mut parts = vec![];
+    for (size, singular, plural) in FR_SCALES {
+        let count = n / size;
+        n %= size;
+        match count {
+            0 => (),
+            1 => parts.push(format!("un {}", singular)),
+            _ => parts.push(format!("{} {}", fr_below_thousand(count, true), plural)),
+        }
+    }
+    match n / 1000 {
+        0 => (),
+        1 => parts.push("mille".to_string()),
+        thousands => parts.push(format!("{} mille", fr_below_thousand(thousands, false))),
+    }
+    if !n.is_multiple_of(1000) {
+        parts.push(fr_below_thousand(n % 1000, true));
+    }
+    parts.join(" ")
+}
+
+fn fr_ordinal(n: u64) -> String {
+    if n == 1 {
+        return "premier".to_string();
+    }
+    let cardinal = fr_cardinal(n);
+    let split = cardinal.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = cardinal.split_at(split);
+    let stem = match last {
+        "cinq" => "cinqu".to_string(),
+        "neuf" => "neuv".to_string(),
+        _ => {
+            let singular = if last.ends_with('s') && last != "trois" && last != "six" {
+                &last[..last.len() - 1]
+            } else {
+                last
+            };
+            singular.strip_suffix('e').unwrap_or(singular).to_string()
+        }
+    };
+    format!("{}{}ième", head, stem)
+}
+
+fn de_below_hundred(n: u64) -> String {
+    if n < 20 {
+        return DE_SMALL[n as usize].to_string();
+    }
+    let tens = DE_TENS[(n / 10) as usize];
+    match n % 10 {
+        0 => tens.to_string(),
+        1 => format!("einund{}", tens),
+        u => format!("{}und{}", DE_SMALL[u as usize], tens),
+    }
+}
+
+fn de_below_thousand(n: u64) -> String {
+    let mut result = match n / 100 {
+        0 => String::new(),
+        1 => "einhundert".to_string(),
+        h => format!("{}hundert", DE_SMALL[h as usize]),
+    };
+    if !n.is_multiple_of(100) || n < 100 {
+        result.push_str(&de_below_hundred(n % 100));
+    }
+    result
+}
+
+// A trailing `eins` becomes `ein` before `tausend` and nouns, and `eine` before feminine nouns
+// such as `Million`
+fn de_inflect(words: String, ending: &str) -> String {
+    match words.strip_suffix("eins") {
+        Some(head) => format!("{}{}", head, ending),
+        None => words,
+    }
+}
+
+fn de_cardinal(mut n: u64) -> String {
+    if n == 0 {
+        return DE_SMALL[0].to_string();
+    }
+    let mut parts = vec![];
+    for (size, singular, plural) in DE_SCALES {
+        let count = n / size;
+        n %= size;
+        match count {
+            0 => (),
+            1 => parts.push(format!("eine {}", singular)),
+            _ => parts.push(format!(
+                "{} {}",
+                de_inflect(de_below_thousand(count), "eine"),
+                plural
+            )),
+        }
+    }
+    let mut below_million = String::new();
+    if n >= 1000 {
+        below_million.push_str(&de_inflect(de_below_thousand(n / 1000), "ein"));
+        below_million.push_str("tausend");
+    }
+    if !n.is_multiple_of(1000) {
+        below_million.push_str(&de_below_thousand(n % 1000));
+    }
+    if !below_million.is_empty() {
+        parts.push(below_million);
+    }
+    parts.join(" ")
+}
+
+fn de_ordinal(n: u64) -> String {
+    let rest = n % 100;
+    let (prefix, suffix) = if (1..20).contains(&rest) {
+        let ordinal = match rest {
+            1 => "erste".to_string(),
+            3 => "dritte".to_string(),
+            7 => "siebte".to_string(),
+            8 => "achte".to_string(),
+            _ => format!("{}te", DE_SMALL[rest as usize]),
+        };
+        let prefix = if n > rest {
+            de_cardinal(n - rest)
+        } else {
+            String::new()
+        };
+        (prefix, ordinal)
+    } else {
+        (de_cardinal(n), "ste".to_string())
+    };
+
+    let prefix = prefix.to_lowercase().replace(' ', "");
+    // `eine Million` is written `millionste`, not `einemillionste`
+    let prefix = match prefix.strip_prefix("eine") {
+        Some(rest) if rest.starts_with(['m', 'b', 't']) => rest.to_string(),
+        _ => prefix,
+    };
+    format!("{}{}", prefix, suffix)
+}
+
+fn pt_below_thousand(n: u64) -> String {
+    if n == 100 {
+        return "cem".to_string();
+    }
+    let mut parts = vec![];
+    if n >= 100 {
+        parts.push(PT_HUNDREDS[(n / 100) as usize].to_string());
+    }
+    let rest = n % 100;
+    if rest >= 20 {
+        parts.push(PT_TENS[(rest / 10) as usize].to_string());
+        if !rest.is_multiple_of(10) {
+            parts.push(PT_SMALL[(rest % 10) as usize].to_string());
+        }
+    } else if rest > 0 || parts.is_empty() {
+        parts.push(PT_SMALL[rest as usize].to_string());
+    }
+    parts.join(" e ")
+}
+
+fn pt_cardinal(mut n: u64) -> String {
+    if n == 0 {
+        return PT_SMALL[0].to_string();
+    }
+    let mut parts = vec![];
+    for (size, singular, plural) in PT_SCALES {
+        let count = n / size;
+        n %= size;
+        match count {
+            0 => (),
+            1 => parts.push(format!("um {}", singular)),
+            _ => parts.push(format!("{} {}", pt_below_thousand(count), plural)),
+        }
+    }
+    match n / 1000 {
+        0 => (),
+        1 => parts.push("mil".to_string()),
+        thousands => parts.push(format!("{} mil", pt_below_thousand(thousands))),
+    }
+
+    let rest = n % 1000;
+    let mut result = parts.join(" ");
+    if rest > 0 {
+        // The last group is joined with `e` when it is below a hundred or a round hundred
+        if !result.is_empty() {
+            result.push_str(if rest < 100 || rest.is_multiple_of(100) {
+                " e "
+            } else {
+                " "
+            });
+        }
+        result.push_str(&pt_below_thousand(rest));
+    }
+    result
+}
+
+fn pt_ordinal(n: u64) -> String {
+    let thousands = n / 1000;
+    let mut parts = vec![];
+    match thousands {
+        0 => (),
+        1 => parts.push("milésimo".to_string()),
+        _ => {
+            parts.extend(latin_ordinal_below_thousand(
+                thousands,
+                &PT_ORDINAL_HUNDREDS,
+                &PT_ORDINAL_TENS,
+                &PT_ORDINAL_UNITS,
+                None,
+            ));
+            parts.push("milésimo".to_string());
+        }
+    }
+    parts.extend(latin_ordinal_below_thousand(
+        n % 1000,
+        &PT_ORDINAL_HUNDREDS,
+        &PT_ORDINAL_TENS,
+        &PT_ORDINAL_UNITS,
+        None,
+    ));
+    parts.join(" ")
+}
+
+pub fn cardinal(n: i64, lang: Language) -> String {
+    let magnitude = n.unsigned_abs();
+    let words = match lang {
+        Language::En => en_cardinal(magnitude),
+        Language::Es => es_cardinal(magnitude),
+        Language::Fr => fr_cardinal(magnitude),
+        Language::De => de_cardinal(magnitude),
+        Language::Pt => pt_cardinal(magnitude),
+    };
+    if n < 0 {
+        format!("{} {}", minus(lang), words)
+    } else {
+        words
+    }
+}
+
+pub fn ordinal(n: u64, lang: Language) -> Result<String, ConversionError> {
+    match lang {
+        Language::En => Ok(en_ordinal(n)),
+        Language::Fr => Ok(fr_ordinal(n)),
+        Language::De => Ok(de_ordinal(n)),
+        Language::Es | Language::Pt if n == 0 || n > LATIN_ORDINAL_LIMIT => {
+            Err(ConversionError::WordsConversion(format!(
+                "Ordinal words are supported from 1 to {} in this language",
+                LATIN_ORDINAL_LIMIT
+            )))
+        }
+        Language::Es => Ok(es_ordinal(n)),
+        Language::Pt => Ok(pt_ordinal(n)),
+    }
+}
+
+fn parse_integer(data: &str) -> Result<i64, ConversionError> {
+    match data.trim().replace('_', "").parse::<i64>() {
+        Ok(v) => Ok(v),
+        Err(_) => Err(ConversionError::WordsConversion(format!(
+            "Cannot convert {} to a whole number",
+            data
+        ))),
+    }
+}
+
+// Integers are spelled out in full, decimals are read digit by digit after the decimal point:
+// `3.14` -> "three point one four"
+pub fn number2words(data: &str, lang: Language) -> Result<String, ConversionError> {
+    let data = data.trim();
+    let (whole, fraction) = match data.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (data, None),
+    };
+
+    let mut result = cardinal(parse_integer(whole)?, lang);
+    if whole.starts_with('-') && !result.starts_with(minus(lang)) {
+        result = format!("{} {}", minus(lang), result);
+    }
+    if let Some(fraction) = fraction {
+        if fraction.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit()) {
+            return Err(ConversionError::WordsConversion(format!(
+                "Cannot convert {} to a number",
+                data
+            )));
+        }
+        let digits = fraction
+            .chars()
+            .map(|d| cardinal(d.to_digit(10).unwrap() as i64, lang))
+            .collect::<Vec<String>>();
+        result = format!("{} {} {}", result, decimal_point(lang), digits.join(" "));
+    }
+    Ok(result)
+}
+
+pub fn ordinal2words(data: &str, lang: Language) -> Result<String, ConversionError> {
+    let value = parse_integer(data)?;
+    if value < 0 {
+        return Err(ConversionError::WordsConversion(format!(
+            "Cannot write {} as an ordinal, it is negative",
+            value
+        )));
+    }
+    ordinal(value as u64, lang)
+}
+
+fn currency_names(lang: Language, currency: Currency) -> [&'static str; 4] {
+    match (lang, currency) {
+        (Language::En, Currency::Usd) => ["dollar", "dollars", "cent", "cents"],
+        (Language::En, Currency::Eur) => ["euro", "euros", "cent", "cents"],
+        (Language::Es, Currency::Usd) => ["dólar", "dólares", "centavo", "centavos"],
+        (Language::Es, Currency::Eur) => ["euro", "euros", "céntimo", "céntimos"],
+        (Language::Fr, Currency::Usd) => ["dollar", "dollars", "cent", "cents"],
+        (Language::Fr, Currency::Eur) => ["euro", "euros", "centime", "centimes"],
+        (Language::De, Currency::Usd) => ["Dollar", "Dollar", "Cent", "Cent"],
+        (Language::De, Currency::Eur) => ["Euro", "Euro", "Cent", "Cent"],
+        (Language::Pt, Currency::Usd) => ["dólar", "dólares", "centavo", "centavos"],
+        (Language::Pt, Currency::Eur) => ["euro", "euros", "cêntimo", "cêntimos"],
+    }
+}
+
+// Spells out a count followed by the noun it counts, applying the agreement rules each language
+// has for that position
+fn counted(count: u64, singular: &str, plural: &str, lang: Language) -> String {
+    let noun = if count == 1 { singular } else { plural };
+    let words = cardinal(count as i64, lang);
+    let words = match lang {
+        Language::Es => es_apocope(words),
+        Language::De => de_inflect(words, "ein"),
+        _ => words,
+    };
+
+    // Round millions take a preposition: "un millón de dólares", "un million d'euros"
+    if count >= 1_000_000 && count.is_multiple_of(1_000_000) {
+        match lang {
+            Language::Es | Language::Pt => return format!("{} de {}", words, noun),
+            Language::Fr if noun.starts_with(['a', 'e', 'i', 'o', 'u']) => {
+                return format!("{} d'{}", words, noun)
+            }
+            Language::Fr => return format!("{} de {}", words, noun),
+            _ => (),
+        }
+    }
+    format!("{} {}", words, noun)
+}
+
+pub fn currency2words(
+    data: &str,
+    lang: Language,
+    currency: Currency,
+) -> Result<String, ConversionError> {
+    let data = data.trim().replace('_', "");
+    let (negative, amount) = match data.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, data.as_str()),
+    };
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, "0"));
+
+    let invalid = || {
+        ConversionError::WordsConversion(format!("Cannot convert {} to an amount of money", data))
+    };
+    if fraction.len() > 2 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let units = whole.parse::<u64>().map_err(|_| invalid())?;
+    let cents = format!("{:0<2}", fraction)
+        .parse::<u64>()
+        .map_err(|_| invalid())?;
+
+    let [major, majors, minor, minors] = currency_names(lang, currency);
+    let connector = match lang {
+        Language::En => "and",
+        Language::Es => "con",
+        Language::Fr => "et",
+        Language::De => "und",
+        Language::Pt => "e",
+    };
+
+    let mut result = if units > 0 || cents == 0 {
+        counted(units, major, majors, lang)
+    } else {
+        String::new()
+    };
+    if cents > 0 {
+        if !result.is_empty() {
+            result.push_str(&format!(" {} ", connector));
+        }
+        result.push_str(&counted(cents, minor, minors, lang));
+    }
+    if negative {
+        result = format!("{} {}", minus(lang), result);
+    }
+    Ok(result)
+}
+
+fn english_value(word: &str) -> Option<u64> {
+    if let Some(i) = EN_SMALL.iter().position(|w| *w == word) {
+        return Some(i as u64);
+    }
+    EN_TENS
+        .iter()
+        .position(|w| !w.is_empty() && *w == word)
+        .map(|i| i as u64 * 10)
+}
+
+// Maps an ordinal word back to the cardinal it was built from: "twentieth" -> "twenty"
+fn english_cardinal_word(word: &str) -> String {
+    match word {
+        "first" => "one".to_string(),
+        "second" => "two".to_string(),
+        "third" => "three".to_string(),
+        "fifth" => "five".to_string(),
+        "eighth" => "eight".to_string(),
+        "ninth" => "nine".to_string(),
+        "twelfth" => "twelve".to_string(),
+        _ => match word.strip_suffix("ieth") {
+            Some(stem) => format!("{}y", stem),
+            None => word.strip_suffix("th").unwrap_or(word).to_string(),
+        },
+    }
+}
+
+// Reads English cardinal or ordinal words back into a number, ignoring `and`, commas and the
+// hyphens in compound numbers
+pub fn words2number(data: &str, lang: Language) -> Result<String, ConversionError> {
+    if lang != Language::En {
+        return Err(ConversionError::WordsConversion(
+            "Parsing number words is only supported in English".to_string(),
+        ));
+    }
+
+    let normalised = data.to_lowercase().replace([',', '-'], " ");
+    let mut tokens = normalised.split_whitespace().peekable();
+    let negative = matches!(tokens.peek(), Some(&"minus") | Some(&"negative"));
+    if negative {
+        tokens.next();
+    }
+
+    let invalid = |word: &str| {
+        ConversionError::WordsConversion(format!("{} is not an English number word", word))
+    };
+    let overflow =
+        || ConversionError::WordsConversion(format!("{} is too large to convert", data.trim()));
+
+    let mut total: u64 = 0;
+    let mut current: u64 = 0;
+    let mut seen = false;
+    for token in tokens {
+        if token == "and" {
+            continue;
+        }
+        let word = if english_value(token).is_some() || token == "hundred" {
+            token.to_string()
+        } else {
+            english_cardinal_word(token)
+        };
+
+        if let Some(value) = english_value(&word) {
+            current = current.checked_add(value).ok_or_else(overflow)?;
+        } else if word == "a" && !seen {
+            current = 1;
+        } else if word == "hundred" {
+            current = current.max(1).checked_mul(100).ok_or_else(overflow)?;
+        } else if let Some((size, _)) = EN_SCALES.iter().find(|(_, name)| *name == word) {
+            let group = current.max(1).checked_mul(*size).ok_or_else(overflow)?;
+            total = total.checked_add(group).ok_or_else(overflow)?;
+            current = 0;
+        } else {
+            return Err(invalid(token));
+        }
+        seen = true;
+    }
+
+    if !seen {
+        return Err(ConversionError::WordsConversion(
+            "No number words found".to_string(),
+        ));
+    }
+
+    let value = total.checked_add(current).ok_or_else(overflow)?;
+    let value = i64::try_from(value).map_err(|_| overflow())?;
+    Ok(if negative { -value } else { value }.to_string())
+}
+
+#[cfg(test)]
+#[path = "./words_test.rs"]
+mod words_test;
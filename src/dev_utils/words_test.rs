@@ -0,0 +1,218 @@
+use crate::dev_utils::words::*;
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cardinal_english() {
+        assert_eq!(cardinal(0, Language::En), "zero");
+        assert_eq!(cardinal(13, Language::En), "thirteen");
+        assert_eq!(cardinal(40, Language::En), "forty");
+        assert_eq!(
+            cardinal(1234, Language::En),
+            "one thousand two hundred thirty-four"
+        );
+        assert_eq!(cardinal(-1_000_005, Language::En), "minus one million five");
+        assert_eq!(
+            cardinal(i64::MIN, Language::En),
+            "minus nine quintillion two hundred twenty-three quadrillion three hundred seventy-two trillion thirty-six billion eight hundred fifty-four million seven hundred seventy-five thousand eight hundred eight"
+        );
+    }
+
+    #[test]
+    fn test_cardinal_spanish() {
+        assert_eq!(cardinal(21, Language::Es), "veintiuno");
+        assert_eq!(cardinal(100, Language::Es), "cien");
+        assert_eq!(cardinal(101, Language::Es), "ciento uno");
+        assert_eq!(
+            cardinal(1234, Language::Es),
+            "mil doscientos treinta y cuatro"
+        );
+        assert_eq!(cardinal(21_000, Language::Es), "veintiún mil");
+        assert_eq!(cardinal(100_000, Language::Es), "cien mil");
+        assert_eq!(cardinal(1_000_000, Language::Es), "un millón");
+        assert_eq!(cardinal(2_000_000_000, Language::Es), "dos mil millones");
+        assert_eq!(cardinal(1_000_000_000_000, Language::Es), "un billón");
+    }
+
+    #[test]
+    fn test_cardinal_french() {
+        assert_eq!(cardinal(21, Language::Fr), "vingt et un");
+        assert_eq!(cardinal(71, Language::Fr), "soixante et onze");
+        assert_eq!(cardinal(77, Language::Fr), "soixante-dix-sept");
+        assert_eq!(cardinal(80, Language::Fr), "quatre-vingts");
+        assert_eq!(cardinal(81, Language::Fr), "quatre-vingt-un");
+        assert_eq!(cardinal(99, Language::Fr), "quatre-vingt-dix-neuf");
+        assert_eq!(cardinal(200, Language::Fr), "deux cents");
+        assert_eq!(cardinal(201, Language::Fr), "deux cent un");
+        assert_eq!(cardinal(1000, Language::Fr), "mille");
+        assert_eq!(cardinal(80_000, Language::Fr), "quatre-vingt mille");
+        assert_eq!(cardinal(200_000_000, Language::Fr), "deux cents millions");
+        assert_eq!(
+            cardinal(1234, Language::Fr),
+            "mille deux cent trente-quatre"
+        );
+    }
+
+    #[test]
+    fn test_cardinal_german() {
+        assert_eq!(cardinal(1, Language::De), "eins");
+        assert_eq!(cardinal(21, Language::De), "einundzwanzig");
+        assert_eq!(cardinal(101, Language::De), "einhunderteins");
+        assert_eq!(
+            cardinal(1234, Language::De),
+            "eintausendzweihundertvierunddreißig"
+        );
+        assert_eq!(cardinal(101_000, Language::De), "einhunderteintausend");
+        assert_eq!(cardinal(2_000_001, Language::De), "zwei Millionen eins");
+        assert_eq!(cardinal(1_000_000, Language::De), "eine Million");
+    }
+
+    #[test]
+    fn test_cardinal_portuguese() {
+        assert_eq!(cardinal(100, Language::Pt), "cem");
+        assert_eq!(cardinal(123, Language::Pt), "cento e vinte e três");
+        assert_eq!(cardinal(1001, Language::Pt), "mil e um");
+        assert_eq!(
+            cardinal(1234, Language::Pt),
+            "mil duzentos e trinta e quatro"
+        );
+        assert_eq!(cardinal(1500, Language::Pt), "mil e quinhentos");
+        assert_eq!(cardinal(2_000_000, Language::Pt), "dois milhões");
+    }
+
+    #[test]
+    fn test_ordinal() {
+        let cases = [
+            (1, Language::En, "first"),
+            (12, Language::En, "twelfth"),
+            (21, Language::En, "twenty-first"),
+            (40, Language::En, "fortieth"),
+            (1000, Language::En, "one thousandth"),
+            (21, Language::Es, "vigésimo primero"),
+            (11, Language::Es, "undécimo"),
+            (
+                2345,
+                Language::Es,
+                "dosmilésimo tricentésimo cuadragésimo quinto",
+            ),
+            (1, Language::Fr, "premier"),
+            (21, Language::Fr, "vingt et unième"),
+            (5, Language::Fr, "cinquième"),
+            (9, Language::Fr, "neuvième"),
+            (80, Language::Fr, "quatre-vingtième"),
+            (1000, Language::Fr, "millième"),
+            (1, Language::De, "erste"),
+            (3, Language::De, "dritte"),
+            (20, Language::De, "zwanzigste"),
+            (101, Language::De, "einhunderterste"),
+            (1_000_000, Language::De, "millionste"),
+            (11, Language::Pt, "décimo primeiro"),
+            (2000, Language::Pt, "segundo milésimo"),
+        ];
+        for (n, lang, expected) in cases {
+            assert_eq!(ordinal(n, lang).unwrap(), expected);
+        }
+
+        assert!(ordinal(1_000_000, Language::Es).is_err());
+        assert!(ordinal2words("-1", Language::En).is_err());
+    }
+
+    #[test]
+    fn test_number2words() {
+        let result = number2words("3.14", Language::En);
+        match result {
+            Ok(s) => assert_eq!(s, "three point one four"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        let result = number2words("-0.5", Language::Fr);
+        match result {
+            Ok(s) => assert_eq!(s, "moins zéro virgule cinq"),
+            Err(e) => panic!("{:#?}", e),
+        }
+
+        assert!(number2words("1.2.3", Language::En).is_err());
+        assert!(number2words("twelve", Language::En).is_err());
+    }
+
+    #[test]
+    fn test_currency2words() {
+        let cases = [
+            (
+                "1234.56",
+                Language::En,
+                Currency::Usd,
+                "one thousand two hundred thirty-four dollars and fifty-six cents",
+            ),
+            ("1", Language::En, Currency::Eur, "one euro"),
+            ("0.01", Language::En, Currency::Usd, "one cent"),
+            ("0", Language::En, Currency::Usd, "zero dollars"),
+            (
+                "21.5",
+                Language::Es,
+                Currency::Usd,
+                "veintiún dólares con cincuenta centavos",
+            ),
+            ("1000000", Language::Es, Currency::Eur, "un millón de euros"),
+            (
+                "2000000",
+                Language::Fr,
+                Currency::Eur,
+                "deux millions d'euros",
+            ),
+            ("1.01", Language::De, Currency::Eur, "ein Euro und ein Cent"),
+            (
+                "2.5",
+                Language::Pt,
+                Currency::Usd,
+                "dois dólares e cinquenta centavos",
+            ),
+            ("-3", Language::En, Currency::Usd, "minus three dollars"),
+        ];
+        for (amount, lang, currency, expected) in cases {
+            match currency2words(amount, lang, currency) {
+                Ok(s) => assert_eq!(s, expected),
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+
+        assert!(currency2words("1.234", Language::En, Currency::Usd).is_err());
+    }
+
+    #[test]
+    fn test_words2number() {
+        let cases = [
+            ("one thousand two hundred thirty-four", "1234"),
+            ("One Hundred and Five", "105"),
+            ("a hundred", "100"),
+            ("minus forty-two", "-42"),
+            ("twenty-first", "21"),
+            ("one millionth", "1000000"),
+            ("zero", "0"),
+            (
+                "nine quintillion two hundred twenty-three quadrillion three hundred seventy-two trillion thirty-six billion eight hundred fifty-four million seven hundred seventy-five thousand eight hundred seven",
+                "9223372036854775807",
+            ),
+        ];
+        for (words, expected) in cases {
+            match words2number(words, Language::En) {
+                Ok(s) => assert_eq!(s, expected),
+                Err(e) => panic!("{:#?}", e),
+            }
+        }
+
+        assert!(words2number("twelve bananas", Language::En).is_err());
+        assert!(words2number("", Language::En).is_err());
+        assert!(words2number("ten quintillion", Language::En).is_err());
+        assert!(words2number("dos", Language::Es).is_err());
+    }
+
+    #[test]
+    fn test_round_trip_english() {
+        for n in [0, 7, 19, 20, 99, 100, 101, 999, 1001, 123_456_789] {
+            let words = cardinal(n, Language::En);
+            assert_eq!(words2number(&words, Language::En).unwrap(), n.to_string());
+        }
+    }
+}
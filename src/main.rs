@@ -1,19 +1,29 @@
 mod dev_utils;
 
-use clap::{command, Args, Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 use clap_stdin::MaybeStdin;
 
 use dev_utils::base64::B64Action;
+use dev_utils::case::{AcronymMode, CaseAction};
+use dev_utils::cipher::{ByteFormat, CipherAction};
 use dev_utils::colour::Colour;
-use dev_utils::convert::Conversion;
-use dev_utils::date::DateAction;
+use dev_utils::convert::{Conversion, TextEncoding};
+use dev_utils::cron::CronAction;
+use dev_utils::date::{DateAction, DiffOutput};
 use dev_utils::datetime::DateTimeFormat;
+use dev_utils::escape::EscapeFormat;
 use dev_utils::generate::GenerateSubcommands;
 use dev_utils::hash::HashType;
+use dev_utils::id::{IdKind, SnowflakeEpoch};
 use dev_utils::list::ListAction;
+use dev_utils::number::{Notation, NumberLocale};
 use dev_utils::percentage::PercentageAction;
 use dev_utils::regex::RegexAction;
+use dev_utils::rrule::RruleAction;
+use dev_utils::spelling::Alphabet;
+use dev_utils::unicode::UnicodeAction;
 use dev_utils::url::UrlAction;
+use dev_utils::words::{Currency, Language};
 use dev_utils::CliError;
 
 use std::process::exit;
@@ -40,6 +50,14 @@ enum Commands {
     Generate(GenerateArgs),
     Percentage(PercentageArgs),
     Regex(RegexArgs),
+    Case(CaseArgs),
+    Escape(EscapeArgs),
+    Unescape(EscapeArgs),
+    Unicode(UnicodeArgs),
+    Cipher(CipherArgs),
+    Id(IdArgs),
+    Cron(CronArgs),
+    Rrule(RruleArgs),
 }
 
 #[derive(Args, Clone)]
@@ -69,14 +87,64 @@ pub struct B64Args {
 pub struct ConversionArgs {
     action: String,
     content: Option<MaybeStdin<String>>,
+    #[arg(long, default_value = "utf8", help = format!("Text encoding for text2asciibinary/asciibinary2text: {}", dev_utils::enum_variants::<TextEncoding>()))]
+    encoding: String,
+    #[arg(
+        long,
+        help = "Use SI (1000-based) units instead of IEC (1024-based) for bytes2human"
+    )]
+    si: bool,
+    #[arg(long, default_value = "nato", help = format!("Spelling alphabet for text2nato/nato2text: {}", dev_utils::enum_variants::<Alphabet>()))]
+    alphabet: String,
+    #[arg(long, default_value = " ", help = "Separator between Morse letters")]
+    letter_separator: String,
+    #[arg(long, default_value = " / ", help = "Separator between Morse words")]
+    word_separator: String,
+    #[arg(
+        long,
+        help = "Write Morse code in timing notation (= dit, === dah, . silence)"
+    )]
+    timing: bool,
+    #[arg(
+        long,
+        help = "Allow arabic2roman values up to 3999999, written with overlined letters"
+    )]
+    vinculum: bool,
+    #[arg(long, default_value = "en", help = format!("Language for number2words/words2number/ordinal2words/currency2words: {}", dev_utils::enum_variants::<Language>()))]
+    lang: String,
+    #[arg(long, default_value = "usd", help = format!("Currency for currency2words: {}", dev_utils::enum_variants::<Currency>()))]
+    currency: String,
+    #[arg(long, default_value = "plain", help = format!("Locale used to read and write numbers: {}", dev_utils::enum_variants::<NumberLocale>()))]
+    locale: String,
+    #[arg(long, default_value = "standard", help = format!("Notation for numeric results: {}", dev_utils::enum_variants::<Notation>()))]
+    notation: String,
+    #[arg(
+        long,
+        help = "Fixed number of decimal places for numeric results, 1 by default for bytes2human"
+    )]
+    decimals: Option<u8>,
+    #[arg(long, help = "Number of significant digits for numeric results")]
+    significant: Option<u8>,
 }
 
 #[derive(Args, Clone)]
-#[command(about = format!("Available formats: {}", dev_utils::enum_variants::<DateTimeFormat>()))]
+#[command(about = format!("Available formats: {}, or a strftime pattern such as \"%d/%m/%Y %H:%M\"", dev_utils::enum_variants::<DateTimeFormat>()))]
 pub struct DateTimeArgs {
     from: String,
     to: String,
     content: Option<MaybeStdin<String>>,
+    #[arg(
+        long,
+        value_delimiter = ',',
+        help = "Time zones to show the result in, as IANA names (Europe/Paris) or offsets (+05:30). Several zones list the same instant in each"
+    )]
+    tz: Vec<String>,
+    #[arg(
+        long,
+        default_value = "UTC",
+        help = "Time zone of inputs that have no offset of their own"
+    )]
+    from_tz: String,
 }
 
 #[derive(Args, Clone)]
@@ -84,12 +152,82 @@ pub struct DateTimeArgs {
 struct ListArgs {
     action: String,
     content: Option<MaybeStdin<String>>,
-    #[arg(short, long, default_value = ",")]
+    #[arg(
+        short,
+        long,
+        default_value = ",",
+        help = "Item separator, escape sequences such as \\n and \\t are interpreted"
+    )]
     separator: String,
+    #[arg(
+        long,
+        help = "Split items on matches of this regex instead of --separator"
+    )]
+    separator_regex: Option<String>,
+    #[arg(
+        short,
+        long,
+        help = "Join the output with this instead of --separator. Defaults to , with --separator-regex"
+    )]
+    output_separator: Option<String>,
+    #[arg(long, help = "Strip whitespace around items")]
+    trim: bool,
+    #[arg(long, help = "Leave out empty items")]
+    drop_empty: bool,
+    #[arg(
+        long,
+        help = "Keep separators inside items wrapped in single or double quotes, as in \"a,b\",c"
+    )]
+    quoted: bool,
     #[arg(short, long, default_value = "0")]
     index: usize,
     #[arg(short, long, default_value = "0")]
     length: usize,
+    #[arg(long, group = "sort_mode", help = "Sort numbers by value")]
+    numeric: bool,
+    #[arg(
+        long,
+        group = "sort_mode",
+        help = "Sort digits within text by value, file2 before file10"
+    )]
+    natural: bool,
+    #[arg(long, group = "sort_mode", help = "Sort by length")]
+    by_length: bool,
+    #[arg(
+        short = 'V',
+        long,
+        group = "sort_mode",
+        help = "Sort versions such as v1.2.10 and 1.0.0-rc.1"
+    )]
+    version_sort: bool,
+    #[arg(long, help = "Sort ignoring case")]
+    ignore_case: bool,
+    #[arg(short, long, help = "Sort in descending order")]
+    reverse: bool,
+    #[arg(long, help = "Keep the input order of items with equal sort keys")]
+    stable: bool,
+    #[arg(
+        short,
+        long,
+        help = "Sort by the first capture group of this regex, or the whole match"
+    )]
+    key: Option<String>,
+    #[arg(
+        long,
+        conflicts_with = "other_file",
+        help = "Second list for union, intersect, difference, symmetric-difference and is-subset"
+    )]
+    other: Option<String>,
+    #[arg(long, help = "Read the second list for set actions from a file")]
+    other_file: Option<String>,
+    #[arg(long, help = "Only show the N most frequent items or largest groups")]
+    top: Option<usize>,
+    #[arg(
+        long,
+        conflicts_with = "key",
+        help = "Group items by their first N characters instead of by --key"
+    )]
+    prefix: Option<usize>,
 }
 #[derive(Args, Clone)]
 #[command(about = format!("Available actions: {}", dev_utils::enum_variants::<Colour>()))]
@@ -102,7 +240,34 @@ struct ColourArgs {
 #[command(about = format!("Available actions: {}", dev_utils::enum_variants::<DateAction>()))]
 pub struct DateArgs {
     action: String,
+    #[arg(allow_hyphen_values = true)]
     content: Option<MaybeStdin<String>>,
+    #[arg(help = "End date for diff, in any format `datetime auto` reads. Defaults to now")]
+    end: Option<String>,
+    #[arg(
+        long,
+        help = "Reference date or date time for delta, parse and humanize, in any format `datetime auto` reads. Defaults to now"
+    )]
+    from: Option<String>,
+    #[arg(
+        long,
+        help = "Output format for delta and parse, one of the datetime formats or a strftime pattern"
+    )]
+    format: Option<String>,
+    #[arg(long, default_value = "report", help = format!("Output for diff: {}", dev_utils::enum_variants::<DiffOutput>()))]
+    output: String,
+    #[arg(
+        long,
+        value_delimiter = ',',
+        help = "Dates skipped as business days, as YYYY-MM-DD separated by commas"
+    )]
+    holidays: Vec<String>,
+    #[arg(
+        long,
+        value_delimiter = ',',
+        help = "Dates to highlight in cal, separated by commas"
+    )]
+    highlight: Vec<String>,
 }
 #[derive(Args, Clone)]
 #[command(about = format!("Available actions: {}", dev_utils::enum_variants::<GenerateSubcommands>()))]
@@ -115,16 +280,18 @@ pub struct GenerateArgs {
 #[command(about = format!("Available actions: {}", dev_utils::enum_variants::<PercentageAction>()))]
 pub struct PercentageArgs {
     action: String,
-    #[arg(short, long)]
-    from_number: Option<MaybeStdin<f64>>,
-    #[arg(short, long)]
-    to_number: Option<MaybeStdin<f64>>,
-    #[arg(short, long)]
-    percentage: Option<MaybeStdin<f32>>,
+    #[arg(short, long, allow_hyphen_values = true)]
+    from_number: Option<MaybeStdin<String>>,
+    #[arg(short, long, allow_hyphen_values = true)]
+    to_number: Option<MaybeStdin<String>>,
+    #[arg(short, long, allow_hyphen_values = true)]
+    percentage: Option<MaybeStdin<String>>,
     #[arg(long, default_value = "0")]
     precision: Option<MaybeStdin<u8>>,
-    #[arg(short, long)]
-    of_number: Option<MaybeStdin<f64>>,
+    #[arg(short, long, allow_hyphen_values = true)]
+    of_number: Option<MaybeStdin<String>>,
+    #[arg(long, default_value = "plain", help = format!("Locale used to read and write numbers: {}", dev_utils::enum_variants::<NumberLocale>()))]
+    locale: String,
 }
 #[derive(Args, Clone)]
 #[command(about = format!("Available actions: {}", dev_utils::enum_variants::<RegexAction>()))]
@@ -133,6 +300,135 @@ pub struct RegexArgs {
     timedate_format: Option<String>,
 }
 
+#[derive(Args, Clone)]
+#[command(about = format!("Available cases: {}", dev_utils::enum_variants::<CaseAction>()))]
+pub struct CaseArgs {
+    case: String,
+    content: Option<MaybeStdin<String>>,
+    #[arg(
+        short,
+        long,
+        help = "Convert each item of a list split by this separator, escape sequences such as \\n and \\t are interpreted"
+    )]
+    separator: Option<String>,
+    #[arg(short, long, default_value = "split", help = format!("Acronym handling: {}", dev_utils::enum_variants::<AcronymMode>()))]
+    acronyms: String,
+}
+
+#[derive(Args, Clone)]
+#[command(about = format!("Available formats: {}", dev_utils::enum_variants::<EscapeFormat>()))]
+pub struct EscapeArgs {
+    format: String,
+    content: Option<MaybeStdin<String>>,
+    #[arg(long, help = "Escape non-ASCII characters as well")]
+    ascii: bool,
+}
+
+#[derive(Args, Clone)]
+#[command(about = format!("Available actions: {}", dev_utils::enum_variants::<UnicodeAction>()))]
+pub struct UnicodeArgs {
+    action: String,
+    content: Option<MaybeStdin<String>>,
+}
+
+#[derive(Args, Clone)]
+#[command(about = format!("Available ciphers: {}", dev_utils::enum_variants::<CipherAction>()))]
+pub struct CipherArgs {
+    cipher: String,
+    content: Option<MaybeStdin<String>>,
+    #[arg(
+        long,
+        default_value = "3",
+        allow_hyphen_values = true,
+        help = "Shift for caesar"
+    )]
+    shift: i32,
+    #[arg(
+        short,
+        long,
+        help = "Key for vigenere and xor, xor keys prefixed with 0x are read as hex"
+    )]
+    key: Option<String>,
+    #[arg(short, long, help = "Decode instead of encode (caesar, vigenere)")]
+    decode: bool,
+    #[arg(long, default_value = "hex", help = format!("Input format for xor: {}", dev_utils::enum_variants::<ByteFormat>()))]
+    input: String,
+    #[arg(long, default_value = "hex", help = format!("Output format for xor: {}", dev_utils::enum_variants::<ByteFormat>()))]
+    output: String,
+}
+
+#[derive(Args, Clone)]
+#[command(about = format!("Inspect an identifier. Available kinds: {}", dev_utils::enum_variants::<IdKind>()))]
+pub struct IdArgs {
+    kind: String,
+    content: Option<MaybeStdin<String>>,
+    #[arg(long, default_value = "twitter", help = format!("Snowflake epoch: {}, or a date such as 2015-01-01T00:00:00Z", dev_utils::enum_variants::<SnowflakeEpoch>()))]
+    epoch: String,
+}
+
+#[derive(Args, Clone)]
+#[command(about = format!("Available actions: {}", dev_utils::enum_variants::<CronAction>()))]
+pub struct CronArgs {
+    action: String,
+    content: Option<MaybeStdin<String>>,
+    #[arg(
+        short = 'n',
+        long,
+        default_value_t = 5,
+        help = "Number of fire times for next and prev"
+    )]
+    count: usize,
+    #[arg(
+        long,
+        help = "Instant to list fire times from, in any format `date parse` reads. Defaults to now"
+    )]
+    from: Option<String>,
+    #[arg(
+        long,
+        default_value = "UTC",
+        help = "Time zone the schedule runs in, as an IANA name or an offset such as +05:30"
+    )]
+    tz: String,
+}
+
+#[derive(Args, Clone)]
+#[command(about = format!("Available actions: {}", dev_utils::enum_variants::<RruleAction>()))]
+pub struct RruleArgs {
+    action: String,
+    #[arg(
+        help = "An RRULE such as FREQ=MONTHLY;BYDAY=-1FR, or DTSTART, RRULE, RDATE and EXDATE lines"
+    )]
+    content: Option<MaybeStdin<String>>,
+    #[arg(
+        long,
+        help = "Start when there is no DTSTART, in any format `date parse` reads. Defaults to now"
+    )]
+    start: Option<String>,
+    #[arg(long, help = "Only expand occurrences from this date on")]
+    from: Option<String>,
+    #[arg(long, help = "Only expand occurrences up to this date")]
+    until: Option<String>,
+    #[arg(
+        short = 'n',
+        long,
+        default_value_t = 10,
+        help = "Maximum number of occurrences to expand"
+    )]
+    count: usize,
+    #[arg(
+        long,
+        default_value = "rfc3339",
+        help = "Output format for expand, one of the datetime formats or a strftime pattern"
+    )]
+    format: String,
+    #[arg(
+        long,
+        default_value = "UTC",
+        help = "Time zone for dates without a TZID or Z, as an IANA name or an offset such as +05:30"
+    )]
+    tz: String,
+}
+
 fn handle_cli_error(e: CliError) {
     match e {
         CliError::NoDataProvided => {
@@ -183,6 +479,26 @@ fn handle_cli_error(e: CliError) {
             eprintln!("Error while processing regex: {}", e);
             exit(exitcode::DATAERR);
         }
+        CliError::EscapeError(e) => {
+            eprintln!("Error while unescaping: {}", e);
+            exit(exitcode::DATAERR);
+        }
+        CliError::CipherError(e) => {
+            eprintln!("Error while applying cipher: {}", e);
+            exit(exitcode::DATAERR);
+        }
+        CliError::IdError(e) => {
+            eprintln!("Error while inspecting id: {}", e);
+            exit(exitcode::DATAERR);
+        }
+        CliError::CronError(e) => {
+            eprintln!("Error while reading cron expression: {}", e);
+            exit(exitcode::DATAERR);
+        }
+        CliError::RruleError(e) => {
+            eprintln!("Error while reading recurrence rule: {}", e);
+            exit(exitcode::DATAERR);
+        }
     }
 }
 
@@ -256,6 +572,54 @@ fn main() {
                 Err(e) => handle_cli_error(e),
             }
         }
+        Commands::Case(ref case_args) => {
+            match dev_utils::command_matchers::case(case_args.clone(), args.clone()) {
+                Ok(s) => println!("{}", s),
+                Err(e) => handle_cli_error(e),
+            }
+        }
+        Commands::Escape(ref escape_args) => {
+            match dev_utils::command_matchers::escape(escape_args.clone(), args.clone()) {
+                Ok(s) => println!("{}", s),
+                Err(e) => handle_cli_error(e),
+            }
+        }
+        Commands::Unescape(ref escape_args) => {
+            match dev_utils::command_matchers::unescape(escape_args.clone(), args.clone()) {
+                Ok(s) => println!("{}", s),
+                Err(e) => handle_cli_error(e),
+            }
+        }
+        Commands::Unicode(ref unicode_args) => {
+            match dev_utils::command_matchers::unicode(unicode_args.clone(), args.clone()) {
+                Ok(s) => println!("{}", s),
+                Err(e) => handle_cli_error(e),
+            }
+        }
+        Commands::Cipher(ref cipher_args) => {
+            match dev_utils::command_matchers::cipher(cipher_args.clone(), args.clone()) {
+                Ok(s) => println!("{}", s),
+                Err(e) => handle_cli_error(e),
+            }
+        }
+        Commands::Id(ref id_args) => {
+            match dev_utils::command_matchers::id(id_args.clone(), args.clone()) {
+                Ok(s) => println!("{}", s),
+                Err(e) => handle_cli_error(e),
+            }
+        }
+        Commands::Cron(ref cron_args) => {
+            match dev_utils::command_matchers::cron(cron_args.clone(), args.clone()) {
+                Ok(s) => println!("{}", s),
+                Err(e) => handle_cli_error(e),
+            }
+        }
+        Commands::Rrule(ref rrule_args) => {
+            match dev_utils::command_matchers::rrule(rrule_args.clone(), args.clone()) {
+                Ok(s) => println!("{}", s),
+                Err(e) => handle_cli_error(e),
+            }
+        }
     }
 
     exit(exitcode::OK)